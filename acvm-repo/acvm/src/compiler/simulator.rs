@@ -33,14 +33,16 @@ impl CircuitSimulator {
     /// Returns false if the circuit cannot be solved
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn check_circuit<F: AcirField>(&mut self, circuit: &Circuit<F>) -> bool {
+        self.find_unsolvable_opcode(circuit).is_none()
+    }
+
+    /// Simulate a symbolic solve for a circuit, as [`CircuitSimulator::check_circuit`] does.
+    /// Returns the index of the first opcode which cannot be solved, if any.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn find_unsolvable_opcode<F: AcirField>(&mut self, circuit: &Circuit<F>) -> Option<usize> {
         let circuit_inputs = circuit.circuit_arguments();
         self.solvable_witness.extend(circuit_inputs.iter());
-        for op in &circuit.opcodes {
-            if !self.try_solve(op) {
-                return false;
-            }
-        }
-        true
+        circuit.opcodes.iter().position(|op| !self.try_solve(op))
     }
 
     /// Check if the Opcode can be solved, and if yes, add the solved witness to set of solvable witness
@@ -289,4 +291,32 @@ mod tests {
 
         assert!(!CircuitSimulator::default().check_circuit(&disconnected_circuit));
     }
+
+    #[test]
+    fn finds_first_unsolvable_opcode() {
+        let circuit = test_circuit(
+            vec![
+                Opcode::AssertZero(Expression {
+                    mul_terms: Vec::new(),
+                    linear_combinations: vec![
+                        (FieldElement::one(), Witness(1)),
+                        (-FieldElement::one(), Witness(2)),
+                    ],
+                    q_c: FieldElement::zero(),
+                }),
+                Opcode::AssertZero(Expression {
+                    mul_terms: Vec::new(),
+                    linear_combinations: vec![
+                        (FieldElement::one(), Witness(3)),
+                        (-FieldElement::one(), Witness(4)),
+                    ],
+                    q_c: FieldElement::zero(),
+                }),
+            ],
+            BTreeSet::from([Witness(1)]),
+            PublicInputs::default(),
+        );
+
+        assert_eq!(CircuitSimulator::default().find_unsolvable_opcode(&circuit), Some(1));
+    }
 }
@@ -6,7 +6,7 @@ use iter_extended::vecmap;
 use noirc_abi::{
     Abi, AbiErrorType, AbiParameter, AbiReturnType, AbiType, AbiValue, AbiVisibility, Sign,
 };
-use noirc_errors::Span;
+use noirc_errors::{CustomDiagnostic, FileDiagnostic, Span};
 use noirc_evaluator::ErrorType;
 use noirc_frontend::ast::{Signedness, Visibility};
 use noirc_frontend::TypeBinding;
@@ -171,6 +171,34 @@ fn get_param_name<'a>(pattern: &HirPattern, interner: &'a NodeInterner) -> Optio
     }
 }
 
+/// Creates an informational diagnostic for each parameter of `main`, stating its visibility,
+/// so that users can check the ABI of their program matches what they intended.
+pub(super) fn parameter_visibility_report(
+    context: &Context,
+    main_function: &FuncId,
+) -> Vec<FileDiagnostic> {
+    let func_meta = context.def_interner.function_meta(main_function);
+    vecmap(func_meta.parameters.0.iter().enumerate(), |(index, (pattern, _, visibility))| {
+        let name = match get_param_name(pattern, &context.def_interner) {
+            Some(name) => format!("`{name}`"),
+            None => format!("Parameter #{index}"),
+        };
+        let visibility = match visibility {
+            Visibility::Public => "public".to_string(),
+            Visibility::Private => "private".to_string(),
+            Visibility::CallData(id) => format!("calldata({id})"),
+            Visibility::ReturnData => "returndata".to_string(),
+        };
+        let location = pattern.location();
+        CustomDiagnostic::simple_info(
+            format!("{name} is {visibility}"),
+            format!("{visibility} parameter of `main`"),
+            location.span,
+        )
+        .in_file(location.file)
+    })
+}
+
 fn into_abi_params(context: &Context, params: Vec<Param>) -> Vec<AbiParameter> {
     vecmap(params, |(pattern, typ, vis)| {
         let param_name = get_param_name(&pattern, &context.def_interner)
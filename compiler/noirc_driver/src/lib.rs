@@ -3,26 +3,28 @@
 #![warn(unreachable_pub)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 
-use abi_gen::{abi_type_from_hir_type, value_from_hir_expression};
+use abi_gen::{abi_type_from_hir_type, parameter_visibility_report, value_from_hir_expression};
 use acvm::acir::circuit::ExpressionWidth;
 use acvm::compiler::MIN_EXPRESSION_WIDTH;
 use clap::Args;
+use fm::codespan_files::Files;
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
 use noirc_abi::{AbiParameter, AbiType, AbiValue};
-use noirc_errors::{CustomDiagnostic, DiagnosticKind, FileDiagnostic};
-use noirc_evaluator::create_program;
+use noirc_errors::{CustomDiagnostic, DiagnosticKind, FileDiagnostic, Location};
+use noirc_evaluator::brillig::{RegisterReuseStrategy, DEFAULT_ARRAY_INIT_LOOP_THRESHOLD};
 use noirc_evaluator::errors::RuntimeError;
-use noirc_evaluator::ssa::{SsaLogging, SsaProgramArtifact};
+use noirc_evaluator::ssa::{SsaLogging, SsaProgramArtifact, DEFAULT_MAX_ARRAY_SET_LOOKBACK};
+use noirc_evaluator::{create_program, is_intrinsic};
 use noirc_frontend::debug::build_debug_crate_file;
 use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
 use noirc_frontend::hir::Context;
 use noirc_frontend::monomorphization::{
-    errors::MonomorphizationError, monomorphize, monomorphize_debug,
+    errors::MonomorphizationError, is_evaluated_builtin, monomorphize, monomorphize_debug,
 };
 use noirc_frontend::node_interner::FuncId;
 use noirc_frontend::token::SecondaryAttribute;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
 mod abi_gen;
@@ -36,7 +38,7 @@ use debug::filter_relevant_files;
 pub use contract::{CompiledContract, CompiledContractOutputs, ContractFunction};
 pub use debug::DebugFile;
 pub use noirc_frontend::graph::{CrateId, CrateName};
-pub use program::CompiledProgram;
+pub use program::{CompiledProgram, ExportedFunctionSchema};
 
 const STD_CRATE_NAME: &str = "std";
 const DEBUG_CRATE_NAME: &str = "__debug";
@@ -85,9 +87,53 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub emit_ssa: bool,
 
+    /// Emit the control flow graph of each optimized function as a Graphviz DOT file.
+    /// The graphs will be written into the workspace target directory,
+    /// under `[compiled-package].[function name].[function id].dot`.
+    #[arg(long, hide = true)]
+    pub emit_cfg: bool,
+
+    /// Emit a graph connecting the witnesses constrained together in each circuit
+    /// as a Graphviz DOT file. The graphs will be written into the workspace target directory,
+    /// under `[compiled-package].[circuit name].witnesses.dot`.
+    #[arg(long, hide = true)]
+    pub emit_witness_graph: bool,
+
+    /// Emit the ACIR program as JSON following a stable schema which does not depend on
+    /// the serialization of the ACVM types. The file will be written into the workspace
+    /// target directory, under `[compiled-package].acir.json`.
+    #[arg(long)]
+    pub emit_acir_json: bool,
+
+    /// Annotate each opcode of the ACIR JSON emitted with `--emit-acir-json` with the source
+    /// locations which produced it and an estimate of its cost.
+    #[arg(long, requires = "emit_acir_json")]
+    pub annotate_acir_json: bool,
+
+    /// Write the files emitted with the `--emit-*` flags into this directory
+    /// instead of the workspace target directory.
+    #[arg(long, value_name = "DIR")]
+    pub emit_dir: Option<PathBuf>,
+
     #[arg(long, hide = true)]
     pub show_brillig: bool,
 
+    /// Display the disassembled opcodes of each function compiled to Brillig, along with
+    /// the labels jumps and calls resolve to.
+    #[arg(long, hide = true)]
+    pub show_brillig_disassembly: bool,
+
+    /// Display the number of ACIR opcodes generated for each constrained function, as lowered
+    /// from SSA before any backend specific optimization.
+    /// Opcodes of inlined functions are counted for the function they were written in.
+    #[arg(long)]
+    pub show_constraint_counts: bool,
+
+    /// Display the given number of largest constant arrays in the program, such as lookup
+    /// tables, along with the file and line creating each of them.
+    #[arg(long, value_name = "COUNT")]
+    pub show_largest_constant_arrays: Option<usize>,
+
     /// Display the ACIR for compiled circuit
     #[arg(long)]
     pub print_acir: bool,
@@ -104,6 +150,11 @@ pub struct CompileOptions {
     #[arg(long, conflicts_with = "deny_warnings")]
     pub silence_warnings: bool,
 
+    /// Warn about `#[oracle]` functions whose oracle name is not in this comma-separated list,
+    /// to catch typos in oracle names at compile time
+    #[arg(long, value_delimiter = ',')]
+    pub known_oracles: Option<Vec<String>>,
+
     /// Disables the builtin Aztec macros being used in the compiler
     #[arg(long, hide = true)]
     pub disable_macros: bool,
@@ -155,6 +206,76 @@ pub struct CompileOptions {
     #[arg(long, hide = true, allow_hyphen_values = true)]
     pub max_bytecode_increase_percent: Option<i32>,
 
+    /// Pin the order of the SSA passes to the one listed in the given file,
+    /// which contains one pass name per line.
+    /// The order must still satisfy the mandatory ordering constraints between passes.
+    #[arg(long, hide = true)]
+    pub ssa_pass_order: Option<PathBuf>,
+
+    /// Skip the SSA passes whose name starts with the given one, which can be repeated.
+    /// For example `--disable-ssa-pass Mem2Reg` skips every run of mem2reg.
+    /// Meant for finding which pass causes a miscompilation: later passes may rely on the
+    /// skipped ones, so the resulting program may fail to compile or be incorrect.
+    #[arg(long = "disable-ssa-pass", hide = true)]
+    pub disabled_ssa_passes: Vec<String>,
+
+    /// Seed the fuzzer running the tests which take arguments, to reproduce a failing run.
+    /// A random seed is used otherwise, which is reported along with any failure.
+    #[arg(long)]
+    pub fuzz_seed: Option<u64>,
+
+    /// List every function compiled to Brillig, along with the reason why:
+    /// it was declared `unconstrained`, called from Brillig, or forced by `--force-brillig`.
+    #[arg(long)]
+    pub report_unconstrained_functions: bool,
+
+    /// Report the visibility of each `main` parameter: public, private, calldata or returndata.
+    #[arg(long)]
+    pub report_parameter_visibility: bool,
+
+    /// Whether Brillig codegen reuses registers once they are deallocated (`reuse`),
+    /// or gives every value its own register at the cost of larger stack frames (`no-reuse`).
+    #[arg(long, hide = true, default_value_t = RegisterReuseStrategy::default())]
+    pub brillig_register_reuse: RegisterReuseStrategy,
+
+    /// Repeated constant arrays with more items than this are initialized in a loop in Brillig.
+    /// Lower values give smaller bytecode, higher values avoid the loop overhead on small arrays.
+    #[arg(long, hide = true, default_value_t = DEFAULT_ARRAY_INIT_LOOP_THRESHOLD)]
+    pub brillig_array_init_loop_threshold: usize,
+
+    /// How many `array_set` instructions to look back at when merging arrays after an `if`,
+    /// to only merge the indices which were changed. Higher values may produce smaller circuits
+    /// for arrays updated many times in a branch, at the cost of compilation time.
+    /// Defaults to `DEFAULT_MAX_ARRAY_SET_LOOKBACK`.
+    #[arg(long, hide = true)]
+    pub max_array_set_lookback: Option<usize>,
+
+    /// Brillig procedures with at most this many opcodes are inlined into their callers
+    /// instead of being called, when they make no calls and only return at their very end.
+    /// Zero disables inlining.
+    #[arg(long, hide = true, default_value_t = 0)]
+    pub brillig_procedure_inline_threshold: usize,
+
+    /// Split the blocks of Brillig functions with more SSA instructions than this into a chain
+    /// of smaller blocks, for tools which perform poorly on large blocks.
+    #[arg(long, hide = true, value_parser = parse_max_brillig_block_size)]
+    pub max_brillig_block_size: Option<usize>,
+
+    /// Warn about calls to `print` and `println` left in the program.
+    /// Debug builds never warn, and neither do tests.
+    #[arg(long)]
+    pub warn_on_print: bool,
+
+    /// Report panics during SSA optimization and code generation as internal compiler errors
+    /// instead of crashing. This loses the backtrace of the panic.
+    #[arg(long, hide = true)]
+    pub convert_panics_to_errors: bool,
+
+    /// Check that no references are left in constrained functions after the last run of
+    /// the mem2reg SSA pass, reporting any leftover reference as an internal compiler error.
+    #[arg(long, hide = true)]
+    pub verify_no_references_after_mem2reg: bool,
+
     /// Use pedantic ACVM solving, i.e. double-check some black-box function
     /// assumptions when solving.
     /// This is disabled by default.
@@ -182,6 +303,18 @@ pub fn parse_expression_width(input: &str) -> Result<ExpressionWidth, std::io::E
     }
 }
 
+pub fn parse_max_brillig_block_size(input: &str) -> Result<usize, std::io::Error> {
+    use std::io::{Error, ErrorKind};
+    let size = input
+        .parse::<usize>()
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+
+    if size == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "has to be at least 1"));
+    }
+    Ok(size)
+}
+
 #[derive(Debug)]
 pub enum CompileError {
     MonomorphizationError(MonomorphizationError),
@@ -327,6 +460,8 @@ pub fn check_crate(
         options.debug_comptime_in_file.as_deref(),
         options.pedantic_solving,
     );
+    let unknown_oracles = unknown_oracle_warnings(context, options);
+    let unknown_builtins = unknown_builtin_errors(context);
     let crate_files = context.crate_files(&crate_id);
     let warnings_and_errors: Vec<FileDiagnostic> = diagnostics
         .into_iter()
@@ -334,6 +469,8 @@ pub fn check_crate(
             let diagnostic = CustomDiagnostic::from(&error);
             diagnostic.in_file(file_id)
         })
+        .chain(unknown_oracles)
+        .chain(unknown_builtins)
         .filter(|diagnostic| {
             // We filter out any warnings if they're going to be ignored later on to free up memory.
             !options.silence_warnings || diagnostic.diagnostic.kind != DiagnosticKind::Warning
@@ -355,6 +492,45 @@ pub fn check_crate(
     }
 }
 
+/// Warns about each `#[oracle]` function whose oracle name is not one of `--known-oracles`.
+fn unknown_oracle_warnings(context: &Context, options: &CompileOptions) -> Vec<FileDiagnostic> {
+    let Some(known_oracles) = &options.known_oracles else {
+        return Vec::new();
+    };
+
+    context
+        .def_interner
+        .oracles()
+        .filter(|(name, _)| !known_oracles.iter().any(|known| known == name))
+        .map(|(name, location)| {
+            CustomDiagnostic::simple_warning(
+                format!("Unknown oracle `{name}`"),
+                "This oracle name is not one of the names passed to `--known-oracles`".to_string(),
+                location.span,
+            )
+            .in_file(location.file)
+        })
+        .collect()
+}
+
+/// Errors on each `#[builtin]` function whose name is not handled by the compiler.
+fn unknown_builtin_errors(context: &Context) -> Vec<FileDiagnostic> {
+    context
+        .def_interner
+        .builtins()
+        .filter(|(name, _)| !is_intrinsic(name) && !is_evaluated_builtin(name))
+        .map(|(name, location)| {
+            CustomDiagnostic::simple_error(
+                format!("Unknown builtin `{name}`"),
+                "This name does not correspond to any builtin function known to the compiler"
+                    .to_string(),
+                location.span,
+            )
+            .in_file(location.file)
+        })
+        .collect()
+}
+
 pub fn compute_function_abi(
     context: &Context,
     crate_id: &CrateId,
@@ -397,6 +573,10 @@ pub fn compile_main(
     }
     warnings.extend(compilation_warnings);
 
+    if options.report_parameter_visibility {
+        warnings.extend(parameter_visibility_report(context, &main));
+    }
+
     if options.print_acir {
         println!("Compiled ACIR for main (unoptimized):");
         println!("{}", compiled_program.program);
@@ -405,6 +585,45 @@ pub fn compile_main(
     Ok((compiled_program, warnings))
 }
 
+/// Run the frontend to check the crate for errors then compile each function marked with
+/// `#[export]` into its own program if there were none
+///
+/// On success this returns the compiled programs, keyed by function name, alongside any warnings
+/// that were found. Each program has its own ABI generated from the exported function's signature.
+/// On error this returns the non-empty list of warnings and errors.
+pub fn compile_exported_functions(
+    context: &mut Context,
+    crate_id: CrateId,
+    options: &CompileOptions,
+) -> CompilationResult<Vec<(String, CompiledProgram)>> {
+    let (_, mut warnings) = check_crate(context, crate_id, options)?;
+
+    let exported_functions = context.get_all_exported_functions_in_crate(&crate_id);
+
+    let mut errors = Vec::new();
+    let mut compilation_warnings = Vec::new();
+    let mut compiled_programs = Vec::with_capacity(exported_functions.len());
+    for (function_name, function_id) in exported_functions {
+        match compile_no_check(context, options, function_id, None, options.force_compile) {
+            Ok(program) => {
+                compilation_warnings.extend(vecmap(program.warnings.clone(), FileDiagnostic::from));
+                compiled_programs.push((function_name, program));
+            }
+            Err(error) => errors.push(FileDiagnostic::from(error)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    if options.deny_warnings && !compilation_warnings.is_empty() {
+        return Err(compilation_warnings);
+    }
+    warnings.extend(compilation_warnings);
+
+    Ok((compiled_programs, warnings))
+}
+
 /// Run the frontend to check the crate for errors then compile all contracts if there were none
 pub fn compile_contract(
     context: &mut Context,
@@ -643,9 +862,16 @@ pub fn compile_no_check(
     let force_compile = force_compile
         || options.print_acir
         || options.show_brillig
+        || options.show_brillig_disassembly
+        || options.show_constraint_counts
+        || options.show_largest_constant_arrays.is_some()
         || options.force_brillig
         || options.show_ssa
-        || options.emit_ssa;
+        || options.emit_ssa
+        || options.emit_cfg
+        || options.emit_witness_graph
+        || options.emit_acir_json
+        || options.report_unconstrained_functions;
 
     // Hash the AST program, which is going to be used to fingerprint the compilation artifact.
     let hash = fxhash::hash64(&program);
@@ -677,14 +903,68 @@ pub fn compile_no_check(
             ExpressionWidth::default()
         },
         emit_ssa: if options.emit_ssa { Some(context.package_build_path.clone()) } else { None },
+        emit_cfg: if options.emit_cfg { Some(context.package_build_path.clone()) } else { None },
+        emit_witness_graph: if options.emit_witness_graph {
+            Some(context.package_build_path.clone())
+        } else {
+            None
+        },
+        emit_acir_json: if options.emit_acir_json {
+            Some(context.package_build_path.clone())
+        } else {
+            None
+        },
+        annotate_acir_json: options.annotate_acir_json,
+        artifact_dir: options.emit_dir.clone(),
         skip_underconstrained_check: options.skip_underconstrained_check,
         skip_brillig_constraints_check: options.skip_brillig_constraints_check,
         inliner_aggressiveness: options.inliner_aggressiveness,
         max_bytecode_increase_percent: options.max_bytecode_increase_percent,
+        ssa_pass_order: options.ssa_pass_order.clone(),
+        disabled_passes: options.disabled_ssa_passes.clone(),
+        report_unconstrained_functions: options.report_unconstrained_functions,
+        force_brillig: options.force_brillig,
+        brillig_register_reuse: options.brillig_register_reuse,
+        brillig_array_init_loop_threshold: options.brillig_array_init_loop_threshold,
+        max_array_set_lookback: options
+            .max_array_set_lookback
+            .unwrap_or(DEFAULT_MAX_ARRAY_SET_LOOKBACK),
+        brillig_procedure_inline_threshold: options.brillig_procedure_inline_threshold,
+        warn_on_print: options.warn_on_print && !options.instrument_debug,
+        pass_progress: None,
+        convert_panics_to_errors: options.convert_panics_to_errors,
+        verify_no_references_after_mem2reg: options.verify_no_references_after_mem2reg,
+        show_brillig_disassembly: options.show_brillig_disassembly,
+        show_constraint_counts: options.show_constraint_counts,
+        show_largest_constant_arrays: options.show_largest_constant_arrays,
+        max_brillig_block_size: options.max_brillig_block_size,
     };
 
-    let SsaProgramArtifact { program, debug, warnings, names, brillig_names, error_types, .. } =
-        create_program(program, &ssa_evaluator_options)?;
+    let SsaProgramArtifact {
+        program,
+        debug,
+        warnings,
+        names,
+        brillig_names,
+        error_types,
+        brillig_function_reasons,
+        largest_constant_arrays,
+        ..
+    } = create_program(program, &ssa_evaluator_options)?;
+
+    if options.report_unconstrained_functions {
+        println!("Unconstrained functions:");
+        for (name, reason) in &brillig_function_reasons {
+            println!("  {name} ({reason})");
+        }
+    }
+
+    if options.show_largest_constant_arrays.is_some() {
+        println!("Largest constant arrays:");
+        for (length, location) in &largest_constant_arrays {
+            println!("  {}: {length} elements", format_location(&context.file_manager, location));
+        }
+    }
 
     let abi = abi_gen::gen_abi(context, &main_function, return_visibility, error_types);
     let file_map = filter_relevant_files(&debug, &context.file_manager);
@@ -701,3 +981,15 @@ pub fn compile_no_check(
         brillig_names,
     })
 }
+
+/// Formats `location` as the name of its file followed by the line it starts on.
+fn format_location(file_manager: &FileManager, location: &Location) -> String {
+    let files = file_manager.as_file_map();
+    let name = files
+        .get_name(location.file)
+        .map_or_else(|_| "<unknown>".to_string(), |name| name.to_string());
+    match files.line_index(location.file, location.span.start() as usize) {
+        Ok(line_index) => format!("{name}:{}", line_index + 1),
+        Err(_) => name,
+    }
+}
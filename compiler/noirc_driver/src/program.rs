@@ -32,3 +32,20 @@ pub struct CompiledProgram {
     /// Names of the unconstrained functions in the program.
     pub brillig_names: Vec<String>,
 }
+
+/// The inputs and outputs of a function exported with `#[export]`, used to generate bindings for it.
+///
+/// This is the ABI of the function's circuit, without the types of the errors it may raise.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExportedFunctionSchema {
+    pub name: String,
+    pub parameters: Vec<noirc_abi::AbiParameter>,
+    pub return_type: Option<noirc_abi::AbiReturnType>,
+}
+
+impl ExportedFunctionSchema {
+    pub fn new(name: String, program: &CompiledProgram) -> Self {
+        let abi = &program.abi;
+        Self { name, parameters: abi.parameters.clone(), return_type: abi.return_type.clone() }
+    }
+}
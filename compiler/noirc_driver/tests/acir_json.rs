@@ -0,0 +1,112 @@
+mod common;
+
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
+use serde_json::{json, Value};
+
+/// Compiles `source` as `main.nr` and returns the parsed ACIR JSON emitted for it.
+fn compile_to_acir_json(
+    source: &str,
+    test_name: &str,
+    options: CompileOptions,
+) -> Result<Value, ErrorsAndWarnings> {
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let target_dir = tempfile::tempdir().unwrap();
+    context.package_build_path = target_dir.path().join(test_name);
+
+    let options = CompileOptions { emit_acir_json: true, ..options };
+    noirc_driver::compile_main(&mut context, root_crate_id, &options, None)?;
+
+    let json = std::fs::read_to_string(target_dir.path().join(format!("{test_name}.acir.json")))
+        .expect("Expected the ACIR JSON to be written");
+    Ok(serde_json::from_str(&json).expect("Expected the ACIR JSON to be valid JSON"))
+}
+
+/// Returns the opcodes of the first function in the ACIR JSON.
+fn main_opcodes(json: &Value) -> &Vec<Value> {
+    json["functions"][0]["opcodes"].as_array().expect("Expected a list of opcodes")
+}
+
+#[test]
+fn emits_acir_json_following_schema() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+fn main(x: Field, y: pub Field) -> pub Field {
+    assert(x != y);
+    x * y
+}";
+    let json = compile_to_acir_json(source, "acir_json", CompileOptions::default())?;
+
+    // The exact structure is covered by the unit tests of the export itself
+    assert_eq!(json["schema_version"], 1);
+    let main = &json["functions"][0];
+    assert_eq!(main["name"], "main");
+    assert_eq!(main["private_parameters"], json!([0]));
+    assert_eq!(main["public_parameters"], json!([1]));
+
+    let opcodes = main_opcodes(&json);
+    assert!(opcodes.iter().any(|opcode| opcode["kind"] == "assert_zero"));
+    assert!(opcodes.iter().any(|opcode| opcode["kind"] == "brillig_call"));
+    assert!(!json["unconstrained_functions"].as_array().unwrap().is_empty());
+    // Opcodes are only annotated on request
+    assert!(opcodes.iter().all(|opcode| opcode.get("cost").is_none()));
+
+    Ok(())
+}
+
+#[test]
+fn annotates_acir_json_opcodes_with_locations_and_cost() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+fn main(x: [u8; 4]) -> pub [u8; 32] {
+    std::hash::blake2s(x)
+}";
+    let options = CompileOptions { annotate_acir_json: true, ..Default::default() };
+    let json = compile_to_acir_json(source, "annotated_acir_json", options)?;
+
+    let blake2s = main_opcodes(&json)
+        .iter()
+        .find(|opcode| opcode["kind"] == "black_box" && opcode["name"] == "blake2s")
+        .expect("Expected a blake2s opcode");
+
+    // 4 input and 32 output witnesses
+    assert_eq!(blake2s["cost"], 36);
+
+    let call = "std::hash::blake2s(x)";
+    let start = source.find(call).unwrap();
+    let locations = blake2s["locations"].as_array().expect("Expected a list of locations");
+    assert!(locations
+        .iter()
+        .any(|location| location["start"] == start && location["end"] == start + call.len()));
+
+    Ok(())
+}
+
+#[test]
+fn lists_acir_json_opcode_locations_from_the_outermost_call() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+fn main(x: [u8; 4]) -> pub [u8; 32] {
+    hash(x)
+}
+
+fn hash(x: [u8; 4]) -> [u8; 32] {
+    std::hash::blake2s(x)
+}";
+    let options = CompileOptions { annotate_acir_json: true, ..Default::default() };
+    let json = compile_to_acir_json(source, "nested_acir_json", options)?;
+
+    let blake2s = main_opcodes(&json)
+        .iter()
+        .find(|opcode| opcode["kind"] == "black_box" && opcode["name"] == "blake2s")
+        .expect("Expected a blake2s opcode");
+    let starts: Vec<_> = blake2s["locations"]
+        .as_array()
+        .expect("Expected a list of locations")
+        .iter()
+        .map(|location| location["start"].as_u64().unwrap() as usize)
+        .collect();
+
+    let outer_call = source.find("hash(x)").unwrap();
+    let inner_call = source.find("std::hash::blake2s(x)").unwrap();
+    assert_eq!(starts, vec![outer_call, inner_call]);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use noirc_driver::{file_manager_with_stdlib, prepare_crate};
+use noirc_frontend::{
+    graph::CrateId,
+    hir::{def_map::parse_file, Context},
+};
+
+/// Creates a context holding the stdlib and a crate made of a single file `file_name` containing
+/// `source`, returning it along with the id of that crate.
+pub fn prepare_source(file_name: &str, source: &str) -> (Context<'static, 'static>, CrateId) {
+    let root = Path::new("");
+    let file_name = Path::new(file_name);
+    let mut file_manager = file_manager_with_stdlib(root);
+    file_manager.add_file_with_source(file_name, source.to_owned()).expect(
+        "Adding source buffer to file manager should never fail when file manager is empty",
+    );
+    let parsed_files = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let root_crate_id = prepare_crate(&mut context, file_name);
+    (context, root_crate_id)
+}
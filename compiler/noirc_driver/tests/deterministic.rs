@@ -0,0 +1,50 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const SOURCE: &str = "
+fn main(x: call_data(0) [Field; 3], y: u32) -> return_data Field {
+    let mut sum = 0;
+    for i in 0..3 {
+        sum += x[i];
+    }
+    // Safety: the result is checked below
+    let doubled = unsafe { double(sum) };
+    assert(doubled == sum * 2);
+    println(doubled);
+    doubled + y as Field
+}
+
+unconstrained fn double(x: Field) -> Field {
+    x * 2
+}";
+
+/// Compiles `SOURCE` on a new thread and serializes the whole compiled program, including its
+/// debug info. The keys of std's `RandomState` are drawn once per thread, so any std `HashMap`
+/// used while compiling gets a different seed on each call.
+fn compile_on_new_thread(options: CompileOptions) -> String {
+    std::thread::spawn(move || {
+        let (mut context, root_crate_id) = common::prepare_source("main.nr", SOURCE);
+        let (program, _warnings) =
+            noirc_driver::compile_main(&mut context, root_crate_id, &options, None)
+                .expect("Expected the program to compile");
+        serde_json::to_string(&program).unwrap()
+    })
+    .join()
+    .unwrap()
+}
+
+#[test]
+fn compiling_with_different_hasher_seeds_produces_identical_artifacts() {
+    for force_brillig in [false, true] {
+        let options = CompileOptions {
+            force_brillig,
+            brillig_procedure_inline_threshold: 100,
+            ..CompileOptions::default()
+        };
+        let first = compile_on_new_thread(options.clone());
+        for _ in 0..4 {
+            assert_eq!(compile_on_new_thread(options.clone()), first);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+#[test]
+fn writes_emitted_files_into_emit_dir() {
+    let source = "
+fn main(x: Field, y: pub Field) {
+    assert(x != y);
+}";
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let test_dir = tempfile::tempdir().unwrap();
+    let target_dir = test_dir.path().join("target");
+    let emit_dir = test_dir.path().join("emitted");
+    context.package_build_path = target_dir.join("package");
+
+    let options = CompileOptions {
+        emit_ssa: true,
+        emit_cfg: true,
+        emit_witness_graph: true,
+        emit_acir_json: true,
+        emit_dir: Some(emit_dir.clone()),
+        ..Default::default()
+    };
+    noirc_driver::compile_main(&mut context, root_crate_id, &options, None)
+        .expect("Expected the program to compile");
+
+    let mut emitted: Vec<_> = std::fs::read_dir(&emit_dir)
+        .expect("Expected the emit directory to be created")
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    emitted.sort();
+
+    assert!(emitted.contains(&"package.ssa.json".to_string()), "{emitted:?}");
+    assert!(emitted.iter().any(|file| file.starts_with("package.main.") && file.ends_with(".dot")));
+    assert!(emitted.contains(&"package.main.witnesses.dot".to_string()), "{emitted:?}");
+    assert!(emitted.contains(&"package.acir.json".to_string()), "{emitted:?}");
+    assert!(!target_dir.exists(), "Expected nothing to be written into the target directory");
+}
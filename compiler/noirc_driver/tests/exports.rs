@@ -0,0 +1,90 @@
+mod common;
+
+use noirc_abi::{AbiType, AbiVisibility, Sign};
+use noirc_driver::{CompileOptions, CompiledProgram, ErrorsAndWarnings, ExportedFunctionSchema};
+
+const SOURCE: &str = "
+#[export]
+fn add(x: Field, y: Field) -> pub Field {
+    x + y
+}
+
+#[export]
+fn is_zero(x: u32) -> bool {
+    x == 0
+}
+
+fn not_exported() {}";
+
+/// Compiles all exported functions in `source`, sorted by name.
+fn compile_exports(source: &str) -> Result<Vec<(String, CompiledProgram)>, ErrorsAndWarnings> {
+    let (mut context, root_crate_id) = common::prepare_source("lib.nr", source);
+
+    let (mut programs, _warnings) = noirc_driver::compile_exported_functions(
+        &mut context,
+        root_crate_id,
+        &CompileOptions::default(),
+    )?;
+    programs.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(programs)
+}
+
+#[test]
+fn compiles_a_program_for_each_exported_function() -> Result<(), ErrorsAndWarnings> {
+    let programs = compile_exports(SOURCE)?;
+
+    let names: Vec<_> = programs.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["add", "is_zero"]);
+
+    let (_, add) = &programs[0];
+    assert_eq!(add.abi.parameters.len(), 2);
+    assert!(add.abi.return_type.is_some());
+
+    let (_, is_zero) = &programs[1];
+    assert_eq!(is_zero.abi.parameters.len(), 1);
+    assert!(is_zero.abi.return_type.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn schema_of_each_exported_function_matches_its_signature() -> Result<(), ErrorsAndWarnings> {
+    let schema: Vec<_> = compile_exports(SOURCE)?
+        .iter()
+        .map(|(name, program)| ExportedFunctionSchema::new(name.clone(), program))
+        .collect();
+    assert_eq!(schema.len(), 2);
+
+    let add = &schema[0];
+    assert_eq!(add.name, "add");
+    let parameters: Vec<_> = add
+        .parameters
+        .iter()
+        .map(|param| (param.name.as_str(), &param.typ, param.visibility))
+        .collect();
+    assert_eq!(
+        parameters,
+        vec![
+            ("x", &AbiType::Field, AbiVisibility::Private),
+            ("y", &AbiType::Field, AbiVisibility::Private),
+        ]
+    );
+    let return_type = add.return_type.as_ref().expect("add should return a value");
+    assert_eq!(return_type.abi_type, AbiType::Field);
+    assert_eq!(return_type.visibility, AbiVisibility::Public);
+
+    let is_zero = &schema[1];
+    assert_eq!(is_zero.name, "is_zero");
+    let parameters: Vec<_> = is_zero
+        .parameters
+        .iter()
+        .map(|param| (param.name.as_str(), &param.typ, param.visibility))
+        .collect();
+    let u32_type = AbiType::Integer { sign: Sign::Unsigned, width: 32 };
+    assert_eq!(parameters, vec![("x", &u32_type, AbiVisibility::Private)]);
+    let return_type = is_zero.return_type.as_ref().expect("is_zero should return a value");
+    assert_eq!(return_type.abi_type, AbiType::Boolean);
+    assert_eq!(return_type.visibility, AbiVisibility::Private);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+
+use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions};
+use noirc_frontend::hir::{def_map::parse_file, Context, ParsedFiles};
+
+#[test]
+fn resolves_modules_from_include_dirs_of_the_crate() {
+    let main_source = "
+mod foo;
+
+fn main() {
+    assert_eq(foo::one(), 1);
+}";
+    let foo_source = "
+pub fn one() -> Field {
+    1
+}";
+
+    let main_file = Path::new("src/main.nr");
+    let mut file_manager = file_manager_with_stdlib(Path::new(""));
+    file_manager.add_file_with_source(main_file, main_source.to_owned()).unwrap();
+    file_manager.add_file_with_source(Path::new("vendor/foo.nr"), foo_source.to_owned()).unwrap();
+    let parsed_files: ParsedFiles = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::from_ref_file_manager(&file_manager, &parsed_files);
+    let root_crate_id = prepare_crate(&mut context, main_file);
+
+    let options = CompileOptions::default();
+    let errors = noirc_driver::check_crate(&mut context, root_crate_id, &options)
+        .expect_err("Expected `foo` not to be found without include directories");
+    assert!(errors
+        .iter()
+        .any(|error| error.diagnostic.message.starts_with("No module `foo` at path")));
+
+    let mut context = Context::from_ref_file_manager(&file_manager, &parsed_files);
+    let root_crate_id = prepare_crate(&mut context, main_file);
+    context.include_dirs.insert(root_crate_id, vec![PathBuf::from("vendor")]);
+
+    noirc_driver::check_crate(&mut context, root_crate_id, &options)
+        .expect("Expected `foo` to be found in the include directory");
+}
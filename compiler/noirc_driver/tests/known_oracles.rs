@@ -0,0 +1,28 @@
+mod common;
+
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
+
+#[test]
+fn warns_about_oracle_names_not_known() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+#[oracle(get_value)]
+unconstrained fn get_value() -> Field {}
+
+#[oracle(get_valeu)]
+unconstrained fn get_other_value() -> Field {}
+
+unconstrained fn main() -> pub Field {
+    get_value() + get_other_value()
+}";
+
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let options =
+        CompileOptions { known_oracles: Some(vec!["get_value".to_string()]), ..Default::default() };
+    let ((), warnings) = noirc_driver::check_crate(&mut context, root_crate_id, &options)?;
+
+    let messages: Vec<_> = warnings.iter().map(|warning| &warning.diagnostic.message).collect();
+    assert_eq!(messages, vec!["Unknown oracle `get_valeu`"]);
+
+    Ok(())
+}
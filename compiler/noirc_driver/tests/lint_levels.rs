@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use noirc_driver::{
+    add_dep, file_manager_with_stdlib, prepare_crate, prepare_dependency, CompileOptions,
+};
+use noirc_frontend::hir::{def_map::parse_file, Context};
+
+#[test]
+fn lints_denied_by_a_dependency_are_warnings() {
+    let main_source = "
+fn main() {
+    my_dep::foo(1);
+}";
+    let dep_source = "
+#![deny(unused_variables)]
+
+pub fn foo(x: Field) {}";
+
+    let main_file = Path::new("main.nr");
+    let dep_file = Path::new("dep/lib.nr");
+    let mut file_manager = file_manager_with_stdlib(Path::new(""));
+    file_manager.add_file_with_source(main_file, main_source.to_owned()).unwrap();
+    file_manager.add_file_with_source(dep_file, dep_source.to_owned()).unwrap();
+    let parsed_files = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let root_crate_id = prepare_crate(&mut context, main_file);
+    let dep_crate_id = prepare_dependency(&mut context, dep_file);
+    add_dep(&mut context, root_crate_id, dep_crate_id, "my_dep".parse().unwrap());
+
+    let ((), warnings) =
+        noirc_driver::check_crate(&mut context, root_crate_id, &CompileOptions::default())
+            .expect("Expected the dependency's denied lint not to fail the compilation");
+
+    // Only warnings from the crate being checked are reported
+    assert_eq!(warnings, Vec::new());
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
+
+/// Returns the messages of the errors found when checking `source`, whose dependency on the
+/// stdlib provides the types and traits defined outside of the crate.
+fn check_errors(source: &str) -> Vec<String> {
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let result = noirc_driver::check_crate(&mut context, root_crate_id, &CompileOptions::default());
+    let diagnostics: ErrorsAndWarnings = match result {
+        Ok(((), warnings)) => warnings,
+        Err(errors) => errors,
+    };
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| diagnostic.diagnostic.is_error())
+        .map(|diagnostic| diagnostic.diagnostic.message)
+        .collect()
+}
+
+#[test]
+fn rejects_inherent_impl_on_foreign_type() {
+    let source = "
+impl BoundedVec<Field, 2> {
+    fn first(self) -> Field {
+        self.get(0)
+    }
+}
+
+fn main() {}";
+
+    assert_eq!(
+        check_errors(source),
+        vec!["Cannot `impl` a type that was defined outside the current crate"]
+    );
+}
+
+#[test]
+fn rejects_foreign_trait_impl_on_foreign_type() {
+    let source = "
+impl From<bool> for BoundedVec<Field, 2> {
+    fn from(_value: bool) -> Self {
+        BoundedVec::new()
+    }
+}
+
+fn main() {}";
+
+    assert_eq!(check_errors(source), vec!["Orphaned trait implementation"]);
+}
+
+#[test]
+fn accepts_foreign_trait_impl_on_local_type() {
+    let source = "
+struct Local {}
+
+impl From<bool> for Local {
+    fn from(_value: bool) -> Self {
+        Local {}
+    }
+}
+
+fn main() {
+    let _ = Local::from(true);
+}";
+
+    assert_eq!(check_errors(source), Vec::<String>::new());
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+#[test]
+fn errors_on_constant_index_out_of_bounds() {
+    let source = "
+fn main() -> pub Field {
+    let array = [1, 2, 3];
+    array[10]
+}";
+
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let errors =
+        noirc_driver::compile_main(&mut context, root_crate_id, &CompileOptions::default(), None)
+            .expect_err("Expected the out of bounds access to be reported as an error");
+
+    assert_eq!(errors.len(), 1);
+    let diagnostic = &errors[0].diagnostic;
+    assert_eq!(diagnostic.message, "Index 10 is out of bounds for an array of length 3");
+
+    let access = "array[10]";
+    let start = source.find(access).unwrap() as u32;
+    let span = diagnostic.secondaries[0].span;
+    assert_eq!((span.start(), span.end()), (start, start + access.len() as u32));
+}
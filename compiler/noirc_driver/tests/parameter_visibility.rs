@@ -0,0 +1,26 @@
+mod common;
+
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
+
+#[test]
+fn reports_visibility_of_each_main_parameter() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+fn main(x: pub Field, y: u32, z: call_data(0) [Field; 2]) -> return_data Field {
+    x + y as Field + z[0] + z[1]
+}";
+
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let options = CompileOptions { report_parameter_visibility: true, ..Default::default() };
+    let (_program, warnings) =
+        noirc_driver::compile_main(&mut context, root_crate_id, &options, None)?;
+
+    let reports: Vec<_> = warnings
+        .iter()
+        .filter(|warning| warning.diagnostic.is_info())
+        .map(|warning| warning.diagnostic.message.as_str())
+        .collect();
+    assert_eq!(reports, vec!["`x` is public", "`y` is private", "`z` is calldata(0)"]);
+
+    Ok(())
+}
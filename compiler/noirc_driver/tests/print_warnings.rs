@@ -0,0 +1,33 @@
+mod common;
+
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
+
+fn print_warnings(source: &str, options: &CompileOptions) -> Result<usize, ErrorsAndWarnings> {
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let (_program, warnings) =
+        noirc_driver::compile_main(&mut context, root_crate_id, options, None)?;
+    let print_warnings = warnings
+        .iter()
+        .filter(|warning| warning.diagnostic.message == "Print call left in the program")
+        .count();
+    Ok(print_warnings)
+}
+
+#[test]
+fn warns_on_stray_print_in_release_build() -> Result<(), ErrorsAndWarnings> {
+    let source = "
+fn main(x: Field) -> pub Field {
+    println(x);
+    x + 1
+}";
+
+    let release = CompileOptions { warn_on_print: true, ..CompileOptions::default() };
+    assert_eq!(print_warnings(source, &release)?, 1);
+
+    let debug = CompileOptions { instrument_debug: true, ..release };
+    assert_eq!(print_warnings(source, &debug)?, 0);
+
+    assert_eq!(print_warnings(source, &CompileOptions::default())?, 0);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use noirc_driver::{CompileOptions, CompiledProgram};
+
+fn compile(source: &str) -> CompiledProgram {
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+    let (program, _warnings) =
+        noirc_driver::compile_main(&mut context, root_crate_id, &CompileOptions::default(), None)
+            .expect("Expected the program to compile");
+    program
+}
+
+fn opcodes(program: &CompiledProgram) -> usize {
+    program.program.functions[0].opcodes.len()
+}
+
+#[test]
+fn unchecked_arithmetic_omits_shift_overflow_predicate() {
+    let source = "
+fn main(x: u32, y: u8) -> pub u32 {
+    shift(x, y)
+}
+
+#[unchecked_arithmetic]
+fn shift(x: u32, y: u8) -> u32 {
+    x << y
+}";
+    let unchecked = compile(source);
+    let checked = compile(&source.replace("#[unchecked_arithmetic]", ""));
+
+    // The assertion that `y` is less than the bit size of `x` is kept either way
+    let assertions = |program: &CompiledProgram| program.program.functions[0].assert_messages.len();
+    assert_eq!(assertions(&checked), 1);
+    assert_eq!(assertions(&unchecked), 1);
+
+    assert!(opcodes(&unchecked) < opcodes(&checked));
+}
+
+#[test]
+fn unchecked_arithmetic_keeps_range_checks() {
+    let source = "
+fn main(x: u8, y: u8, z: i8) -> pub (u8, i8) {
+    arithmetic(x, y, z)
+}
+
+#[unchecked_arithmetic]
+fn arithmetic(x: u8, y: u8, z: i8) -> (u8, i8) {
+    (x * y + x - y, z + z)
+}";
+    let unchecked = compile(source);
+    let checked = compile(&source.replace("#[unchecked_arithmetic]", ""));
+
+    assert_eq!(unchecked.program, checked.program);
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+#[test]
+fn errors_on_unknown_builtin_name() {
+    let source = "
+#[builtin(array_len)]
+fn len<let N: u32>(array: [Field; N]) -> u32 {}
+
+#[builtin(arary_len)]
+fn other_len<let N: u32>(array: [Field; N]) -> u32 {}
+
+fn main(x: [Field; 2]) -> pub u32 {
+    len(x) + other_len(x)
+}";
+
+    let (mut context, root_crate_id) = common::prepare_source("main.nr", source);
+
+    let errors = noirc_driver::check_crate(&mut context, root_crate_id, &CompileOptions::default())
+        .expect_err("Expected an unknown builtin to be reported as an error");
+
+    // Builtins are also rejected outside of the standard library, which is reported separately
+    let messages: Vec<_> = errors
+        .iter()
+        .map(|error| &error.diagnostic.message)
+        .filter(|message| message.starts_with("Unknown builtin"))
+        .collect();
+    assert_eq!(messages, vec!["Unknown builtin `arary_len`"]);
+}
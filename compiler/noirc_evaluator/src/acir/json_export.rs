@@ -0,0 +1,481 @@
+//! Exports an ACIR program as JSON following a schema which is independent of the serialization
+//! of the `acvm` types, for tools which want to inspect circuits without depending on `acvm`.
+//!
+//! The schema (version 1) is as follows:
+//!
+//! - The root object has a `schema_version` number, a `functions` list with one object per
+//!   circuit and an `unconstrained_functions` list with one object per Brillig function.
+//! - A function has a `name`, the `current_witness_index`, the `private_parameters`,
+//!   `public_parameters` and `return_values` as lists of witness indices and a list of `opcodes`.
+//! - An unconstrained function has a `name` and a `bytecode_length`.
+//! - An opcode is an object with a `kind` and the fields specific to that kind:
+//!   - `assert_zero`: an `expression` which must be equal to zero.
+//!   - `black_box`: the `name` of the black box function and its `inputs` and `outputs`
+//!     as lists of witness indices.
+//!   - `memory_init`: the `block_id` being initialized and its `init` witness indices.
+//!   - `memory_op`: the `block_id` being accessed, an `operation` expression which is 0 for
+//!     reads and 1 for writes, the `index` and `value` expressions and an optional `predicate`.
+//!   - `brillig_call`: the called `function_id`, its `inputs`, its `outputs` as one list of
+//!     witness indices per output and an optional `predicate`. Each input is an object with
+//!     either a `single` expression, an `array` of expressions or a `memory_block` id.
+//!   - `call`: the called `function_id`, its `inputs` and `outputs` as lists of witness indices
+//!     and an optional `predicate`.
+//! - When annotations are requested, each opcode also has:
+//!   - `locations`: the source locations which produced it as a call stack, outermost call
+//!     first. Each location has a `file_id` and the `start` and `end` byte offsets of its span.
+//!   - `cost`: a backend independent estimate of the opcode's cost. This is the number of terms
+//!     of an `assert_zero` expression, the number of input and output witnesses of a `black_box`
+//!     call, the number of initialized elements of a `memory_init` and 1 for a `memory_op`.
+//!     `brillig_call`s cost nothing as unconstrained code isn't proven and the cost of a `call`
+//!     is the total cost of the opcodes of the called function, including its own calls.
+//! - An expression has `mul_terms` (objects with a `coefficient`, `lhs` and `rhs` witness),
+//!   `linear_terms` (objects with a `coefficient` and a `witness`) and a `constant`.
+//!
+//! Field elements are written as `0x`-prefixed hex strings and witnesses as their index.
+use acvm::acir::{
+    circuit::{
+        brillig::{BrilligInputs, BrilligOutputs},
+        Circuit, Opcode, OpcodeLocation, Program,
+    },
+    native_types::{Expression, Witness},
+};
+use acvm::{AcirField, FieldElement};
+use noirc_errors::{debug_info::DebugInfo, Location};
+use serde::Serialize;
+
+/// The version of the schema described in the module documentation.
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonProgram {
+    schema_version: u32,
+    functions: Vec<JsonFunction>,
+    unconstrained_functions: Vec<JsonUnconstrainedFunction>,
+}
+
+#[derive(Serialize)]
+struct JsonFunction {
+    name: String,
+    current_witness_index: u32,
+    private_parameters: Vec<u32>,
+    public_parameters: Vec<u32>,
+    return_values: Vec<u32>,
+    opcodes: Vec<JsonAnnotatedOpcode>,
+}
+
+#[derive(Serialize)]
+struct JsonUnconstrainedFunction {
+    name: String,
+    bytecode_length: usize,
+}
+
+#[derive(Serialize)]
+struct JsonAnnotatedOpcode {
+    #[serde(flatten)]
+    opcode: JsonOpcode,
+    #[serde(flatten)]
+    annotation: Option<JsonAnnotation>,
+}
+
+#[derive(Serialize)]
+struct JsonAnnotation {
+    locations: Vec<JsonLocation>,
+    cost: usize,
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+    file_id: usize,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum JsonOpcode {
+    AssertZero {
+        expression: JsonExpression,
+    },
+    BlackBox {
+        name: String,
+        inputs: Vec<u32>,
+        outputs: Vec<u32>,
+    },
+    MemoryInit {
+        block_id: u32,
+        init: Vec<u32>,
+    },
+    MemoryOp {
+        block_id: u32,
+        operation: JsonExpression,
+        index: JsonExpression,
+        value: JsonExpression,
+        predicate: Option<JsonExpression>,
+    },
+    BrilligCall {
+        function_id: u32,
+        inputs: Vec<JsonBrilligInput>,
+        outputs: Vec<Vec<u32>>,
+        predicate: Option<JsonExpression>,
+    },
+    Call {
+        function_id: u32,
+        inputs: Vec<u32>,
+        outputs: Vec<u32>,
+        predicate: Option<JsonExpression>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JsonBrilligInput {
+    Single(JsonExpression),
+    Array(Vec<JsonExpression>),
+    MemoryBlock(u32),
+}
+
+#[derive(Serialize)]
+struct JsonExpression {
+    mul_terms: Vec<JsonMulTerm>,
+    linear_terms: Vec<JsonLinearTerm>,
+    constant: String,
+}
+
+#[derive(Serialize)]
+struct JsonMulTerm {
+    coefficient: String,
+    lhs: u32,
+    rhs: u32,
+}
+
+#[derive(Serialize)]
+struct JsonLinearTerm {
+    coefficient: String,
+    witness: u32,
+}
+
+/// Returns `program` as pretty-printed JSON following the schema described in the module
+/// documentation. `names` and `brillig_names` are the names of the circuits and of the
+/// unconstrained functions respectively, in order.
+///
+/// If the `debug_infos` of each circuit are given, opcodes are annotated with their source
+/// locations and estimated cost.
+pub(crate) fn program_to_json(
+    program: &Program<FieldElement>,
+    names: &[String],
+    brillig_names: &[String],
+    debug_infos: Option<&[DebugInfo]>,
+) -> String {
+    let function_costs = function_costs(program);
+    let functions = program
+        .functions
+        .iter()
+        .zip(names)
+        .enumerate()
+        .map(|(index, (circuit, name))| {
+            let debug_info = debug_infos.map(|debug_infos| &debug_infos[index]);
+            function_to_json(name, circuit, debug_info, &function_costs)
+        })
+        .collect();
+    let unconstrained_functions = program
+        .unconstrained_functions
+        .iter()
+        .zip(brillig_names)
+        .map(|(function, name)| JsonUnconstrainedFunction {
+            name: name.clone(),
+            bytecode_length: function.bytecode.len(),
+        })
+        .collect();
+
+    let program =
+        JsonProgram { schema_version: SCHEMA_VERSION, functions, unconstrained_functions };
+    serde_json::to_string_pretty(&program).expect("ACIR program should serialize to JSON")
+}
+
+fn function_to_json(
+    name: &str,
+    circuit: &Circuit<FieldElement>,
+    debug_info: Option<&DebugInfo>,
+    function_costs: &[usize],
+) -> JsonFunction {
+    let opcodes = circuit
+        .opcodes
+        .iter()
+        .enumerate()
+        .map(|(index, opcode)| JsonAnnotatedOpcode {
+            opcode: opcode_to_json(opcode),
+            annotation: debug_info.map(|debug_info| {
+                let locations = debug_info
+                    .locations
+                    .get(&OpcodeLocation::Acir(index))
+                    // Call stacks are recorded from the innermost call to the outermost one
+                    .map_or_else(Vec::new, |locations| {
+                        locations.iter().rev().map(location_to_json).collect()
+                    });
+                JsonAnnotation { locations, cost: opcode_cost(opcode, function_costs) }
+            }),
+        })
+        .collect();
+
+    JsonFunction {
+        name: name.to_string(),
+        current_witness_index: circuit.current_witness_index,
+        private_parameters: witness_indices(&circuit.private_parameters),
+        public_parameters: witness_indices(&circuit.public_parameters.0),
+        return_values: witness_indices(&circuit.return_values.0),
+        opcodes,
+    }
+}
+
+/// The total cost of each function of `program`, indexed by function id.
+fn function_costs(program: &Program<FieldElement>) -> Vec<usize> {
+    let mut costs = vec![None; program.functions.len()];
+    for index in 0..program.functions.len() {
+        function_cost(program, index, &mut costs);
+    }
+    costs.into_iter().map(|cost| cost.expect("Every function cost should be computed")).collect()
+}
+
+fn function_cost(
+    program: &Program<FieldElement>,
+    index: usize,
+    costs: &mut [Option<usize>],
+) -> usize {
+    if let Some(cost) = costs[index] {
+        return cost;
+    }
+    // ACIR functions can't be recursive, this only guarantees termination on malformed programs
+    costs[index] = Some(0);
+
+    let mut cost = 0;
+    for opcode in &program.functions[index].opcodes {
+        cost += match opcode {
+            Opcode::Call { id, .. } => function_cost(program, id.as_usize(), costs),
+            _ => local_opcode_cost(opcode),
+        };
+    }
+    costs[index] = Some(cost);
+    cost
+}
+
+/// The estimated cost of an opcode, as described in the module documentation.
+fn opcode_cost(opcode: &Opcode<FieldElement>, function_costs: &[usize]) -> usize {
+    match opcode {
+        Opcode::Call { id, .. } => function_costs[id.as_usize()],
+        _ => local_opcode_cost(opcode),
+    }
+}
+
+/// The estimated cost of an opcode, ignoring the cost of the function called by a `call`.
+fn local_opcode_cost(opcode: &Opcode<FieldElement>) -> usize {
+    match opcode {
+        Opcode::AssertZero(expression) => {
+            (expression.mul_terms.len() + expression.linear_combinations.len()).max(1)
+        }
+        Opcode::BlackBoxFuncCall(call) => {
+            call.get_input_witnesses().len() + call.get_outputs_vec().len()
+        }
+        Opcode::MemoryInit { init, .. } => init.len(),
+        Opcode::MemoryOp { .. } => 1,
+        Opcode::BrilligCall { .. } | Opcode::Call { .. } => 0,
+    }
+}
+
+fn location_to_json(location: &Location) -> JsonLocation {
+    JsonLocation {
+        file_id: location.file.as_usize(),
+        start: location.span.start(),
+        end: location.span.end(),
+    }
+}
+
+fn opcode_to_json(opcode: &Opcode<FieldElement>) -> JsonOpcode {
+    match opcode {
+        Opcode::AssertZero(expression) => {
+            JsonOpcode::AssertZero { expression: expression_to_json(expression) }
+        }
+        Opcode::BlackBoxFuncCall(call) => JsonOpcode::BlackBox {
+            name: call.name().to_string(),
+            inputs: witness_indices(&call.get_input_witnesses()),
+            outputs: witness_indices(&call.get_outputs_vec()),
+        },
+        Opcode::MemoryInit { block_id, init, .. } => {
+            JsonOpcode::MemoryInit { block_id: block_id.0, init: witness_indices(init) }
+        }
+        Opcode::MemoryOp { block_id, op, predicate } => JsonOpcode::MemoryOp {
+            block_id: block_id.0,
+            operation: expression_to_json(&op.operation),
+            index: expression_to_json(&op.index),
+            value: expression_to_json(&op.value),
+            predicate: predicate.as_ref().map(expression_to_json),
+        },
+        Opcode::BrilligCall { id, inputs, outputs, predicate } => JsonOpcode::BrilligCall {
+            function_id: id.0,
+            inputs: inputs.iter().map(brillig_input_to_json).collect(),
+            outputs: outputs
+                .iter()
+                .map(|output| match output {
+                    BrilligOutputs::Simple(witness) => vec![witness.0],
+                    BrilligOutputs::Array(witnesses) => witness_indices(witnesses),
+                })
+                .collect(),
+            predicate: predicate.as_ref().map(expression_to_json),
+        },
+        Opcode::Call { id, inputs, outputs, predicate } => JsonOpcode::Call {
+            function_id: id.0,
+            inputs: witness_indices(inputs),
+            outputs: witness_indices(outputs),
+            predicate: predicate.as_ref().map(expression_to_json),
+        },
+    }
+}
+
+fn brillig_input_to_json(input: &BrilligInputs<FieldElement>) -> JsonBrilligInput {
+    match input {
+        BrilligInputs::Single(expression) => {
+            JsonBrilligInput::Single(expression_to_json(expression))
+        }
+        BrilligInputs::Array(expressions) => {
+            JsonBrilligInput::Array(expressions.iter().map(expression_to_json).collect())
+        }
+        BrilligInputs::MemoryArray(block_id) => JsonBrilligInput::MemoryBlock(block_id.0),
+    }
+}
+
+fn expression_to_json(expression: &Expression<FieldElement>) -> JsonExpression {
+    JsonExpression {
+        mul_terms: expression
+            .mul_terms
+            .iter()
+            .map(|(coefficient, lhs, rhs)| JsonMulTerm {
+                coefficient: field_to_json(*coefficient),
+                lhs: lhs.0,
+                rhs: rhs.0,
+            })
+            .collect(),
+        linear_terms: expression
+            .linear_combinations
+            .iter()
+            .map(|(coefficient, witness)| JsonLinearTerm {
+                coefficient: field_to_json(*coefficient),
+                witness: witness.0,
+            })
+            .collect(),
+        constant: field_to_json(expression.q_c),
+    }
+}
+
+fn field_to_json(value: FieldElement) -> String {
+    format!("0x{}", value.to_hex())
+}
+
+fn witness_indices<'a>(witnesses: impl IntoIterator<Item = &'a Witness>) -> Vec<u32> {
+    witnesses.into_iter().map(|witness| witness.0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use acvm::{
+        acir::{
+            circuit::{
+                brillig::BrilligBytecode, opcodes::AcirFunctionId, Circuit, Opcode, Program,
+                PublicInputs,
+            },
+            native_types::{Expression, Witness},
+        },
+        AcirField, FieldElement,
+    };
+    use noirc_errors::debug_info::DebugInfo;
+    use serde_json::json;
+
+    use super::program_to_json;
+
+    #[test]
+    fn exports_program_following_schema() {
+        // w0 * w1 - w2 + 5 = 0
+        let expression = Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(0), Witness(1))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(2))],
+            q_c: FieldElement::from(5u128),
+        };
+        let circuit = Circuit {
+            current_witness_index: 2,
+            opcodes: vec![Opcode::AssertZero(expression)],
+            private_parameters: BTreeSet::from([Witness(0), Witness(1)]),
+            return_values: PublicInputs(BTreeSet::from([Witness(2)])),
+            ..Circuit::default()
+        };
+        let program = Program {
+            functions: vec![circuit],
+            unconstrained_functions: vec![BrilligBytecode::default()],
+        };
+
+        let exported =
+            program_to_json(&program, &["main".to_string()], &["helper".to_string()], None);
+        let exported: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+        let field = |value: FieldElement| format!("0x{}", value.to_hex());
+        let expected = json!({
+            "schema_version": 1,
+            "functions": [{
+                "name": "main",
+                "current_witness_index": 2,
+                "private_parameters": [0, 1],
+                "public_parameters": [],
+                "return_values": [2],
+                "opcodes": [{
+                    "kind": "assert_zero",
+                    "expression": {
+                        "mul_terms": [{ "coefficient": field(FieldElement::one()), "lhs": 0, "rhs": 1 }],
+                        "linear_terms": [{ "coefficient": field(-FieldElement::one()), "witness": 2 }],
+                        "constant": field(FieldElement::from(5u128)),
+                    },
+                }],
+            }],
+            "unconstrained_functions": [{ "name": "helper", "bytecode_length": 0 }],
+        });
+        assert_eq!(exported, expected);
+    }
+
+    #[test]
+    fn call_costs_as_much_as_its_callee() {
+        let call = |id| Opcode::Call {
+            id: AcirFunctionId(id),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            predicate: None,
+        };
+        // w0 * w1 - w2 = 0
+        let two_terms = Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(0), Witness(1))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(2))],
+            q_c: FieldElement::zero(),
+        };
+        let circuit = |opcodes| Circuit { opcodes, ..Circuit::default() };
+        let program = Program {
+            functions: vec![
+                circuit(vec![call(1), Opcode::AssertZero(Expression::zero())]),
+                circuit(vec![Opcode::AssertZero(two_terms), call(2)]),
+                circuit(vec![Opcode::AssertZero(Expression::from(Witness(0)))]),
+            ],
+            unconstrained_functions: Vec::new(),
+        };
+
+        let names = ["main".to_string(), "foo".to_string(), "bar".to_string()];
+        let debug_infos = vec![DebugInfo::default(); 3];
+        let exported = program_to_json(&program, &names, &[], Some(&debug_infos));
+        let exported: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+        let costs = |function: usize| {
+            let opcodes = exported["functions"][function]["opcodes"].as_array().unwrap();
+            opcodes.iter().map(|opcode| opcode["cost"].as_u64().unwrap()).collect::<Vec<_>>()
+        };
+        assert_eq!(costs(2), vec![1]);
+        // The call to `bar` costs as much as `bar`
+        assert_eq!(costs(1), vec![2, 1]);
+        // The call to `foo` costs as much as `foo`, including its call to `bar`
+        assert_eq!(costs(0), vec![3, 1]);
+    }
+}
@@ -1,7 +1,7 @@
 //! This file holds the pass to convert from Noir's SSA IR to ACIR.
 
-use fxhash::FxHashMap as HashMap;
-use std::collections::{BTreeMap, HashSet};
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 use acvm::acir::{
@@ -16,12 +16,15 @@ use acvm::acir::{
 use acvm::{acir::circuit::opcodes::BlockId, acir::AcirField, FieldElement};
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use iter_extended::{try_vecmap, vecmap};
+use noirc_errors::Location;
 use noirc_frontend::monomorphization::ast::InlineType;
 
 mod acir_variable;
 mod big_int;
 mod brillig_directive;
 mod generated_acir;
+pub(crate) mod json_export;
+pub(crate) mod witness_graph;
 
 use crate::brillig::brillig_gen::gen_brillig_for;
 use crate::brillig::{
@@ -356,6 +359,50 @@ impl Ssa {
 
         Ok((acirs, brillig_bytecode, brillig_names, self.error_selector_to_type))
     }
+
+    /// Maps the location of each instruction to the name of the function holding it.
+    ///
+    /// Taken before inlining, this tells which function an instruction was written in from the
+    /// innermost location of its call stack, which comes first, even once it is inlined into
+    /// another function.
+    pub(crate) fn function_names_by_location(&self) -> HashMap<Location, String> {
+        let mut function_names = HashMap::default();
+        for function in self.functions.values() {
+            for block in function.reachable_blocks() {
+                for instruction in function.dfg[block].instructions() {
+                    let call_stack = function.dfg.get_instruction_call_stack(*instruction);
+                    if let Some(location) = call_stack.first() {
+                        function_names.insert(*location, function.name().to_owned());
+                    }
+                }
+            }
+        }
+        function_names
+    }
+}
+
+/// Returns the number of ACIR opcodes generated for each function, by name.
+///
+/// Each opcode is attributed to the function holding the innermost location of its call stack
+/// according to `function_names`, so that functions inlined into their callers are counted on
+/// their own. Opcodes without a known location are attributed to the ACIR function holding them,
+/// and calls to Brillig functions count as a single opcode.
+pub(crate) fn count_constraints_per_function(
+    acirs: &[GeneratedAcir<FieldElement>],
+    function_names: &HashMap<Location, String>,
+) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for acir in acirs {
+        for index in 0..acir.opcodes().len() {
+            let call_stack = acir.locations.get(&OpcodeLocation::Acir(index));
+            let function_name = call_stack
+                .and_then(|call_stack| call_stack.first())
+                .and_then(|location| function_names.get(location))
+                .unwrap_or(&acir.name);
+            *counts.entry(function_name.clone()).or_default() += 1;
+        }
+    }
+    counts
 }
 
 impl<'a> Context<'a> {
@@ -371,7 +418,7 @@ impl<'a> Context<'a> {
             ssa_values: HashMap::default(),
             current_side_effects_enabled_var,
             acir_context,
-            initialized_arrays: HashSet::new(),
+            initialized_arrays: HashSet::default(),
             memory_blocks: HashMap::default(),
             internal_memory_blocks: HashMap::default(),
             internal_mem_block_lengths: HashMap::default(),
@@ -1036,6 +1083,8 @@ impl<'a> Context<'a> {
             unreachable!("ICE: expected array or slice type");
         };
 
+        self.check_constant_index_in_bounds(&array_typ, index, dfg)?;
+
         if self.handle_constant_index_wrapper(instruction, dfg, array, index, store_value)? {
             return Ok(());
         }
@@ -1076,6 +1125,39 @@ impl<'a> Context<'a> {
         Ok(())
     }
 
+    /// Returns an error if `index` is a constant past the end of an array and the access is not
+    /// guarded by a predicate, as it would then always fail at runtime.
+    fn check_constant_index_in_bounds(
+        &self,
+        array_typ: &Type,
+        index: ValueId,
+        dfg: &DataFlowGraph,
+    ) -> Result<(), RuntimeError> {
+        let Type::Array(element_types, length) = array_typ else {
+            return Ok(());
+        };
+        let Some(index) = dfg.get_numeric_constant(index).and_then(|index| index.try_to_u64())
+        else {
+            return Ok(());
+        };
+        if element_types.is_empty()
+            || !self.acir_context.is_constant_one(&self.current_side_effects_enabled_var)
+        {
+            return Ok(());
+        }
+
+        // The index is flattened over the elements of each item of the array
+        let item_size = element_types.len() as u64;
+        if index >= u64::from(*length) * item_size {
+            return Err(RuntimeError::ArrayIndexOutOfBounds {
+                index: index / item_size,
+                length: *length,
+                call_stack: self.acir_context.get_call_stack(),
+            });
+        }
+        Ok(())
+    }
+
     fn handle_constant_index_wrapper(
         &mut self,
         instruction: InstructionId,
@@ -1980,7 +2062,7 @@ impl<'a> Context<'a> {
                 bit_count,
                 self.current_side_effects_enabled_var,
             ),
-            BinaryOp::Shl | BinaryOp::Shr => unreachable!(
+            BinaryOp::Shl { .. } | BinaryOp::Shr => unreachable!(
                 "ICE - bit shift operators do not exist in ACIR and should have been replaced"
             ),
         }?;
@@ -2863,13 +2945,13 @@ mod test {
         },
         FieldElement,
     };
-    use noirc_errors::Location;
+    use noirc_errors::{Location, Span};
     use noirc_frontend::monomorphization::ast::InlineType;
     use std::collections::BTreeMap;
 
     use crate::{
-        acir::BrilligStdlibFunc,
-        brillig::Brillig,
+        acir::{count_constraints_per_function, BrilligStdlibFunc},
+        brillig::{Brillig, BrilligOptions},
         ssa::{
             function_builder::FunctionBuilder,
             ir::{
@@ -3301,7 +3383,7 @@ mod test {
         build_basic_foo_with_return(&mut builder, bar_id, true, InlineType::default());
 
         let ssa = builder.finish();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let (acir_functions, brillig_functions, _, _) = ssa
             .generate_entry_point_index()
@@ -3440,7 +3522,7 @@ mod test {
 
         let ssa = builder.finish();
         // We need to generate  Brillig artifacts for the regular Brillig function and pass them to the ACIR generation pass.
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
         println!("{}", ssa);
 
         let (acir_functions, brillig_functions, _, _) = ssa
@@ -3529,7 +3611,7 @@ mod test {
 
         let ssa = builder.finish();
         // We need to generate  Brillig artifacts for the regular Brillig function and pass them to the ACIR generation pass.
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
         println!("{}", ssa);
 
         let (acir_functions, brillig_functions, _, _) = ssa
@@ -3646,7 +3728,7 @@ mod test {
             }
         ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let (mut acir_functions, _brillig_functions, _, _) = ssa
             .into_acir(&brillig, ExpressionWidth::default())
@@ -3665,4 +3747,64 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn counts_constraints_per_function() {
+        // acir(inline) fn main f0 {
+        //   b0(v0: Field, v1: Field):
+        //     v2 = call f1(v0, v1) -> Field
+        //     v3 = call f2(v2) -> Field
+        //     constrain v3 == v0
+        //     return
+        // }
+        // acir(inline) fn foo f1 {
+        //   b0(v0: Field, v1: Field):
+        //     v2 = mul v0, v1
+        //     constrain v2 == v1
+        //     return v2
+        // }
+        // brillig(inline) fn bar f2 {
+        //   b0(v0: Field):
+        //     return v0
+        // }
+        // Each function's instructions are at their own location
+        let location = |start| Location::new(Span::single_char(start), Default::default());
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+        builder.set_location(location(10));
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let foo = builder.import_function(Id::test_new(1));
+        let bar = builder.import_function(Id::test_new(2));
+        let v2 = builder.insert_call(foo, vec![v0, v1], vec![Type::field()])[0];
+        let v3 = builder.insert_call(bar, vec![v2], vec![Type::field()])[0];
+        builder.insert_constrain(v3, v0, None);
+        builder.terminate_with_return(vec![]);
+
+        builder.new_function("foo".into(), Id::test_new(1), InlineType::Inline);
+        builder.set_location(location(20));
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.insert_binary(v0, BinaryOp::Mul { unchecked: false }, v1);
+        builder.insert_constrain(v2, v1, None);
+        builder.terminate_with_return(vec![v2]);
+
+        builder.new_brillig_function("bar".into(), Id::test_new(2), InlineType::Inline);
+        builder.set_location(location(30));
+        let v0 = builder.add_parameter(Type::field());
+        builder.terminate_with_return(vec![v0]);
+
+        let ssa = builder.finish();
+        let function_names = ssa.function_names_by_location();
+        let ssa = ssa.inline_functions(i64::MAX).generate_entry_point_index();
+        assert_eq!(ssa.functions.len(), 2, "Expected foo to be inlined into main");
+
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
+        let (acir_functions, ..) = ssa.into_acir(&brillig, ExpressionWidth::default()).unwrap();
+
+        // foo's opcodes are attributed to it although it is inlined into main, while the call to
+        // bar counts as a single opcode of main
+        let counts = count_constraints_per_function(&acir_functions, &function_names);
+        let expected = BTreeMap::from([("foo".to_string(), 1), ("main".to_string(), 2)]);
+        assert_eq!(counts, expected);
+    }
 }
@@ -0,0 +1,101 @@
+//! Renders the witnesses of a circuit as a Graphviz DOT graph for debugging, connecting
+//! witnesses which are constrained together.
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use acvm::acir::{
+    circuit::{Circuit, Opcode},
+    native_types::Witness,
+};
+use acvm::FieldElement;
+
+/// Returns an undirected DOT graph with a node for each witness used in a constraint and an edge
+/// between each pair of witnesses which appear together in an `AssertZero` or black box opcode.
+///
+/// Brillig calls are not constraints, and memory and ACIR call opcodes are left out.
+pub(crate) fn witness_graph_to_dot(name: &str, circuit: &Circuit<FieldElement>) -> String {
+    let mut witnesses = BTreeSet::new();
+    let mut edges = BTreeSet::new();
+
+    for opcode in &circuit.opcodes {
+        let constrained: BTreeSet<Witness> = match opcode {
+            Opcode::AssertZero(expression) => {
+                let mul_witnesses =
+                    expression.mul_terms.iter().flat_map(|(_, lhs, rhs)| [*lhs, *rhs]);
+                let linear_witnesses =
+                    expression.linear_combinations.iter().map(|(_, witness)| *witness);
+                mul_witnesses.chain(linear_witnesses).collect()
+            }
+            Opcode::BlackBoxFuncCall(call) => {
+                let mut constrained = call.get_input_witnesses();
+                constrained.extend(call.get_outputs_vec());
+                constrained
+            }
+            _ => continue,
+        };
+
+        for (index, witness) in constrained.iter().enumerate() {
+            witnesses.insert(*witness);
+            for other in constrained.iter().skip(index + 1) {
+                edges.insert((*witness, *other));
+            }
+        }
+    }
+
+    let mut dot = String::new();
+    writeln!(dot, "graph \"{name}\" {{").unwrap();
+    for witness in &witnesses {
+        writeln!(dot, "  w{};", witness.0).unwrap();
+    }
+    for (witness, other) in &edges {
+        writeln!(dot, "  w{} -- w{};", witness.0, other.0).unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::{
+        acir::{
+            circuit::{Circuit, Opcode},
+            native_types::{Expression, Witness},
+        },
+        AcirField, FieldElement,
+    };
+
+    use super::witness_graph_to_dot;
+
+    #[test]
+    fn connects_witnesses_constrained_together() {
+        // w0 * w1 - w2 = 0 and w3 - w4 = 0
+        let product = Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(0), Witness(1))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(2))],
+            q_c: FieldElement::zero(),
+        };
+        let equality = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![
+                (FieldElement::one(), Witness(3)),
+                (-FieldElement::one(), Witness(4)),
+            ],
+            q_c: FieldElement::zero(),
+        };
+        let circuit = Circuit {
+            opcodes: vec![Opcode::AssertZero(product), Opcode::AssertZero(equality)],
+            ..Circuit::default()
+        };
+
+        let dot = witness_graph_to_dot("main", &circuit);
+
+        assert!(dot.starts_with("graph \"main\" {\n"));
+        for witness in 0..5 {
+            assert!(dot.contains(&format!("  w{witness};\n")));
+        }
+        for edge in ["w0 -- w1", "w0 -- w2", "w1 -- w2", "w3 -- w4"] {
+            assert!(dot.contains(&format!("  {edge};\n")), "missing edge {edge} in:\n{dot}");
+        }
+        assert_eq!(dot.matches(" -- ").count(), 4);
+    }
+}
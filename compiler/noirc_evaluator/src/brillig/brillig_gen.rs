@@ -14,7 +14,7 @@ use super::{
         artifact::{BrilligArtifact, BrilligParameter, GeneratedBrillig, Label},
         BrilligContext,
     },
-    Brillig,
+    Brillig, BrilligOptions,
 };
 use crate::{
     errors::InternalError,
@@ -24,9 +24,12 @@ use crate::{
 /// Converting an SSA function into Brillig bytecode.
 pub(crate) fn convert_ssa_function(
     func: &Function,
-    enable_debug_trace: bool,
+    options: &BrilligOptions,
 ) -> BrilligArtifact<FieldElement> {
-    let mut brillig_context = BrilligContext::new(enable_debug_trace);
+    let mut brillig_context = BrilligContext::new(options.enable_debug_trace);
+    brillig_context.set_register_reuse_strategy(options.register_reuse);
+    brillig_context.set_array_init_loop_threshold(options.array_init_loop_threshold);
+    brillig_context.set_procedure_inline_threshold(options.procedure_inline_threshold);
 
     let mut function_context = FunctionContext::new(func);
 
@@ -1336,7 +1336,7 @@ impl<'block> BrilligBlock<'block> {
             BinaryOp::And => BrilligBinaryOp::And,
             BinaryOp::Or => BrilligBinaryOp::Or,
             BinaryOp::Xor => BrilligBinaryOp::Xor,
-            BinaryOp::Shl => BrilligBinaryOp::Shl,
+            BinaryOp::Shl { .. } => BrilligBinaryOp::Shl,
             BinaryOp::Shr => {
                 if is_signed {
                     self.convert_signed_shr(left, right, result_variable);
@@ -1636,10 +1636,10 @@ impl<'block> BrilligBlock<'block> {
         }
 
         // If all the items are single address, and all have the same initial value, we can initialize the array in a runtime loop.
-        // Since the cost in instructions for a runtime loop is in the order of magnitude of 10, we only do this if the item_count is bigger than that.
+        // Since the loop has a fixed cost in instructions, we only do this if the item_count is above the configured threshold.
         let item_count = data.len() / item_types.len();
 
-        if item_count > 10
+        if item_count > self.brillig_context.array_init_loop_threshold()
             && is_repeating
             && item_types.iter().all(|typ| matches!(typ, Type::Numeric(_)))
         {
@@ -1910,7 +1910,7 @@ pub(crate) fn type_of_binary_operation(lhs_type: &Type, rhs_type: &Type, op: Bin
         // If both sides are numeric type, then we expect their types to be
         // the same.
         (Type::Numeric(lhs_type), Type::Numeric(rhs_type))
-            if op != BinaryOp::Shl && op != BinaryOp::Shr =>
+            if !matches!(op, BinaryOp::Shl { .. } | BinaryOp::Shr) =>
         {
             assert_eq!(
                 lhs_type, rhs_type,
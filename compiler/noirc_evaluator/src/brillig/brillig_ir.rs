@@ -21,13 +21,14 @@ mod codegen_control_flow;
 mod codegen_intrinsic;
 mod codegen_memory;
 mod codegen_stack;
+mod disassembly;
 mod entry_point;
 mod instructions;
 
 use artifact::Label;
 use brillig_variable::SingleAddrVariable;
 pub(crate) use instructions::BrilligBinaryOp;
-use registers::{RegisterAllocator, ScratchSpace};
+use registers::{RegisterAllocator, RegisterReuseStrategy, ScratchSpace};
 
 use self::{artifact::BrilligArtifact, debug_show::DebugToString, registers::Stack};
 use crate::ssa::ir::call_stack::CallStack;
@@ -36,6 +37,7 @@ use acvm::{
     AcirField,
 };
 use debug_show::DebugShow;
+use fxhash::FxHashMap as HashMap;
 
 use super::ProcedureId;
 
@@ -44,6 +46,10 @@ use super::ProcedureId;
 /// memory has 2^32 memory slots.
 pub(crate) const BRILLIG_MEMORY_ADDRESSING_BIT_SIZE: u32 = 32;
 
+/// Repeated constant arrays with more items than this are initialized in a runtime loop rather than
+/// item by item. The loop costs in the order of 10 instructions, so it only pays off above that.
+pub const DEFAULT_ARRAY_INIT_LOOP_THRESHOLD: usize = 10;
+
 // Registers reserved in runtime for special purposes.
 pub(crate) enum ReservedRegisters {
     /// This register stores the stack pointer. All relative memory addresses are relative to this pointer.
@@ -95,6 +101,14 @@ pub(crate) struct BrilligContext<F, Registers> {
     /// Whether this context can call procedures or not.
     /// This is used to prevent a procedure from calling another procedure.
     can_call_procedures: bool,
+    /// Whether deallocated registers are reused by later allocations
+    register_reuse: RegisterReuseStrategy,
+    /// Repeated constant arrays with more items than this are initialized in a runtime loop
+    array_init_loop_threshold: usize,
+    /// Procedures with at most this many opcodes are inlined instead of called
+    procedure_inline_threshold: usize,
+    /// Procedures compiled to be inlined, so that each one is compiled at most once
+    compiled_procedures: HashMap<ProcedureId, BrilligArtifact<F>>,
 }
 
 /// Regular brillig context to codegen user defined functions
@@ -108,6 +122,10 @@ impl<F: AcirField + DebugToString> BrilligContext<F, Stack> {
             next_section: 1,
             debug_show: DebugShow::new(enable_debug_trace),
             can_call_procedures: true,
+            register_reuse: RegisterReuseStrategy::default(),
+            array_init_loop_threshold: DEFAULT_ARRAY_INIT_LOOP_THRESHOLD,
+            procedure_inline_threshold: 0,
+            compiled_procedures: HashMap::default(),
         }
     }
 
@@ -209,11 +227,29 @@ impl<F: AcirField + DebugToString> BrilligContext<F, ScratchSpace> {
             next_section: 1,
             debug_show: DebugShow::new(enable_debug_trace),
             can_call_procedures: false,
+            register_reuse: RegisterReuseStrategy::default(),
+            array_init_loop_threshold: DEFAULT_ARRAY_INIT_LOOP_THRESHOLD,
+            procedure_inline_threshold: 0,
+            compiled_procedures: HashMap::default(),
         }
     }
 }
 
 impl<F: AcirField + DebugToString, Registers: RegisterAllocator> BrilligContext<F, Registers> {
+    /// Sets the number of items above which repeated constant arrays are initialized in a runtime loop.
+    pub(crate) fn set_array_init_loop_threshold(&mut self, threshold: usize) {
+        self.array_init_loop_threshold = threshold;
+    }
+
+    pub(crate) fn array_init_loop_threshold(&self) -> usize {
+        self.array_init_loop_threshold
+    }
+
+    /// Sets the number of opcodes up to which procedures are inlined instead of called.
+    pub(crate) fn set_procedure_inline_threshold(&mut self, threshold: usize) {
+        self.procedure_inline_threshold = threshold;
+    }
+
     /// Adds a brillig instruction to the brillig byte code
     fn push_opcode(&mut self, opcode: BrilligOpcode<F>) {
         self.obj.push_opcode(opcode);
@@ -1,10 +1,13 @@
 use acvm::acir::brillig::Opcode as BrilligOpcode;
 use acvm::acir::circuit::ErrorSelector;
-use std::collections::{BTreeMap, HashMap};
+use fxhash::FxHashMap as HashMap;
+use std::collections::BTreeMap;
 
 use crate::ssa::ir::{basic_block::BasicBlockId, call_stack::CallStack, function::FunctionId};
 use crate::ErrorType;
 
+use super::debug_show::DebugToString;
+use super::disassembly::disassemble_opcode;
 use super::procedures::ProcedureId;
 
 /// Represents a parameter or a return value of an entry point function.
@@ -64,6 +67,41 @@ pub(crate) struct BrilligArtifact<F> {
     pub(crate) procedure_locations: BTreeMap<ProcedureId, (OpcodeLocation, OpcodeLocation)>,
 }
 
+impl<F: DebugToString> BrilligArtifact<F> {
+    /// Renders the opcodes of this artifact as a listing, one opcode per line preceded by
+    /// its position and by the labels pointing to it.
+    ///
+    /// Jumps and calls which are not resolved yet show the label they target.
+    pub(crate) fn disassemble(&self) -> String {
+        let mut labels: BTreeMap<OpcodeLocation, Vec<String>> = BTreeMap::new();
+        for (label, position) in &self.labels {
+            labels.entry(*position).or_default().push(label.to_string());
+        }
+        let targets: HashMap<JumpInstructionPosition, &Label> = self
+            .unresolved_jumps
+            .iter()
+            .chain(&self.unresolved_external_call_labels)
+            .map(|(position, label)| (*position, label))
+            .collect();
+
+        let mut listing = String::new();
+        // Labels may also point right past the last opcode
+        for position in 0..=self.byte_code.len() {
+            if let Some(labels) = labels.get_mut(&position) {
+                labels.sort();
+                for label in labels {
+                    listing.push_str(&format!("{label}:\n"));
+                }
+            }
+            if let Some(opcode) = self.byte_code.get(position) {
+                let opcode = disassemble_opcode(opcode, targets.get(&position).copied());
+                listing.push_str(&format!("  {position}: {opcode}\n"));
+            }
+        }
+        listing
+    }
+}
+
 /// A pointer to a location in the opcode.
 pub(crate) type OpcodeLocation = usize;
 
@@ -162,11 +200,69 @@ impl<F: Clone + std::fmt::Debug> BrilligArtifact<F> {
         }
     }
 
+    /// Returns the number of opcodes this artifact would take once inlined in place of a call,
+    /// or None if it cannot be inlined.
+    ///
+    /// Only artifacts which make no calls and return at their very end can be inlined,
+    /// as the final `Return` is then simply dropped to fall through to the caller's code.
+    pub(crate) fn inlined_len(&self) -> Option<usize> {
+        if !self.unresolved_external_call_labels.is_empty() {
+            return None;
+        }
+        let (BrilligOpcode::Return, body) = self.byte_code.split_last()? else {
+            return None;
+        };
+        let returns_early = body.iter().any(|opcode| matches!(opcode, BrilligOpcode::Return));
+        let labels_within_body = self.labels.values().all(|position| *position <= body.len());
+        (!returns_early && labels_within_body).then_some(body.len())
+    }
+
+    /// Returns the labels defined in this artifact.
+    pub(crate) fn labels(&self) -> impl Iterator<Item = &Label> {
+        self.labels.keys()
+    }
+
+    /// Appends the opcodes of `procedure` in place of a call to it, see [Self::inlined_len].
+    ///
+    /// As a procedure may be inlined more than once, each of its labels is renamed
+    /// using `relabeling` so that they do not clash between copies.
+    pub(crate) fn inline(
+        &mut self,
+        procedure: &BrilligArtifact<F>,
+        relabeling: &HashMap<Label, Label>,
+    ) {
+        let offset = self.index_of_next_opcode();
+        for (label, position) in &procedure.labels {
+            self.add_label_at_position(relabeling[label].clone(), offset + position);
+        }
+        for (position, label) in &procedure.unresolved_jumps {
+            self.unresolved_jumps.push((offset + position, relabeling[label].clone()));
+        }
+        for (error_selector, error_type) in &procedure.error_types {
+            self.error_types.insert(*error_selector, error_type.clone());
+        }
+
+        let (_return, body) = procedure.byte_code.split_last().expect("procedure should return");
+        for opcode in body {
+            self.push_opcode(opcode.clone());
+        }
+    }
+
     /// Gets the first unresolved function call of this artifact.
     pub(crate) fn first_unresolved_function_call(&self) -> Option<Label> {
         self.unresolved_external_call_labels.first().map(|(_, label)| label.clone())
     }
 
+    /// Gets the procedures called from this artifact which are not linked in yet.
+    pub(crate) fn unresolved_procedure_calls(&self) -> impl Iterator<Item = ProcedureId> + '_ {
+        self.unresolved_external_call_labels.iter().filter_map(|(_, label)| {
+            match &label.label_type {
+                LabelType::Procedure(procedure_id) => Some(procedure_id.clone()),
+                _ => None,
+            }
+        })
+    }
+
     /// Link with an external brillig artifact called from this artifact.
     ///
     /// This method will offset the positions in the Brillig artifact to
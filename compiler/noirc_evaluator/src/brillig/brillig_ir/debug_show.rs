@@ -414,6 +414,11 @@ impl DebugShow {
         debug_println!(self.enable_debug_trace, "  CALL {}", func_label);
     }
 
+    /// Debug function for inlining a procedure instead of calling it
+    pub(crate) fn inline_procedure(&self, procedure: String) {
+        debug_println!(self.enable_debug_trace, "  INLINE {}", procedure);
+    }
+
     /// Debug function for calldata_copy
     pub(crate) fn calldata_copy_instruction(
         &self,
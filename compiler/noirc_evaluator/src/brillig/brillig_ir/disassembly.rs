@@ -0,0 +1,183 @@
+//! Renders Brillig opcodes as a readable listing of mnemonics and operands.
+//!
+//! Unlike [debug_show][super::debug_show], which traces the instructions of the brillig IR as
+//! they are generated, this works on the opcodes of an artifact, so it also covers the opcodes
+//! of procedures and of code which is patched after being generated.
+use acvm::acir::brillig::{BinaryFieldOp, BinaryIntOp, BitSize, Opcode as BrilligOpcode};
+
+use super::{artifact::Label, debug_show::DebugToString};
+
+/// Disassembles a single opcode.
+///
+/// `target` is the label targeted by the opcode if it is a jump or a call which is not resolved
+/// yet, in which case its location is only a placeholder and the label is shown instead.
+pub(crate) fn disassemble_opcode<F: DebugToString>(
+    opcode: &BrilligOpcode<F>,
+    target: Option<&Label>,
+) -> String {
+    let location = |location: &usize| match target {
+        Some(label) => label.to_string(),
+        None => location.to_string(),
+    };
+
+    match opcode {
+        BrilligOpcode::BinaryFieldOp { destination, op, lhs, rhs } => format!(
+            "{}.field {}, {}, {}",
+            binary_field_op_mnemonic(op),
+            destination.debug_to_string(),
+            lhs.debug_to_string(),
+            rhs.debug_to_string()
+        ),
+        BrilligOpcode::BinaryIntOp { destination, op, bit_size, lhs, rhs } => format!(
+            "{}.{bit_size} {}, {}, {}",
+            binary_int_op_mnemonic(op),
+            destination.debug_to_string(),
+            lhs.debug_to_string(),
+            rhs.debug_to_string()
+        ),
+        BrilligOpcode::Not { destination, source, bit_size } => {
+            format!(
+                "not.{bit_size} {}, {}",
+                destination.debug_to_string(),
+                source.debug_to_string()
+            )
+        }
+        BrilligOpcode::Cast { destination, source, bit_size } => format!(
+            "cast.{} {}, {}",
+            bit_size_suffix(bit_size),
+            destination.debug_to_string(),
+            source.debug_to_string()
+        ),
+        BrilligOpcode::JumpIfNot { condition, location: jump_location } => {
+            format!("jump_if_not {}, {}", condition.debug_to_string(), location(jump_location))
+        }
+        BrilligOpcode::JumpIf { condition, location: jump_location } => {
+            format!("jump_if {}, {}", condition.debug_to_string(), location(jump_location))
+        }
+        BrilligOpcode::Jump { location: jump_location } => {
+            format!("jump {}", location(jump_location))
+        }
+        BrilligOpcode::CalldataCopy { destination_address, size_address, offset_address } => {
+            format!(
+                "calldata_copy {}, {}, {}",
+                destination_address.debug_to_string(),
+                size_address.debug_to_string(),
+                offset_address.debug_to_string()
+            )
+        }
+        BrilligOpcode::Call { location: call_location } => {
+            format!("call {}", location(call_location))
+        }
+        BrilligOpcode::Const { destination, bit_size, value } => format!(
+            "const.{} {}, {}",
+            bit_size_suffix(bit_size),
+            destination.debug_to_string(),
+            value.debug_to_string()
+        ),
+        BrilligOpcode::IndirectConst { destination_pointer, bit_size, value } => format!(
+            "indirect_const.{} {}, {}",
+            bit_size_suffix(bit_size),
+            destination_pointer.debug_to_string(),
+            value.debug_to_string()
+        ),
+        BrilligOpcode::Return => "return".to_string(),
+        BrilligOpcode::ForeignCall { function, destinations, inputs, .. } => format!(
+            "foreign_call {function}({}) -> ({})",
+            inputs.debug_to_string(),
+            destinations.debug_to_string()
+        ),
+        BrilligOpcode::Mov { destination, source } => {
+            format!("mov {}, {}", destination.debug_to_string(), source.debug_to_string())
+        }
+        BrilligOpcode::ConditionalMov { destination, source_a, source_b, condition } => format!(
+            "cmov {}, {}, {}, {}",
+            destination.debug_to_string(),
+            condition.debug_to_string(),
+            source_a.debug_to_string(),
+            source_b.debug_to_string()
+        ),
+        BrilligOpcode::Load { destination, source_pointer } => {
+            format!("load {}, {}", destination.debug_to_string(), source_pointer.debug_to_string())
+        }
+        BrilligOpcode::Store { destination_pointer, source } => {
+            format!("store {}, {}", destination_pointer.debug_to_string(), source.debug_to_string())
+        }
+        BrilligOpcode::BlackBox(op) => format!("black_box {op:?}"),
+        BrilligOpcode::Trap { revert_data } => format!("trap {}", revert_data.debug_to_string()),
+        BrilligOpcode::Stop { return_data } => format!("stop {}", return_data.debug_to_string()),
+    }
+}
+
+fn binary_field_op_mnemonic(op: &BinaryFieldOp) -> &'static str {
+    match op {
+        BinaryFieldOp::Add => "add",
+        BinaryFieldOp::Sub => "sub",
+        BinaryFieldOp::Mul => "mul",
+        BinaryFieldOp::Div => "div",
+        BinaryFieldOp::IntegerDiv => "idiv",
+        BinaryFieldOp::Equals => "eq",
+        BinaryFieldOp::LessThan => "lt",
+        BinaryFieldOp::LessThanEquals => "lte",
+    }
+}
+
+fn binary_int_op_mnemonic(op: &BinaryIntOp) -> &'static str {
+    match op {
+        BinaryIntOp::Add => "add",
+        BinaryIntOp::Sub => "sub",
+        BinaryIntOp::Mul => "mul",
+        BinaryIntOp::Div => "div",
+        BinaryIntOp::Equals => "eq",
+        BinaryIntOp::LessThan => "lt",
+        BinaryIntOp::LessThanEquals => "lte",
+        BinaryIntOp::And => "and",
+        BinaryIntOp::Or => "or",
+        BinaryIntOp::Xor => "xor",
+        BinaryIntOp::Shl => "shl",
+        BinaryIntOp::Shr => "shr",
+    }
+}
+
+fn bit_size_suffix(bit_size: &BitSize) -> String {
+    match bit_size {
+        BitSize::Field => "field".to_string(),
+        BitSize::Integer(bit_size) => bit_size.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::{acir::brillig::MemoryAddress, FieldElement};
+
+    use crate::brillig::brillig_ir::{
+        brillig_variable::SingleAddrVariable, tests::create_context, BrilligBinaryOp,
+    };
+    use crate::ssa::ir::function::FunctionId;
+
+    #[test]
+    fn disassembles_brillig_function() {
+        let mut context = create_context(FunctionId::test_new(1));
+        let lhs = SingleAddrVariable::new(MemoryAddress::direct(10), 32);
+        let rhs = SingleAddrVariable::new(MemoryAddress::direct(11), 32);
+        let result = SingleAddrVariable::new(MemoryAddress::direct(12), 32);
+        context.const_instruction(lhs, FieldElement::from(3_u128));
+        context.binary_instruction(lhs, rhs, result, BrilligBinaryOp::Add);
+        context.codegen_if_not(result.address, |context| {
+            context.not_instruction(lhs, result);
+        });
+        context.return_instruction();
+
+        let listing = context.artifact().disassemble();
+        let expected = "\
+Function(Id(1), None):
+Function(Id(1), None) - 0:
+  0: const.u32 M10, 3
+  1: add.u32 M12, M10, M11
+  2: jump_if M12, Function(Id(1), None) - 1
+  3: not.u32 M12, M10
+Function(Id(1), None) - 1:
+  4: return
+";
+        assert_eq!(listing, expected);
+    }
+}
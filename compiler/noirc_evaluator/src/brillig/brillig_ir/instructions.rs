@@ -15,7 +15,7 @@ use super::{
     artifact::{Label, UnresolvedJumpLocation},
     brillig_variable::SingleAddrVariable,
     debug_show::DebugToString,
-    procedures::ProcedureId,
+    procedures::{compile_procedure, ProcedureId},
     registers::RegisterAllocator,
     BrilligContext, ReservedRegisters, BRILLIG_MEMORY_ADDRESSING_BIT_SIZE,
 };
@@ -194,7 +194,26 @@ impl<F: AcirField + DebugToString, Registers: RegisterAllocator> BrilligContext<
         self.obj.add_unresolved_external_call(BrilligOpcode::Call { location: 0 }, func_label);
     }
 
+    /// Adds a call to the given procedure, or the procedure body itself if it is small enough
+    /// to be inlined.
     pub(super) fn add_procedure_call_instruction(&mut self, procedure_id: ProcedureId) {
+        if self.procedure_inline_threshold > 0 {
+            let procedure = self
+                .compiled_procedures
+                .entry(procedure_id.clone())
+                .or_insert_with(|| compile_procedure(procedure_id.clone()));
+            if procedure.inlined_len().is_some_and(|len| len <= self.procedure_inline_threshold) {
+                self.debug_show.inline_procedure(procedure_id.to_string());
+                let labels: Vec<_> = procedure.labels().cloned().collect();
+                let relabeling = labels
+                    .into_iter()
+                    .map(|label| (label, self.reserve_next_section_label().1))
+                    .collect();
+                self.obj.inline(&self.compiled_procedures[&procedure_id], &relabeling);
+                return;
+            }
+        }
+
         let proc_label = Label::procedure(procedure_id);
         self.debug_show.add_external_call_instruction(proc_label.to_string());
         self.obj.add_unresolved_external_call(BrilligOpcode::Call { location: 0 }, proc_label);
@@ -11,6 +11,43 @@ use super::{
     BrilligContext, ReservedRegisters,
 };
 
+/// Whether registers which have been deallocated are handed out again by later allocations.
+///
+/// Reusing registers keeps stack frames small, which matters because calls save and restore
+/// every allocated register. Disabling reuse gives each value its own register at the cost of
+/// larger frames and more bytecode around calls.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterReuseStrategy {
+    /// Reuse deallocated registers before allocating fresh ones.
+    #[default]
+    Reuse,
+    /// Never reuse a register once it has been deallocated within a block.
+    NoReuse,
+}
+
+impl std::fmt::Display for RegisterReuseStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterReuseStrategy::Reuse => write!(f, "reuse"),
+            RegisterReuseStrategy::NoReuse => write!(f, "no-reuse"),
+        }
+    }
+}
+
+impl std::str::FromStr for RegisterReuseStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reuse" => Ok(RegisterReuseStrategy::Reuse),
+            "no-reuse" => Ok(RegisterReuseStrategy::NoReuse),
+            _ => Err(format!(
+                "Unknown register reuse strategy `{s}`, expected `reuse` or `no-reuse`"
+            )),
+        }
+    }
+}
+
 pub(crate) trait RegisterAllocator {
     /// First valid memory address
     fn start() -> usize;
@@ -224,8 +261,16 @@ impl<F, Registers: RegisterAllocator> BrilligContext<F, Registers> {
     }
 
     /// Push a register to the deallocation list, ready for reuse.
+    /// Does nothing if registers are not to be reused.
     pub(crate) fn deallocate_register(&mut self, register_index: MemoryAddress) {
-        self.registers.deallocate_register(register_index);
+        if self.register_reuse == RegisterReuseStrategy::Reuse {
+            self.registers.deallocate_register(register_index);
+        }
+    }
+
+    /// Sets whether deallocated registers are reused by later allocations.
+    pub(crate) fn set_register_reuse_strategy(&mut self, register_reuse: RegisterReuseStrategy) {
+        self.register_reuse = register_reuse;
     }
 
     /// Deallocates the address where the single address variable is stored
@@ -12,13 +12,46 @@ use self::{
     },
 };
 use crate::ssa::{
-    ir::function::{Function, FunctionId},
+    ir::{
+        call_graph::CallGraph,
+        function::{Function, FunctionId},
+    },
     ssa_gen::Ssa,
 };
 use fxhash::FxHashMap as HashMap;
-use std::{borrow::Cow, collections::BTreeSet};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
 
 pub use self::brillig_ir::procedures::ProcedureId;
+pub use self::brillig_ir::registers::RegisterReuseStrategy;
+pub use self::brillig_ir::DEFAULT_ARRAY_INIT_LOOP_THRESHOLD;
+
+/// Options controlling how SSA functions are compiled into Brillig bytecode.
+#[derive(Debug, Clone)]
+pub struct BrilligOptions {
+    /// Print the Brillig bytecode as it is generated
+    pub enable_debug_trace: bool,
+    /// Whether deallocated registers are reused by later allocations
+    pub register_reuse: RegisterReuseStrategy,
+    /// Repeated constant arrays with more items than this are initialized in a loop
+    pub array_init_loop_threshold: usize,
+    /// Procedures with at most this many opcodes are inlined into their callers when possible.
+    /// Zero disables inlining.
+    pub procedure_inline_threshold: usize,
+}
+
+impl Default for BrilligOptions {
+    fn default() -> Self {
+        Self {
+            enable_debug_trace: false,
+            register_reuse: RegisterReuseStrategy::default(),
+            array_init_loop_threshold: DEFAULT_ARRAY_INIT_LOOP_THRESHOLD,
+            procedure_inline_threshold: 0,
+        }
+    }
+}
 
 /// Context structure for the brillig pass.
 /// It stores brillig-related data required for brillig generation.
@@ -30,8 +63,8 @@ pub struct Brillig {
 
 impl Brillig {
     /// Compiles a function into brillig and store the compilation artifacts
-    pub(crate) fn compile(&mut self, func: &Function, enable_debug_trace: bool) {
-        let obj = convert_ssa_function(func, enable_debug_trace);
+    pub(crate) fn compile(&mut self, func: &Function, options: &BrilligOptions) {
+        let obj = convert_ssa_function(func, options);
         self.ssa_function_to_brillig.insert(func.id(), obj);
     }
 
@@ -49,6 +82,46 @@ impl Brillig {
             _ => unreachable!("ICE: Expected a function or procedure label"),
         }
     }
+
+    /// Returns the ids of every function compiled to Brillig, in ascending order.
+    pub(crate) fn compiled_function_ids(&self) -> impl Iterator<Item = FunctionId> {
+        let mut function_ids: Vec<_> = self.ssa_function_to_brillig.keys().copied().collect();
+        function_ids.sort();
+        function_ids.into_iter()
+    }
+
+    /// Disassembles the artifact of every compiled function, in the order of their ids,
+    /// followed by the artifacts of the procedures they call.
+    pub(crate) fn disassemble(&self) -> String {
+        let mut listings: Vec<_> = self
+            .compiled_function_ids()
+            .map(|id| {
+                let artifact = &self[id];
+                format!("brillig fn {} {id}:\n{}", artifact.name, artifact.disassemble())
+            })
+            .collect();
+
+        // Procedures are only compiled when linking, and may call other procedures in turn
+        let mut procedures = BTreeMap::new();
+        let mut pending: Vec<_> = self
+            .ssa_function_to_brillig
+            .values()
+            .flat_map(BrilligArtifact::unresolved_procedure_calls)
+            .collect();
+        while let Some(procedure_id) = pending.pop() {
+            if procedures.contains_key(&procedure_id) {
+                continue;
+            }
+            let artifact = compile_procedure(procedure_id.clone());
+            pending.extend(artifact.unresolved_procedure_calls());
+            procedures.insert(procedure_id, artifact);
+        }
+        listings.extend(procedures.iter().map(|(procedure_id, artifact)| {
+            format!("brillig procedure {procedure_id}:\n{}", artifact.disassemble())
+        }));
+
+        listings.join("\n")
+    }
 }
 
 impl std::ops::Index<FunctionId> for Brillig {
@@ -58,24 +131,324 @@ impl std::ops::Index<FunctionId> for Brillig {
     }
 }
 
+/// Why a function ended up being compiled to Brillig.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrilligReason {
+    /// The function is the entry point or is called from ACIR, so it was declared `unconstrained`.
+    Unconstrained,
+    /// The function is only called from other Brillig functions.
+    CalledFromBrillig,
+    /// The whole program was forced into Brillig.
+    Forced,
+}
+
+impl std::fmt::Display for BrilligReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BrilligReason::Unconstrained => write!(f, "unconstrained"),
+            BrilligReason::CalledFromBrillig => write!(f, "called from brillig"),
+            BrilligReason::Forced => write!(f, "forced"),
+        }
+    }
+}
+
 impl Ssa {
     /// Compile Brillig functions and ACIR functions reachable from them
     #[tracing::instrument(level = "trace", skip_all)]
-    pub(crate) fn to_brillig(&self, enable_debug_trace: bool) -> Brillig {
-        // Collect all the function ids that are reachable from brillig
-        // That means all the functions marked as brillig and ACIR functions called by them
-        let brillig_reachable_function_ids = self
-            .functions
-            .iter()
-            .filter_map(|(id, func)| func.runtime().is_brillig().then_some(*id))
-            .collect::<BTreeSet<_>>();
-
+    pub(crate) fn to_brillig(&self, options: &BrilligOptions) -> Brillig {
         let mut brillig = Brillig::default();
-        for brillig_function_id in brillig_reachable_function_ids {
+        for brillig_function_id in self.brillig_reachable_function_ids() {
             let func = &self.functions[&brillig_function_id];
-            brillig.compile(func, enable_debug_trace);
+            brillig.compile(func, options);
         }
 
         brillig
     }
+
+    /// Lists every function which is compiled to Brillig, along with the reason why.
+    ///
+    /// The monomorphizer gives functions called from an unconstrained context their own
+    /// unconstrained copy, so we can only tell these copies apart from functions the user marked
+    /// `unconstrained` by looking at their callers: only the latter are entered from ACIR.
+    pub(crate) fn brillig_function_reasons(
+        &self,
+        force_brillig: bool,
+    ) -> Vec<(FunctionId, BrilligReason)> {
+        let call_graph = CallGraph::from_ssa(self);
+        let mut entered_from_acir = BTreeSet::from([self.main_id]);
+        for (id, func) in &self.functions {
+            if func.runtime().is_acir() {
+                entered_from_acir.extend(call_graph.callees(*id));
+            }
+        }
+
+        self.brillig_reachable_function_ids()
+            .into_iter()
+            .map(|id| {
+                let reason = if force_brillig {
+                    BrilligReason::Forced
+                } else if entered_from_acir.contains(&id) {
+                    BrilligReason::Unconstrained
+                } else {
+                    BrilligReason::CalledFromBrillig
+                };
+                (id, reason)
+            })
+            .collect()
+    }
+
+    /// Collect all the function ids that are reachable from brillig
+    /// That means all the functions marked as brillig and ACIR functions called by them
+    fn brillig_reachable_function_ids(&self) -> BTreeSet<FunctionId> {
+        self.functions
+            .iter()
+            .filter_map(|(id, func)| func.runtime().is_brillig().then_some(*id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{ir::map::Id, ssa_gen::Ssa};
+
+    use acvm::{
+        acir::brillig::{MemoryAddress, Opcode},
+        FieldElement,
+    };
+
+    use super::{
+        brillig_gen::convert_ssa_function,
+        brillig_ir::artifact::{BrilligArtifact, Label},
+        BrilligOptions, BrilligReason, ProcedureId, RegisterReuseStrategy,
+    };
+
+    #[test]
+    fn lists_compiled_function_ids_in_order() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f3(v0) -> Field
+                v3 = call f1(v2) -> Field
+                return v3
+            }
+            brillig(inline) fn foo f1 {
+              b0(v0: Field):
+                return v0
+            }
+            acir(inline) fn bar f2 {
+              b0(v0: Field):
+                return v0
+            }
+            brillig(inline) fn baz f3 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
+
+        let function_ids: Vec<_> = brillig.compiled_function_ids().collect();
+        assert_eq!(function_ids, vec![Id::test_new(1), Id::test_new(3)]);
+    }
+
+    #[test]
+    fn disassembles_functions_and_the_procedures_they_call() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
+
+        // Every function starts by calling the `CheckMaxStackDepth` procedure
+        let listing = brillig.disassemble();
+        assert!(listing.starts_with("brillig fn main f0:\n"), "{listing}");
+        assert!(listing.contains("call Procedure(CheckMaxStackDepth)"), "{listing}");
+        assert!(listing.contains("\nbrillig procedure CheckMaxStackDepth:\n"), "{listing}");
+    }
+
+    #[test]
+    fn reports_unconstrained_functions_and_their_callees() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn foo f1 {
+              b0(v0: Field):
+                v2 = call f2(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn bar f2 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let reasons = ssa
+            .brillig_function_reasons(false)
+            .into_iter()
+            .map(|(id, reason)| (ssa.functions[&id].name().to_string(), reason))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                ("foo".to_string(), BrilligReason::Unconstrained),
+                ("bar".to_string(), BrilligReason::CalledFromBrillig),
+            ]
+        );
+
+        let forced = ssa.brillig_function_reasons(true);
+        assert!(forced.iter().all(|(_, reason)| *reason == BrilligReason::Forced));
+    }
+
+    /// Returns the memory addresses an opcode operates on directly, leaving out the operands of
+    /// foreign calls and black box functions.
+    fn opcode_addresses(opcode: &Opcode<FieldElement>) -> Vec<MemoryAddress> {
+        match opcode {
+            Opcode::BinaryFieldOp { destination, lhs, rhs, .. }
+            | Opcode::BinaryIntOp { destination, lhs, rhs, .. } => vec![*destination, *lhs, *rhs],
+            Opcode::Not { destination, source, .. }
+            | Opcode::Cast { destination, source, .. }
+            | Opcode::Mov { destination, source }
+            | Opcode::Load { destination, source_pointer: source }
+            | Opcode::Store { destination_pointer: destination, source } => {
+                vec![*destination, *source]
+            }
+            Opcode::ConditionalMov { destination, source_a, source_b, condition } => {
+                vec![*destination, *source_a, *source_b, *condition]
+            }
+            Opcode::CalldataCopy { destination_address, size_address, offset_address } => {
+                vec![*destination_address, *size_address, *offset_address]
+            }
+            Opcode::Const { destination, .. }
+            | Opcode::IndirectConst { destination_pointer: destination, .. }
+            | Opcode::JumpIf { condition: destination, .. }
+            | Opcode::JumpIfNot { condition: destination, .. } => vec![*destination],
+            Opcode::Trap { revert_data: data } | Opcode::Stop { return_data: data } => {
+                vec![data.pointer, data.size]
+            }
+            Opcode::Jump { .. }
+            | Opcode::Call { .. }
+            | Opcode::Return
+            | Opcode::ForeignCall { .. }
+            | Opcode::BlackBox(_) => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn register_reuse_strategy_changes_register_count() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field, v1: Field):
+                v2 = add v0, v1
+                v3 = mul v2, v2
+                v4 = add v3, v0
+                v5 = mul v4, v4
+                v6 = add v5, v1
+                v7 = mul v6, v6
+                return v7
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The number of registers is one past the highest stack frame offset used.
+        let register_count = |register_reuse| {
+            let options = BrilligOptions { register_reuse, ..BrilligOptions::default() };
+            let artifact = convert_ssa_function(ssa.main(), &options);
+            artifact
+                .byte_code
+                .iter()
+                .flat_map(opcode_addresses)
+                .filter_map(|address| match address {
+                    MemoryAddress::Relative(offset) => Some(offset),
+                    MemoryAddress::Direct(_) => None,
+                })
+                .max()
+                .unwrap()
+                + 1
+        };
+
+        let with_reuse = register_count(RegisterReuseStrategy::Reuse);
+        let without_reuse = register_count(RegisterReuseStrategy::NoReuse);
+        assert!(
+            with_reuse < without_reuse,
+            "expected fewer registers with reuse ({with_reuse}) than without ({without_reuse})"
+        );
+    }
+
+    #[test]
+    fn array_init_loop_threshold_selects_initialization_strategy() {
+        let items = vec!["Field 1"; 15].join(", ");
+        let src = format!(
+            "
+            brillig(inline) fn main f0 {{
+              b0():
+                v1 = make_array [{items}] : [Field; 15]
+                return v1
+            }}
+            "
+        );
+        let ssa = Ssa::from_str(&src).unwrap();
+
+        // Initializing the array item by item takes one store per item, while the loop only has one.
+        let store_count = |threshold| {
+            let options = BrilligOptions {
+                array_init_loop_threshold: threshold,
+                ..BrilligOptions::default()
+            };
+            let artifact = convert_ssa_function(ssa.main(), &options);
+            artifact
+                .byte_code
+                .iter()
+                .filter(|opcode| matches!(opcode, Opcode::Store { .. }))
+                .count()
+        };
+
+        let in_loop = store_count(5);
+        let item_by_item = store_count(20);
+        assert!(in_loop < 15, "expected a runtime loop, got {in_loop} stores");
+        assert!(item_by_item >= 15, "expected one store per item, got {item_by_item} stores");
+    }
+
+    #[test]
+    fn inlines_procedures_below_threshold() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // Every function starts by calling the small `CheckMaxStackDepth` procedure
+        let compile = |procedure_inline_threshold| {
+            let options =
+                BrilligOptions { procedure_inline_threshold, ..BrilligOptions::default() };
+            convert_ssa_function(ssa.main(), &options)
+        };
+        let calls = |artifact: &BrilligArtifact<FieldElement>| {
+            artifact.byte_code.iter().filter(|opcode| matches!(opcode, Opcode::Call { .. })).count()
+        };
+
+        let called = compile(0);
+        assert_eq!(
+            called.first_unresolved_function_call(),
+            Some(Label::procedure(ProcedureId::CheckMaxStackDepth))
+        );
+        assert_eq!(calls(&called), 1);
+
+        let inlined = compile(10);
+        assert_eq!(inlined.first_unresolved_function_call(), None);
+        assert_eq!(calls(&inlined), 0);
+        // The procedure's final `Return` is dropped, leaving only the function's own
+        assert_eq!(inlined.byte_code.len(), called.byte_code.len() - 1 + 5);
+
+        // Procedures above the threshold are still called
+        assert_eq!(calls(&compile(4)), 1);
+    }
 }
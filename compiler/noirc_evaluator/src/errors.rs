@@ -61,6 +61,18 @@ pub enum RuntimeError {
     UnconstrainedOracleReturnToConstrained { call_stack: CallStack },
     #[error("Could not resolve some references to the array. All references must be resolved at compile time")]
     UnknownReference { call_stack: CallStack },
+    #[error("Invalid SSA pass order: {message}")]
+    InvalidSsaPassOrder { message: String },
+    #[error("Cannot merge arrays of different lengths ({then_length} and {else_length}) in an if expression")]
+    MismatchedArrayLengths { then_length: u32, else_length: u32, call_stack: CallStack },
+    #[error("Cannot return references from an if expression whose condition is not known at compile-time")]
+    ReferenceInIfExpression { call_stack: CallStack },
+    #[error("Index {index} is out of bounds for an array of length {length}")]
+    ArrayIndexOutOfBounds { index: u64, length: u32, call_stack: CallStack },
+    #[error("Call data ids must be contiguous starting from 0, but no parameter uses `call_data({missing_id})`")]
+    NonContiguousCallDataIds { missing_id: u32, call_stack: CallStack },
+    #[error("Parameters sharing `call_data({call_data_id})` must have the same numeric types")]
+    MismatchedCallDataTypes { call_data_id: u32, call_stack: CallStack },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
@@ -81,6 +93,9 @@ impl From<SsaReport> for FileDiagnostic {
                     InternalWarning::VerifyProof { call_stack } => {
                         ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
                     },
+                    InternalWarning::PrintCall { call_stack } => {
+                        ("This call prints output whenever the program is executed. Consider removing it before releasing the program".to_string(), call_stack)
+                    },
                 };
                 let call_stack = vecmap(call_stack, |location| location);
                 let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
@@ -116,6 +131,8 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("Print call left in the program")]
+    PrintCall { call_stack: CallStack },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error, Serialize, Deserialize, Hash)]
@@ -144,8 +161,20 @@ pub enum InternalError {
     UndeclaredAcirVar { call_stack: CallStack },
     #[error("ICE: Expected {expected:?}, found {found:?}")]
     Unexpected { expected: String, found: String, call_stack: CallStack },
+    #[error("ICE: Operands of `{operator}` have mismatched types `{lhs_type}` and `{rhs_type}`")]
+    MismatchedBinaryOperands {
+        operator: String,
+        lhs_type: String,
+        rhs_type: String,
+        call_stack: CallStack,
+    },
+    #[error("ICE: A reference remains in ACIR function `{function}` after mem2reg")]
+    ReferenceAfterMem2Reg { function: String, call_stack: CallStack },
 }
 
+/// The call stack of errors which do not originate from the program being compiled.
+static EMPTY_CALL_STACK: CallStack = CallStack::new();
+
 impl RuntimeError {
     fn call_stack(&self) -> &CallStack {
         match self {
@@ -156,7 +185,9 @@ impl RuntimeError {
                 | InternalError::MissingArg { call_stack, .. }
                 | InternalError::NotAConstant { call_stack, .. }
                 | InternalError::UndeclaredAcirVar { call_stack }
-                | InternalError::Unexpected { call_stack, .. },
+                | InternalError::Unexpected { call_stack, .. }
+                | InternalError::MismatchedBinaryOperands { call_stack, .. }
+                | InternalError::ReferenceAfterMem2Reg { call_stack, .. },
             )
             | RuntimeError::InvalidRangeConstraint { call_stack, .. }
             | RuntimeError::TypeConversion { call_stack, .. }
@@ -173,7 +204,13 @@ impl RuntimeError {
             | RuntimeError::BigIntModulus { call_stack, .. }
             | RuntimeError::UnconstrainedSliceReturnToConstrained { call_stack }
             | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack }
-            | RuntimeError::UnknownReference { call_stack } => call_stack,
+            | RuntimeError::UnknownReference { call_stack }
+            | RuntimeError::MismatchedArrayLengths { call_stack, .. }
+            | RuntimeError::ReferenceInIfExpression { call_stack }
+            | RuntimeError::ArrayIndexOutOfBounds { call_stack, .. }
+            | RuntimeError::NonContiguousCallDataIds { call_stack, .. }
+            | RuntimeError::MismatchedCallDataTypes { call_stack, .. } => call_stack,
+            RuntimeError::InvalidSsaPassOrder { .. } => &EMPTY_CALL_STACK,
         }
     }
 }
@@ -198,6 +235,11 @@ impl RuntimeError {
                     noirc_errors::Span::inclusive(0, 0)
                 )
             }
+            RuntimeError::InvalidSsaPassOrder { .. } => Diagnostic::simple_error(
+                self.to_string(),
+                String::new(),
+                noirc_errors::Span::inclusive(0, 0),
+            ),
             RuntimeError::UnknownLoopBound { .. } => {
                 let primary_message = self.to_string();
                 let location =
@@ -10,7 +10,7 @@ pub mod brillig;
 pub mod ssa;
 
 pub use ssa::create_program;
-pub use ssa::ir::instruction::ErrorType;
+pub use ssa::ir::instruction::{is_intrinsic, ErrorType};
 
 /// Trims leading whitespace from each line of the input string
 #[cfg(test)]
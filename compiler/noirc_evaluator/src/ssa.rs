@@ -10,10 +10,13 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     fs::File,
     io::Write,
+    panic::{catch_unwind, AssertUnwindSafe},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
-use crate::errors::{RuntimeError, SsaReport};
+use crate::brillig::{BrilligOptions, BrilligReason, RegisterReuseStrategy};
+use crate::errors::{InternalError, RuntimeError, SsaReport};
 use acvm::{
     acir::{
         circuit::{
@@ -25,20 +28,28 @@ use acvm::{
     FieldElement,
 };
 
+use fxhash::FxHashMap as HashMap;
+use ir::call_stack::CallStack;
 use ir::instruction::ErrorType;
+use iter_extended::vecmap;
 use noirc_errors::debug_info::{DebugFunctions, DebugInfo, DebugTypes, DebugVariables};
+use noirc_errors::Location;
 
 use noirc_frontend::ast::Visibility;
 use noirc_frontend::{hir_def::function::FunctionSignature, monomorphization::ast::Program};
 use ssa_gen::Ssa;
 use tracing::{span, Level};
 
-use crate::acir::{Artifacts, GeneratedAcir};
+use crate::acir::{
+    count_constraints_per_function, json_export::program_to_json,
+    witness_graph::witness_graph_to_dot, Artifacts, GeneratedAcir,
+};
 
 mod checks;
 pub(super) mod function_builder;
 pub mod ir;
 mod opt;
+pub use opt::flatten_cfg::value_merger::DEFAULT_MAX_ARRAY_SET_LOOKBACK;
 #[cfg(test)]
 pub(crate) mod parser;
 pub mod ssa_gen;
@@ -50,6 +61,10 @@ pub enum SsaLogging {
     Contains(String),
 }
 
+/// Receives the name of each SSA pass once it has run, along with its index in the pipeline
+/// (starting at zero) and the total number of passes in the pipeline.
+pub type PassProgressCallback = Arc<dyn Fn(&str, usize, usize) + Send + Sync>;
+
 pub struct SsaEvaluatorOptions {
     /// Emit debug information for the intermediate SSA IR
     pub ssa_logging: SsaLogging,
@@ -65,6 +80,25 @@ pub struct SsaEvaluatorOptions {
     /// Dump the unoptimized SSA to the supplied path if it exists
     pub emit_ssa: Option<PathBuf>,
 
+    /// Write the control flow graph of each optimized function as a Graphviz DOT file,
+    /// next to the supplied path if it exists
+    pub emit_cfg: Option<PathBuf>,
+
+    /// Write a graph connecting the witnesses constrained together in each final circuit
+    /// as a Graphviz DOT file, next to the supplied path if it exists
+    pub emit_witness_graph: Option<PathBuf>,
+
+    /// Write the final ACIR program as JSON following the schema documented in
+    /// `acir::json_export`, next to the supplied path if it exists
+    pub emit_acir_json: Option<PathBuf>,
+
+    /// Annotate each opcode of the emitted ACIR JSON with its source locations and estimated cost
+    pub annotate_acir_json: bool,
+
+    /// Write the emitted files into this directory rather than next to their supplied paths,
+    /// keeping the file names derived from those paths
+    pub artifact_dir: Option<PathBuf>,
+
     /// Skip the check for under constrained values
     pub skip_underconstrained_check: bool,
 
@@ -78,9 +112,71 @@ pub struct SsaEvaluatorOptions {
     /// When `None` the size increase check is skipped altogether and any decrease in the SSA
     /// instruction count is accepted.
     pub max_bytecode_increase_percent: Option<i32>,
+
+    /// Run the SSA passes in the order listed in the supplied file (one pass name per line)
+    /// instead of the default order.
+    pub ssa_pass_order: Option<PathBuf>,
+
+    /// Skip the SSA passes whose name starts with any of these, ignoring case.
+    /// Names can also be given as printed with the SSA, such as `After Mem2Reg:`.
+    pub disabled_passes: Vec<String>,
+
+    /// List every function with a Brillig runtime before any SSA pass runs, along with the
+    /// reason why, in [SsaProgramArtifact::brillig_function_reasons]
+    pub report_unconstrained_functions: bool,
+
+    /// Whether the whole program was forced into Brillig, used when reporting
+    /// why functions were compiled to Brillig
+    pub force_brillig: bool,
+
+    /// Whether Brillig codegen reuses deallocated registers
+    pub brillig_register_reuse: RegisterReuseStrategy,
+
+    /// Repeated constant arrays with more items than this are initialized in a loop in Brillig
+    pub brillig_array_init_loop_threshold: usize,
+
+    /// How many `array_set` instructions are looked back at when merging two arrays, to only
+    /// merge the indices changed since their common ancestor
+    pub max_array_set_lookback: usize,
+
+    /// Brillig procedures with at most this many opcodes are inlined into their callers
+    /// when possible, zero disables inlining
+    pub brillig_procedure_inline_threshold: usize,
+
+    /// Warn about calls to `print` left in the program
+    pub warn_on_print: bool,
+
+    /// Called after each pass of the SSA pipeline, for example to report compilation progress
+    pub pass_progress: Option<PassProgressCallback>,
+
+    /// Turn panics raised while optimizing and generating code into internal errors
+    /// instead of unwinding out of the compiler
+    pub convert_panics_to_errors: bool,
+
+    /// Check that no references are left in ACIR functions after the last run of mem2reg
+    pub verify_no_references_after_mem2reg: bool,
+
+    /// Print the disassembled opcodes of every function compiled to Brillig
+    pub show_brillig_disassembly: bool,
+
+    /// Print the number of ACIR opcodes generated for each function once lowered
+    pub show_constraint_counts: bool,
+
+    /// Find this many of the largest constant arrays in the optimized program,
+    /// returned in [SsaProgramArtifact::largest_constant_arrays]
+    pub show_largest_constant_arrays: Option<usize>,
+
+    /// Split the blocks of Brillig functions with more instructions than this into a chain
+    /// of smaller blocks, `None` leaves blocks as they are
+    pub max_brillig_block_size: Option<usize>,
 }
 
-pub(crate) struct ArtifactsAndWarnings(Artifacts, Vec<SsaReport>);
+pub(crate) struct ArtifactsAndWarnings(
+    Artifacts,
+    Vec<SsaReport>,
+    Vec<(String, BrilligReason)>,
+    Vec<(u32, Location)>,
+);
 
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
@@ -97,11 +193,33 @@ pub(crate) fn optimize_into_acir(
         program,
         options.ssa_logging.clone(),
         options.print_codegen_timings,
-        &options.emit_ssa,
+        options.disabled_passes.clone(),
+        &options.emit_ssa.as_deref().map(|path| options.emit_path(path)),
     )?;
 
+    // Functions are named by location before they are inlined into each other
+    let function_names =
+        options.show_constraint_counts.then(|| builder.ssa.function_names_by_location());
+
+    // Runtimes are separated by the monomorphizer, so the reasons are found before any pass
+    // inlines or removes Brillig functions
+    let brillig_function_reasons = if options.report_unconstrained_functions {
+        let ssa = &builder.ssa;
+        vecmap(ssa.brillig_function_reasons(options.force_brillig), |(id, reason)| {
+            (ssa.functions[&id].name().to_owned(), reason)
+        })
+    } else {
+        Vec::new()
+    };
+
     let mut ssa = optimize_all(builder, options)?;
 
+    ssa.check_for_mismatched_binary_operands()?;
+
+    if let Some(emit_cfg) = &options.emit_cfg {
+        emit_cfg_files(&ssa, &options.emit_path(emit_cfg));
+    }
+
     let mut ssa_level_warnings = vec![];
 
     if !options.skip_underconstrained_check {
@@ -120,12 +238,30 @@ pub(crate) fn optimize_into_acir(
         ));
     };
 
+    if options.warn_on_print {
+        ssa_level_warnings.extend(ssa.check_for_print_calls());
+    }
+
+    let largest_constant_arrays = options
+        .show_largest_constant_arrays
+        .map(|count| ssa.largest_constant_arrays(count))
+        .unwrap_or_default();
+
     drop(ssa_gen_span_guard);
 
     let brillig = time("SSA to Brillig", options.print_codegen_timings, || {
-        ssa.to_brillig(options.enable_brillig_logging)
+        ssa.to_brillig(&BrilligOptions {
+            enable_debug_trace: options.enable_brillig_logging,
+            register_reuse: options.brillig_register_reuse,
+            array_init_loop_threshold: options.brillig_array_init_loop_threshold,
+            procedure_inline_threshold: options.brillig_procedure_inline_threshold,
+        })
     });
 
+    if options.show_brillig_disassembly {
+        println!("{}", brillig.disassemble());
+    }
+
     let ssa_gen_span = span!(Level::TRACE, "ssa_generation");
     let ssa_gen_span_guard = ssa_gen_span.enter();
 
@@ -133,6 +269,7 @@ pub(crate) fn optimize_into_acir(
         ssa,
         ssa_logging: options.ssa_logging.clone(),
         print_codegen_timings: options.print_codegen_timings,
+        disabled_passes: options.disabled_passes.clone(),
     }
     .run_pass(|ssa| ssa.fold_constants_with_brillig(&brillig), "Inlining Brillig Calls Inlining")
     .run_pass(Ssa::dead_instruction_elimination, "Dead Instruction Elimination (2nd)")
@@ -144,52 +281,229 @@ pub(crate) fn optimize_into_acir(
         ssa.into_acir(&brillig, options.expression_width)
     })?;
 
-    Ok(ArtifactsAndWarnings(artifacts, ssa_level_warnings))
+    if let Some(function_names) = function_names {
+        print_constraint_counts(&artifacts.0, &function_names);
+    }
+
+    Ok(ArtifactsAndWarnings(
+        artifacts,
+        ssa_level_warnings,
+        brillig_function_reasons,
+        largest_constant_arrays,
+    ))
+}
+
+/// Print the number of ACIR opcodes generated for each function, and their total.
+fn print_constraint_counts(
+    acirs: &[GeneratedAcir<FieldElement>],
+    function_names: &HashMap<Location, String>,
+) {
+    let counts = count_constraints_per_function(acirs, function_names);
+    println!("Constraint counts:");
+    for (name, count) in &counts {
+        println!("  {name}: {count}");
+    }
+    println!("  total: {}", counts.values().sum::<usize>());
+}
+
+/// Runs `f`, turning a panic inside it into an [InternalError] carrying the panic message.
+fn catch_panics<T>(f: impl FnOnce() -> Result<T, RuntimeError>) -> Result<T, RuntimeError> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "compiler panicked".to_string()
+        };
+        Err(InternalError::General { message, call_stack: CallStack::new() }.into())
+    })
 }
 
 /// Run all SSA passes.
 fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ssa, RuntimeError> {
-    Ok(builder
-        .run_pass(Ssa::remove_unreachable_functions, "Removing Unreachable Functions")
-        .run_pass(Ssa::defunctionalize, "Defunctionalization")
-        .run_pass(Ssa::remove_paired_rc, "Removing Paired rc_inc & rc_decs")
-        .run_pass(|ssa| ssa.inline_functions(options.inliner_aggressiveness), "Inlining (1st)")
+    let passes = primary_passes(options);
+    let passes = match &options.ssa_pass_order {
+        Some(path) => pin_pass_order(&passes, &read_ssa_pass_order(path)?)?,
+        None => passes.iter().collect(),
+    };
+    Ok(builder.run_passes(&passes, options.pass_progress.as_ref())?.finish())
+}
+
+/// An SSA pass reified as a construct we can put into a list,
+/// so that the pipeline can be reordered from a configuration.
+pub(crate) struct SsaPass<'a> {
+    msg: &'static str,
+    run: Box<dyn Fn(Ssa) -> Result<Ssa, RuntimeError> + 'a>,
+}
+
+impl<'a> SsaPass<'a> {
+    fn new<F>(f: F, msg: &'static str) -> Self
+    where
+        F: Fn(Ssa) -> Ssa + 'a,
+    {
+        Self::new_try(move |ssa| Ok(f(ssa)), msg)
+    }
+
+    fn new_try<F>(f: F, msg: &'static str) -> Self
+    where
+        F: Fn(Ssa) -> Result<Ssa, RuntimeError> + 'a,
+    {
+        Self { msg, run: Box::new(f) }
+    }
+}
+
+/// The default SSA pass pipeline, in the order in which the passes are run.
+/// Each pass has a unique name, by which it is referred to in a pinned pass order.
+fn primary_passes(options: &SsaEvaluatorOptions) -> Vec<SsaPass> {
+    let mut passes = vec![
+        SsaPass::new(Ssa::remove_unreachable_functions, "Removing Unreachable Functions (1st)"),
+        SsaPass::new(Ssa::deduplicate_functions, "Deduplicating Functions"),
+        SsaPass::new(Ssa::defunctionalize, "Defunctionalization"),
+        SsaPass::new(Ssa::remove_paired_rc, "Removing Paired rc_inc & rc_decs"),
+        SsaPass::new(|ssa| ssa.inline_functions(options.inliner_aggressiveness), "Inlining (1st)"),
         // Run mem2reg with the CFG separated into blocks
-        .run_pass(Ssa::mem2reg, "Mem2Reg (1st)")
-        .run_pass(Ssa::simplify_cfg, "Simplifying (1st)")
-        .run_pass(Ssa::as_slice_optimization, "`as_slice` optimization")
-        .run_pass(Ssa::remove_unreachable_functions, "Removing Unreachable Functions")
-        .try_run_pass(
+        SsaPass::new(Ssa::mem2reg, "Mem2Reg (1st)"),
+        SsaPass::new(Ssa::simplify_cfg, "Simplifying (1st)"),
+        SsaPass::new(Ssa::as_slice_optimization, "`as_slice` optimization"),
+        SsaPass::new(Ssa::remove_unreachable_functions, "Removing Unreachable Functions (2nd)"),
+        SsaPass::new_try(
             Ssa::evaluate_static_assert_and_assert_constant,
             "`static_assert` and `assert_constant`",
-        )?
-        .run_pass(Ssa::loop_invariant_code_motion, "Loop Invariant Code Motion")
-        .try_run_pass(
+        ),
+        SsaPass::new(Ssa::loop_invariant_code_motion, "Loop Invariant Code Motion"),
+        SsaPass::new_try(
             |ssa| ssa.unroll_loops_iteratively(options.max_bytecode_increase_percent),
             "Unrolling",
-        )?
-        .run_pass(Ssa::simplify_cfg, "Simplifying (2nd)")
-        .run_pass(Ssa::mem2reg, "Mem2Reg (2nd)")
-        .run_pass(Ssa::flatten_cfg, "Flattening")
-        .run_pass(Ssa::remove_bit_shifts, "Removing Bit Shifts")
+        ),
+        SsaPass::new(Ssa::simplify_cfg, "Simplifying (2nd)"),
+        SsaPass::new(Ssa::mem2reg, "Mem2Reg (2nd)"),
+        SsaPass::new(Ssa::flatten_cfg, "Flattening"),
+        SsaPass::new(Ssa::remove_bit_shifts, "Removing Bit Shifts"),
         // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
-        .run_pass(Ssa::mem2reg, "Mem2Reg (3rd)")
+        SsaPass::new_try(
+            |ssa| {
+                let ssa = ssa.mem2reg();
+                if options.verify_no_references_after_mem2reg {
+                    ssa.verify_no_references_after_mem2reg()?;
+                }
+                Ok(ssa)
+            },
+            "Mem2Reg (3rd)",
+        ),
         // Run the inlining pass again to handle functions with `InlineType::NoPredicates`.
         // Before flattening is run, we treat functions marked with the `InlineType::NoPredicates` as an entry point.
         // This pass must come immediately following `mem2reg` as the succeeding passes
         // may create an SSA which inlining fails to handle.
-        .run_pass(
+        SsaPass::new(
             |ssa| ssa.inline_functions_with_no_predicates(options.inliner_aggressiveness),
             "Inlining (2nd)",
-        )
-        .run_pass(Ssa::remove_if_else, "Remove IfElse")
-        .run_pass(Ssa::fold_constants, "Constant Folding")
-        .run_pass(Ssa::remove_enable_side_effects, "EnableSideEffectsIf removal")
-        .run_pass(Ssa::fold_constants_using_constraints, "Constraint Folding")
-        .run_pass(Ssa::dead_instruction_elimination, "Dead Instruction Elimination (1st)")
-        .run_pass(Ssa::simplify_cfg, "Simplifying:")
-        .run_pass(Ssa::array_set_optimization, "Array Set Optimizations")
-        .finish())
+        ),
+        SsaPass::new_try(|ssa| ssa.remove_if_else(options.max_array_set_lookback), "Remove IfElse"),
+        SsaPass::new(Ssa::fold_constants, "Constant Folding"),
+        SsaPass::new(Ssa::remove_enable_side_effects, "EnableSideEffectsIf removal"),
+        SsaPass::new(Ssa::fold_constants_using_constraints, "Constraint Folding"),
+        SsaPass::new(Ssa::dead_instruction_elimination, "Dead Instruction Elimination (1st)"),
+        SsaPass::new(Ssa::simplify_cfg, "Simplifying (3rd)"),
+        SsaPass::new(Ssa::array_set_optimization, "Array Set Optimizations"),
+        // The second inlining and the folding passes may have removed the last call to
+        // some functions, which would otherwise still be compiled to Brillig or ACIR.
+        SsaPass::new(Ssa::remove_unreachable_functions, "Removing Unreachable Functions (3rd)"),
+    ];
+
+    if let Some(max_block_size) = options.max_brillig_block_size {
+        passes.push(SsaPass::new(
+            move |ssa| ssa.split_large_blocks(max_block_size),
+            "Splitting Large Blocks",
+        ));
+    }
+    passes
+}
+
+/// Pairs of passes where the first pass must run before the second one for the resulting SSA
+/// to be valid input to ACIR and Brillig generation. Both passes of each pair are mandatory
+/// in a pinned pass order.
+const SSA_PASS_ORDERING_CONSTRAINTS: &[(&str, &str)] = &[
+    ("Defunctionalization", "Inlining (1st)"),
+    ("Unrolling", "Flattening"),
+    ("Flattening", "Removing Bit Shifts"),
+    ("Flattening", "Inlining (2nd)"),
+    ("Flattening", "Remove IfElse"),
+    // ACIR and Brillig generation expect every `assert_constant` and `static_assert` call to
+    // have been evaluated and removed, and evaluating them needs the unflattened CFG.
+    ("`static_assert` and `assert_constant`", "Flattening"),
+];
+
+/// Pairs of passes where the second pass must run immediately after the first one in a pinned
+/// pass order, as other passes may create an SSA which the second pass fails to handle.
+/// Both passes of each pair are mandatory in a pinned pass order.
+const SSA_PASS_ADJACENCY_CONSTRAINTS: &[(&str, &str)] = &[("Mem2Reg (3rd)", "Inlining (2nd)")];
+
+/// Reads a pinned SSA pass order from the file at `path`.
+fn read_ssa_pass_order(path: &Path) -> Result<Vec<String>, RuntimeError> {
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        let message = format!("could not read {}: {err}", path.display());
+        RuntimeError::InvalidSsaPassOrder { message }
+    })?;
+    Ok(parse_ssa_pass_order(&contents))
+}
+
+/// Parses a pinned SSA pass order, which lists one pass name per line.
+/// Empty lines and lines starting with `#` are ignored.
+fn parse_ssa_pass_order(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Arranges `passes` in the given order of pass names, checking that each pass is listed once
+/// and that the order satisfies all of the [SSA_PASS_ORDERING_CONSTRAINTS] and
+/// [SSA_PASS_ADJACENCY_CONSTRAINTS].
+fn pin_pass_order<'a, 'b>(
+    passes: &'b [SsaPass<'a>],
+    pass_order: &[String],
+) -> Result<Vec<&'b SsaPass<'a>>, RuntimeError> {
+    let error = |message| RuntimeError::InvalidSsaPassOrder { message };
+
+    let pinned_passes = pass_order
+        .iter()
+        .map(|name| {
+            passes
+                .iter()
+                .find(|pass| pass.msg == name)
+                .ok_or_else(|| error(format!("unknown SSA pass `{name}`")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (index, name) in pass_order.iter().enumerate() {
+        if pass_order[..index].contains(name) {
+            return Err(error(format!("SSA pass `{name}` is listed more than once")));
+        }
+    }
+
+    let position = |name: &str| pass_order.iter().position(|pass| pass == name);
+    let positions = |before: &str, after: &str| match (position(before), position(after)) {
+        (Some(before_position), Some(after_position)) => Ok((before_position, after_position)),
+        (None, _) => Err(error(format!("mandatory SSA pass `{before}` is missing"))),
+        (_, None) => Err(error(format!("mandatory SSA pass `{after}` is missing"))),
+    };
+    for (before, after) in SSA_PASS_ORDERING_CONSTRAINTS {
+        let (before_position, after_position) = positions(before, after)?;
+        if before_position > after_position {
+            return Err(error(format!("SSA pass `{before}` must run before `{after}`")));
+        }
+    }
+    for (before, after) in SSA_PASS_ADJACENCY_CONSTRAINTS {
+        let (before_position, after_position) = positions(before, after)?;
+        if before_position + 1 != after_position {
+            return Err(error(format!("SSA pass `{after}` must run immediately after `{before}`")));
+        }
+    }
+
+    Ok(pinned_passes)
 }
 
 // Helper to time SSA passes
@@ -215,6 +529,12 @@ pub struct SsaProgramArtifact {
     pub names: Vec<String>,
     pub brillig_names: Vec<String>,
     pub error_types: BTreeMap<ErrorSelector, ErrorType>,
+    /// The name of each function compiled to Brillig, along with the reason why,
+    /// if requested with [SsaEvaluatorOptions::report_unconstrained_functions]
+    pub brillig_function_reasons: Vec<(String, BrilligReason)>,
+    /// The number of elements of the largest constant arrays and the location creating each of
+    /// them, if requested with [SsaEvaluatorOptions::show_largest_constant_arrays]
+    pub largest_constant_arrays: Vec<(u32, Location)>,
 }
 
 impl SsaProgramArtifact {
@@ -232,6 +552,8 @@ impl SsaProgramArtifact {
             names: Vec::default(),
             brillig_names: Vec::default(),
             error_types,
+            brillig_function_reasons: Vec::default(),
+            largest_constant_arrays: Vec::default(),
         }
     }
 
@@ -271,7 +593,13 @@ pub fn create_program(
     let ArtifactsAndWarnings(
         (generated_acirs, generated_brillig, brillig_function_names, error_types),
         ssa_level_warnings,
-    ) = optimize_into_acir(program, options)?;
+        brillig_function_reasons,
+        largest_constant_arrays,
+    ) = if options.convert_panics_to_errors {
+        catch_panics(|| optimize_into_acir(program, options))?
+    } else {
+        optimize_into_acir(program, options)?
+    };
 
     assert_eq!(
         generated_acirs.len(),
@@ -303,6 +631,17 @@ pub fn create_program(
         is_main = false;
     }
     program_artifact.brillig_names = brillig_function_names;
+    program_artifact.brillig_function_reasons = brillig_function_reasons;
+    program_artifact.largest_constant_arrays = largest_constant_arrays;
+
+    if let Some(emit_witness_graph) = &options.emit_witness_graph {
+        emit_witness_graph_files(&program_artifact, &options.emit_path(emit_witness_graph));
+    }
+
+    if let Some(emit_acir_json) = &options.emit_acir_json {
+        let debug_infos = options.annotate_acir_json.then_some(program_artifact.debug.as_slice());
+        emit_acir_json_file(&program_artifact, debug_infos, &options.emit_path(emit_acir_json));
+    }
 
     Ok(program_artifact)
 }
@@ -434,6 +773,7 @@ struct SsaBuilder {
     ssa: Ssa,
     ssa_logging: SsaLogging,
     print_codegen_timings: bool,
+    disabled_passes: Vec<String>,
 }
 
 impl SsaBuilder {
@@ -441,6 +781,7 @@ impl SsaBuilder {
         program: Program,
         ssa_logging: SsaLogging,
         print_codegen_timings: bool,
+        disabled_passes: Vec<String>,
         emit_ssa: &Option<PathBuf>,
     ) -> Result<SsaBuilder, RuntimeError> {
         let ssa = ssa_gen::generate_ssa(program)?;
@@ -453,7 +794,8 @@ impl SsaBuilder {
             let ssa_path = emit_ssa.with_extension("ssa.json");
             write_to_file(&serde_json::to_vec(&ssa).unwrap(), &ssa_path);
         }
-        Ok(SsaBuilder { ssa_logging, print_codegen_timings, ssa }.print("Initial SSA"))
+        Ok(SsaBuilder { ssa_logging, print_codegen_timings, disabled_passes, ssa }
+            .print("Initial SSA"))
     }
 
     fn finish(self) -> Ssa {
@@ -461,23 +803,86 @@ impl SsaBuilder {
     }
 
     /// Runs the given SSA pass and prints the SSA afterward if `print_ssa_passes` is true.
+    /// Does nothing if the pass was disabled.
     fn run_pass<F>(mut self, pass: F, msg: &str) -> Self
     where
         F: FnOnce(Ssa) -> Ssa,
     {
+        if self.is_disabled(msg) {
+            return self;
+        }
+        let instructions_before = self.instruction_count_to_report();
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa));
+        self.report_instruction_count_change(msg, instructions_before);
+        self.check_pass_invariants();
         self.print(msg)
     }
 
+    /// Runs each of the given SSA passes in order, reporting each pass to `progress` once it has run.
+    fn run_passes(
+        mut self,
+        passes: &[&SsaPass],
+        progress: Option<&PassProgressCallback>,
+    ) -> Result<Self, RuntimeError> {
+        for (index, pass) in passes.iter().enumerate() {
+            self = self.try_run_pass(|ssa| (pass.run)(ssa), pass.msg)?;
+            if let Some(progress) = progress {
+                progress(pass.msg, index, passes.len());
+            }
+        }
+        Ok(self)
+    }
+
     /// The same as `run_pass` but for passes that may fail
     fn try_run_pass<F>(mut self, pass: F, msg: &str) -> Result<Self, RuntimeError>
     where
         F: FnOnce(Ssa) -> Result<Ssa, RuntimeError>,
     {
+        if self.is_disabled(msg) {
+            return Ok(self);
+        }
+        let instructions_before = self.instruction_count_to_report();
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa))?;
+        self.report_instruction_count_change(msg, instructions_before);
+        self.check_pass_invariants();
         Ok(self.print(msg))
     }
 
+    /// Whether the pass `msg` starts with the name of one of the disabled passes.
+    fn is_disabled(&self, msg: &str) -> bool {
+        let msg = msg.to_lowercase();
+        self.disabled_passes.iter().any(|name| {
+            let name = name.trim().to_lowercase();
+            let name = name.strip_prefix("after ").unwrap_or(&name);
+            let name = name.strip_suffix(':').unwrap_or(name);
+            !name.is_empty() && msg.starts_with(name)
+        })
+    }
+
+    /// Counts the instructions of the program if they are reported alongside the pass timings.
+    fn instruction_count_to_report(&self) -> Option<usize> {
+        self.print_codegen_timings.then(|| self.ssa.count_instructions())
+    }
+
+    /// Prints how the pass `msg` changed the number of instructions, given the count from before it ran.
+    fn report_instruction_count_change(&self, msg: &str, instructions_before: Option<usize>) {
+        if let Some(before) = instructions_before {
+            let after = self.ssa.count_instructions();
+            let change = after as i64 - before as i64;
+            println!("{msg}: {before} -> {after} instructions ({change:+})");
+        }
+    }
+
+    /// Checks that the last pass left every function well-formed. Only done in debug builds.
+    fn check_pass_invariants(&self) {
+        if cfg!(debug_assertions) {
+            for function in self.ssa.functions.values() {
+                function.assert_all_blocks_terminated();
+                function.assert_entry_block_has_no_predecessors();
+            }
+        }
+    }
+
     fn print(mut self, msg: &str) -> Self {
         let print_ssa_pass = match &self.ssa_logging {
             SsaLogging::None => false,
@@ -497,6 +902,58 @@ impl SsaBuilder {
     }
 }
 
+impl SsaEvaluatorOptions {
+    /// Returns the path the files emitted for `path` are derived from, which is moved into
+    /// `artifact_dir` if one is set.
+    fn emit_path(&self, path: &Path) -> PathBuf {
+        match (&self.artifact_dir, path.file_name()) {
+            (Some(artifact_dir), Some(file_name)) => artifact_dir.join(file_name),
+            _ => path.to_path_buf(),
+        }
+    }
+}
+
+/// Writes the control flow graph of each function to `<path>.<function name>.<function id>.dot`.
+fn emit_cfg_files(ssa: &Ssa, path: &Path) {
+    let mut emit_cfg_dir = path.to_path_buf();
+    emit_cfg_dir.pop();
+    create_named_dir(emit_cfg_dir.as_ref(), "target");
+    for function in ssa.functions.values() {
+        let dot_path = path.with_extension(format!("{}.{}.dot", function.name(), function.id()));
+        write_to_file(function.cfg_to_dot().as_bytes(), &dot_path);
+    }
+}
+
+/// Writes the witness graph of each circuit to `<path>.<circuit name>.witnesses.dot`.
+fn emit_witness_graph_files(program_artifact: &SsaProgramArtifact, path: &Path) {
+    let mut emit_dir = path.to_path_buf();
+    emit_dir.pop();
+    create_named_dir(emit_dir.as_ref(), "target");
+    for (circuit, name) in program_artifact.program.functions.iter().zip(&program_artifact.names) {
+        let dot_path = path.with_extension(format!("{name}.witnesses.dot"));
+        write_to_file(witness_graph_to_dot(name, circuit).as_bytes(), &dot_path);
+    }
+}
+
+/// Writes the ACIR program as JSON to `<path>.acir.json`, annotating its opcodes if
+/// `debug_infos` are given.
+fn emit_acir_json_file(
+    program_artifact: &SsaProgramArtifact,
+    debug_infos: Option<&[DebugInfo]>,
+    path: &Path,
+) {
+    let mut emit_dir = path.to_path_buf();
+    emit_dir.pop();
+    create_named_dir(emit_dir.as_ref(), "target");
+    let json = program_to_json(
+        &program_artifact.program,
+        &program_artifact.names,
+        &program_artifact.brillig_names,
+        debug_infos,
+    );
+    write_to_file(json.as_bytes(), &path.with_extension("acir.json"));
+}
+
 fn create_named_dir(named_dir: &Path, name: &str) -> PathBuf {
     std::fs::create_dir_all(named_dir)
         .unwrap_or_else(|_| panic!("could not create the `{name}` directory"));
@@ -516,3 +973,384 @@ fn write_to_file(bytes: &[u8], path: &Path) {
         panic!("couldn't write to {display}: {why}");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::acir::circuit::ExpressionWidth;
+
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use super::{
+        catch_panics, optimize_all, parse_ssa_pass_order, pin_pass_order, primary_passes,
+        SsaBuilder, SsaEvaluatorOptions, SsaLogging, DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+    };
+    use crate::{
+        brillig::{RegisterReuseStrategy, DEFAULT_ARRAY_INIT_LOOP_THRESHOLD},
+        errors::{InternalError, RuntimeError},
+        ssa::{ir::instruction::Instruction, ssa_gen::Ssa},
+    };
+
+    fn options() -> SsaEvaluatorOptions {
+        SsaEvaluatorOptions {
+            ssa_logging: SsaLogging::None,
+            enable_brillig_logging: false,
+            print_codegen_timings: false,
+            expression_width: ExpressionWidth::default(),
+            emit_ssa: None,
+            emit_cfg: None,
+            emit_witness_graph: None,
+            emit_acir_json: None,
+            artifact_dir: None,
+            annotate_acir_json: false,
+            skip_underconstrained_check: true,
+            skip_brillig_constraints_check: true,
+            inliner_aggressiveness: 0,
+            max_bytecode_increase_percent: None,
+            ssa_pass_order: None,
+            disabled_passes: Vec::new(),
+            report_unconstrained_functions: false,
+            force_brillig: false,
+            brillig_register_reuse: RegisterReuseStrategy::default(),
+            brillig_array_init_loop_threshold: DEFAULT_ARRAY_INIT_LOOP_THRESHOLD,
+            max_array_set_lookback: DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+            brillig_procedure_inline_threshold: 0,
+            warn_on_print: false,
+            pass_progress: None,
+            convert_panics_to_errors: false,
+            verify_no_references_after_mem2reg: false,
+            show_brillig_disassembly: false,
+            show_constraint_counts: false,
+            show_largest_constant_arrays: None,
+            max_brillig_block_size: None,
+        }
+    }
+
+    #[test]
+    fn pins_pass_order() {
+        let config = "
+            # Minimal pipeline
+            Defunctionalization
+            Inlining (1st)
+            Mem2Reg (1st)
+            `static_assert` and `assert_constant`
+            Unrolling
+            Flattening
+            Mem2Reg (3rd)
+            Inlining (2nd)
+            Remove IfElse
+            Removing Bit Shifts
+            Dead Instruction Elimination (1st)
+        ";
+        let pass_order = parse_ssa_pass_order(config);
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let pinned_passes = pin_pass_order(&passes, &pass_order).unwrap();
+
+        let names: Vec<_> = pinned_passes.iter().map(|pass| pass.msg).collect();
+        assert_eq!(names, pass_order);
+    }
+
+    #[test]
+    fn primary_pass_names_are_unique() {
+        let options = options();
+        let passes = primary_passes(&options);
+        for (index, pass) in passes.iter().enumerate() {
+            assert!(
+                passes[..index].iter().all(|other| other.msg != pass.msg),
+                "SSA pass `{}` is named more than once",
+                pass.msg
+            );
+        }
+    }
+
+    #[test]
+    fn runs_passes_in_pinned_order_from_file() {
+        let pass_order = "
+            Defunctionalization
+            Inlining (1st)
+            Mem2Reg (1st)
+            `static_assert` and `assert_constant`
+            Unrolling
+            Flattening
+            Mem2Reg (3rd)
+            Inlining (2nd)
+            Remove IfElse
+            Removing Bit Shifts
+            Dead Instruction Elimination (1st)
+        ";
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(pass_order.as_bytes()).unwrap();
+
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            ";
+        let builder = SsaBuilder {
+            ssa: Ssa::from_str(src).unwrap(),
+            ssa_logging: SsaLogging::None,
+            print_codegen_timings: false,
+            disabled_passes: Vec::new(),
+        };
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let progress_reported = reported.clone();
+        let progress: super::PassProgressCallback =
+            Arc::new(move |name: &str, _index: usize, _total: usize| {
+                progress_reported.lock().unwrap().push(name.to_string());
+            });
+        let options = SsaEvaluatorOptions {
+            ssa_pass_order: Some(file.path().to_path_buf()),
+            pass_progress: Some(progress),
+            ..options()
+        };
+        optimize_all(builder, &options).unwrap();
+
+        assert_eq!(*reported.lock().unwrap(), parse_ssa_pass_order(pass_order));
+    }
+
+    #[test]
+    fn rejects_unknown_pass() {
+        let pass_order = parse_ssa_pass_order("Defunctionalization\nMem2Reg (4th)");
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(message, "unknown SSA pass `Mem2Reg (4th)`");
+    }
+
+    #[test]
+    fn rejects_pass_order_violating_constraints() {
+        let pass_order = parse_ssa_pass_order(
+            "
+            Defunctionalization
+            Inlining (1st)
+            Flattening
+            Unrolling
+            Inlining (2nd)
+            Remove IfElse
+            Removing Bit Shifts
+            ",
+        );
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(message, "SSA pass `Unrolling` must run before `Flattening`");
+    }
+
+    #[test]
+    fn rejects_pass_order_missing_mandatory_pass() {
+        let pass_order = parse_ssa_pass_order("Defunctionalization\nInlining (1st)\nUnrolling");
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(message, "mandatory SSA pass `Flattening` is missing");
+    }
+
+    #[test]
+    fn rejects_pass_order_without_evaluating_static_asserts() {
+        let pass_order = parse_ssa_pass_order(
+            "
+            Defunctionalization
+            Inlining (1st)
+            Unrolling
+            Flattening
+            Mem2Reg (3rd)
+            Inlining (2nd)
+            Remove IfElse
+            Removing Bit Shifts
+            ",
+        );
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(
+            message,
+            "mandatory SSA pass ``static_assert` and `assert_constant`` is missing"
+        );
+    }
+
+    #[test]
+    fn rejects_pass_order_evaluating_static_asserts_after_flattening() {
+        let pass_order = parse_ssa_pass_order(
+            "
+            Defunctionalization
+            Inlining (1st)
+            Unrolling
+            Flattening
+            `static_assert` and `assert_constant`
+            Mem2Reg (3rd)
+            Inlining (2nd)
+            Remove IfElse
+            Removing Bit Shifts
+            ",
+        );
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(
+            message,
+            "SSA pass ``static_assert` and `assert_constant`` must run before `Flattening`"
+        );
+    }
+
+    #[test]
+    fn rejects_pass_order_separating_adjacent_passes() {
+        let pass_order = parse_ssa_pass_order(
+            "
+            Defunctionalization
+            Inlining (1st)
+            `static_assert` and `assert_constant`
+            Unrolling
+            Flattening
+            Mem2Reg (3rd)
+            Removing Bit Shifts
+            Inlining (2nd)
+            Remove IfElse
+            ",
+        );
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(message, "SSA pass `Inlining (2nd)` must run immediately after `Mem2Reg (3rd)`");
+    }
+
+    #[test]
+    fn rejects_pass_listed_more_than_once() {
+        let pass_order = parse_ssa_pass_order("Defunctionalization\nMem2Reg (1st)\nMem2Reg (1st)");
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let Err(RuntimeError::InvalidSsaPassOrder { message }) =
+            pin_pass_order(&passes, &pass_order)
+        else {
+            panic!("Expected an invalid pass order");
+        };
+        assert_eq!(message, "SSA pass `Mem2Reg (1st)` is listed more than once");
+    }
+
+    #[test]
+    fn converts_panics_into_internal_errors() {
+        let result: Result<(), RuntimeError> =
+            catch_panics(|| unreachable!("ICE: unexpected input"));
+        let Err(RuntimeError::InternalError(InternalError::General { message, .. })) = result
+        else {
+            panic!("Expected the panic to become an internal error");
+        };
+        assert_eq!(message, "internal error: entered unreachable code: ICE: unexpected input");
+
+        assert_eq!(catch_panics(|| Ok(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn reports_progress_after_each_pass() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            ";
+        let builder = SsaBuilder {
+            ssa: Ssa::from_str(src).unwrap(),
+            ssa_logging: SsaLogging::None,
+            print_codegen_timings: false,
+            disabled_passes: Vec::new(),
+        };
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let progress_reported = reported.clone();
+        let progress: super::PassProgressCallback =
+            Arc::new(move |name: &str, index: usize, total: usize| {
+                progress_reported.lock().unwrap().push((name.to_string(), index, total));
+            });
+
+        let options = options();
+        let passes = primary_passes(&options);
+        let passes: Vec<_> = passes.iter().collect();
+        builder.run_passes(&passes, Some(&progress)).unwrap();
+
+        let expected: Vec<_> = passes
+            .iter()
+            .enumerate()
+            .map(|(index, pass)| (pass.msg.to_string(), index, passes.len()))
+            .collect();
+        assert_eq!(*reported.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn skips_disabled_passes() {
+        // A Brillig function, as the ACIR passes which run after the last mem2reg
+        // expect all stores to have been removed by it.
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field):
+                v1 = allocate -> &mut Field
+                store v0 at v1
+                v2 = load v1 -> Field
+                return v2
+            }
+            ";
+
+        // Runs the whole pipeline and counts the loads and stores left in `main`.
+        let count_loads_and_stores = |disabled_passes: Vec<String>| {
+            let options = SsaEvaluatorOptions { disabled_passes, ..options() };
+            let builder = SsaBuilder {
+                ssa: Ssa::from_str(src).unwrap(),
+                ssa_logging: SsaLogging::None,
+                print_codegen_timings: false,
+                disabled_passes: options.disabled_passes.clone(),
+            };
+            let ssa = optimize_all(builder, &options).unwrap();
+            let main = ssa.main();
+            main.reachable_blocks()
+                .iter()
+                .flat_map(|block| main.dfg[*block].instructions())
+                .filter(|instruction| {
+                    matches!(
+                        main.dfg[**instruction],
+                        Instruction::Load { .. } | Instruction::Store { .. }
+                    )
+                })
+                .count()
+        };
+
+        assert_eq!(count_loads_and_stores(Vec::new()), 0);
+        assert_eq!(count_loads_and_stores(vec!["Mem2Reg".to_string()]), 2);
+        assert_eq!(count_loads_and_stores(vec!["After mem2reg:".to_string()]), 2);
+        assert_eq!(count_loads_and_stores(vec!["Mem2Reg (1st)".to_string()]), 0);
+    }
+}
@@ -0,0 +1,87 @@
+//! Detects binary instructions whose operands have different types.
+//!
+//! Operands of a binary instruction are expected to have been unified by casts during SSA
+//! generation, with the exception of the shift amount in `shl` and `shr`. A mismatch is a bug in
+//! the frontend or in an SSA pass, which would otherwise only surface as an obscure failure while
+//! lowering to ACIR or Brillig.
+use crate::errors::{InternalError, RuntimeError};
+use crate::ssa::ir::function::Function;
+use crate::ssa::ir::instruction::{BinaryOp, Instruction};
+use crate::ssa::ssa_gen::Ssa;
+
+impl Ssa {
+    /// Returns an error for the first binary instruction found whose operands have different types.
+    pub(crate) fn check_for_mismatched_binary_operands(&self) -> Result<(), RuntimeError> {
+        self.functions.values().try_for_each(check_function)
+    }
+}
+
+fn check_function(function: &Function) -> Result<(), RuntimeError> {
+    for block in function.reachable_blocks() {
+        for instruction in function.dfg[block].instructions() {
+            let Instruction::Binary(binary) = &function.dfg[*instruction] else {
+                continue;
+            };
+            if matches!(binary.operator, BinaryOp::Shl { .. } | BinaryOp::Shr) {
+                continue;
+            }
+
+            let lhs_type = function.dfg.type_of_value(binary.lhs);
+            let rhs_type = function.dfg.type_of_value(binary.rhs);
+            if lhs_type != rhs_type {
+                let call_stack = function.dfg.get_instruction_call_stack(*instruction);
+                return Err(RuntimeError::InternalError(InternalError::MismatchedBinaryOperands {
+                    operator: binary.operator.to_string(),
+                    lhs_type: lhs_type.to_string(),
+                    rhs_type: rhs_type.to_string(),
+                    call_stack,
+                }));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::{InternalError, RuntimeError};
+    use crate::ssa::ssa_gen::Ssa;
+
+    #[test]
+    fn errors_on_mismatched_comparison_operands() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u8, v1: u16):
+                v2 = lt v0, v1
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let Err(RuntimeError::InternalError(InternalError::MismatchedBinaryOperands {
+            operator,
+            lhs_type,
+            rhs_type,
+            ..
+        })) = ssa.check_for_mismatched_binary_operands()
+        else {
+            panic!("Expected mismatched binary operands to be reported");
+        };
+        assert_eq!(operator, "lt");
+        assert_eq!(lhs_type, "u8");
+        assert_eq!(rhs_type, "u16");
+    }
+
+    #[test]
+    fn allows_shift_by_a_different_type() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u64, v1: u8):
+                v2 = shl v0, v1
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        assert!(ssa.check_for_mismatched_binary_operands().is_ok());
+    }
+}
@@ -0,0 +1,86 @@
+//! Detects calls to `print` left in a program, which are still executed once it is released.
+use std::collections::BTreeSet;
+
+use crate::errors::{InternalWarning, SsaReport};
+use crate::ssa::ir::call_graph::CallGraph;
+use crate::ssa::ir::function::{Function, FunctionId};
+use crate::ssa::ir::instruction::{Instruction, InstructionId};
+use crate::ssa::ir::value::Value;
+use crate::ssa::ssa_gen::Ssa;
+
+/// The name of the oracle behind `std::print` and `std::println`.
+const PRINT_ORACLE: &str = "print";
+
+impl Ssa {
+    /// Warns about every call made from `main` which prints, either by calling the `print`
+    /// oracle or a function which (transitively) does.
+    ///
+    /// Every print reachable from `main` goes through one of its calls, so reporting those is
+    /// enough. Once inlined, these calls also carry the call stack down to the print itself.
+    pub(crate) fn check_for_print_calls(&self) -> Vec<SsaReport> {
+        let printing_functions = self.printing_functions();
+        let main = self.main();
+
+        let mut warnings = Vec::new();
+        for block in main.reachable_blocks() {
+            for instruction in main.dfg[block].instructions() {
+                let prints = match called_value(main, *instruction) {
+                    Some(Value::ForeignFunction(name)) => name == PRINT_ORACLE,
+                    Some(Value::Function(function)) => printing_functions.contains(function),
+                    _ => false,
+                };
+                if prints {
+                    let call_stack = main.dfg.get_instruction_call_stack(*instruction);
+                    warnings.push(SsaReport::Warning(InternalWarning::PrintCall { call_stack }));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Returns the functions which call the `print` oracle, directly or through other functions.
+    fn printing_functions(&self) -> BTreeSet<FunctionId> {
+        let mut printing_functions: BTreeSet<FunctionId> = self
+            .functions
+            .iter()
+            .filter_map(|(id, function)| calls_print_oracle(function).then_some(*id))
+            .collect();
+
+        let call_graph = CallGraph::from_ssa(self);
+        loop {
+            let callers: Vec<FunctionId> = self
+                .functions
+                .keys()
+                .filter(|id| !printing_functions.contains(id))
+                .filter(|id| {
+                    call_graph.callees(**id).any(|callee| printing_functions.contains(&callee))
+                })
+                .copied()
+                .collect();
+
+            if callers.is_empty() {
+                return printing_functions;
+            }
+            printing_functions.extend(callers);
+        }
+    }
+}
+
+fn calls_print_oracle(function: &Function) -> bool {
+    function.reachable_blocks().into_iter().any(|block| {
+        function.dfg[block].instructions().iter().any(|instruction| {
+            matches!(
+                called_value(function, *instruction),
+                Some(Value::ForeignFunction(name)) if name == PRINT_ORACLE
+            )
+        })
+    })
+}
+
+/// Returns the value being called if the given instruction is a call.
+fn called_value(function: &Function, instruction: InstructionId) -> Option<&Value> {
+    match &function.dfg[instruction] {
+        Instruction::Call { func, .. } => Some(&function.dfg[*func]),
+        _ => None,
+    }
+}
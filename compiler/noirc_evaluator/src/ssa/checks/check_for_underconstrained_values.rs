@@ -8,9 +8,10 @@ use crate::ssa::ir::function::{Function, FunctionId};
 use crate::ssa::ir::instruction::{Hint, Instruction, InstructionId, Intrinsic};
 use crate::ssa::ir::value::{Value, ValueId};
 use crate::ssa::ssa_gen::Ssa;
+use fxhash::FxHashSet as HashSet;
 use im::HashMap;
 use rayon::prelude::*;
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 use tracing::trace;
 
 impl Ssa {
@@ -133,7 +134,9 @@ impl BrilligTaintedIds {
             arguments: HashSet::from_iter(arguments.iter().copied()),
             results: results
                 .iter()
-                .map(|result| ResultStatus::Unconstrained { descendants: HashSet::from([*result]) })
+                .map(|result| ResultStatus::Unconstrained {
+                    descendants: HashSet::from_iter([*result]),
+                })
                 .collect(),
             root_results: HashSet::from_iter(results.iter().copied()),
         }
@@ -0,0 +1,108 @@
+//! Finds the largest constant arrays in a program, which are often lookup tables
+//! making up a good share of its size.
+use std::cmp::Reverse;
+
+use fxhash::FxHashSet as HashSet;
+use noirc_errors::Location;
+
+use crate::ssa::ir::function::Function;
+use crate::ssa::ir::instruction::{Instruction, InstructionId};
+use crate::ssa::ssa_gen::Ssa;
+
+impl Ssa {
+    /// Returns the number of elements of the `count` largest arrays whose elements are all
+    /// numeric constants, along with the location creating each of them.
+    ///
+    /// Arrays are returned once per instruction creating them, so distinct arrays created at
+    /// the same location, such as in a function inlined once, are all returned.
+    pub(crate) fn largest_constant_arrays(&self, count: usize) -> Vec<(u32, Location)> {
+        let mut arrays = Vec::new();
+        // The globals have no function id, so functions are told apart by their position
+        let functions = std::iter::once(&self.globals).chain(self.functions.values());
+        let mut instructions: HashSet<(usize, InstructionId)> = HashSet::default();
+        for (function_index, function) in functions.enumerate() {
+            for (instruction, length, location) in constant_arrays(function) {
+                if instructions.insert((function_index, instruction)) {
+                    arrays.push((length, location));
+                }
+            }
+        }
+
+        arrays.sort_by_key(|(length, _)| Reverse(*length));
+        arrays.truncate(count);
+        arrays
+    }
+}
+
+/// Returns the instruction creating each constant array in `function`, along with the number
+/// of elements and the location of the array.
+/// Arrays without a location can't be pointed at, so they are left out.
+fn constant_arrays(function: &Function) -> Vec<(InstructionId, u32, Location)> {
+    let dfg = &function.dfg;
+    let mut arrays = Vec::new();
+    for block in function.reachable_blocks() {
+        for instruction in dfg[block].instructions() {
+            if !matches!(dfg[*instruction], Instruction::MakeArray { .. }) {
+                continue;
+            }
+            let array = dfg.instruction_results(*instruction)[0];
+            let Some((elements, _)) = dfg.get_array_constant(array) else {
+                continue;
+            };
+            if elements.iter().all(|element| dfg.get_numeric_constant(*element).is_some()) {
+                // The first location is where the array is written, the others are the calls
+                // it was inlined through, from the innermost to the outermost one
+                let call_stack = dfg.get_instruction_call_stack(*instruction);
+                if let Some(location) = call_stack.first() {
+                    arrays.push((*instruction, elements.len() as u32, *location));
+                }
+            }
+        }
+    }
+    arrays
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_errors::{Location, Span};
+
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{map::Id, types::Type},
+    };
+
+    #[test]
+    fn returns_largest_constant_arrays_where_they_are_written() {
+        let location = |start| Location::new(Span::single_char(start), Default::default());
+
+        // main creates constant arrays of 3 and 5 elements and an array holding its parameter,
+        // then two arrays of 4 elements inlined from the same call to a helper
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+        let v0 = builder.add_parameter(Type::field());
+        let make_array = |builder: &mut FunctionBuilder, elements: &[u128]| {
+            let typ = Type::Array(vec![Type::field()].into(), elements.len() as u32);
+            let elements =
+                elements.iter().map(|element| builder.field_constant(*element)).collect();
+            builder.insert_make_array(elements, typ);
+        };
+        builder.set_location(location(10));
+        make_array(&mut builder, &[1, 2, 3]);
+        builder.set_location(location(20));
+        make_array(&mut builder, &[1, 2, 3, 4, 5]);
+        let typ = Type::Array(vec![Type::field()].into(), 6);
+        builder.insert_make_array(im::Vector::from(vec![v0; 6]), typ);
+
+        let call_stack_data = &mut builder.current_function.dfg.call_stack_data;
+        let call = call_stack_data.add_location_to_root(location(30));
+        let call_stack = call_stack_data.add_child(call, location(40));
+        builder.set_call_stack(call_stack);
+        make_array(&mut builder, &[1, 2, 3, 4]);
+        make_array(&mut builder, &[5, 6, 7, 8]);
+        builder.terminate_with_return(vec![]);
+
+        let ssa = builder.finish();
+        let arrays = ssa.largest_constant_arrays(3);
+        assert_eq!(arrays, vec![(5, location(20)), (4, location(40)), (4, location(40))]);
+        assert_eq!(ssa.largest_constant_arrays(1), vec![(5, location(20))]);
+    }
+}
@@ -1 +1,5 @@
+mod check_for_mismatched_binary_operands;
+mod check_for_print_calls;
 mod check_for_underconstrained_values;
+mod largest_constant_arrays;
+mod verify_no_references_after_mem2reg;
@@ -0,0 +1,170 @@
+//! Verifies that no references are left in ACIR functions once `mem2reg` has run on the
+//! flattened CFG.
+//!
+//! ACIR has no notion of memory, so every `load` and `store` must have been replaced by the values
+//! they read and write by then. Allocations are only removed later on by dead instruction
+//! elimination, so references are fine as long as nothing live uses them anymore. A surviving reference is a
+//! bug in `mem2reg` which would otherwise only surface later, as a panic in a downstream pass or
+//! during ACIR generation.
+use fxhash::FxHashSet as HashSet;
+
+use crate::errors::{InternalError, RuntimeError};
+use crate::ssa::ir::call_stack::CallStack;
+use crate::ssa::ir::function::Function;
+use crate::ssa::ir::instruction::Instruction;
+use crate::ssa::ir::post_order::PostOrder;
+use crate::ssa::ir::value::ValueId;
+use crate::ssa::ssa_gen::Ssa;
+
+impl Ssa {
+    /// Returns an error for the first reference found in an ACIR function, which is either a
+    /// `load` or `store` instruction, a live value of a type containing a reference,
+    /// or a block parameter of such a type.
+    pub(crate) fn verify_no_references_after_mem2reg(&self) -> Result<(), RuntimeError> {
+        self.functions
+            .values()
+            .filter(|function| function.runtime().is_acir())
+            .try_for_each(verify_function)
+    }
+}
+
+fn verify_function(function: &Function) -> Result<(), RuntimeError> {
+    let error = |call_stack| {
+        RuntimeError::InternalError(InternalError::ReferenceAfterMem2Reg {
+            function: function.name().to_string(),
+            call_stack,
+        })
+    };
+    let dfg = &function.dfg;
+    let live_values = live_values(function);
+
+    for block in function.reachable_blocks() {
+        // Block parameters have no call stack of their own
+        if dfg[block]
+            .parameters()
+            .iter()
+            .any(|value| dfg.type_of_value(*value).contains_reference())
+        {
+            return Err(error(CallStack::new()));
+        }
+
+        for instruction in dfg[block].instructions() {
+            let is_load_or_store =
+                matches!(dfg[*instruction], Instruction::Load { .. } | Instruction::Store { .. });
+            let has_live_reference_result =
+                dfg.instruction_results(*instruction).iter().any(|result| {
+                    dfg.type_of_value(*result).contains_reference() && live_values.contains(result)
+                });
+
+            if is_load_or_store || has_live_reference_result {
+                return Err(error(dfg.get_instruction_call_stack(*instruction)));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the values which dead instruction elimination would keep, which are the values used
+/// by terminators and by instructions which are either not removable or whose results are live.
+fn live_values(function: &Function) -> HashSet<ValueId> {
+    let dfg = &function.dfg;
+    let mut live_values = HashSet::default();
+
+    // Blocks are visited after their successors, as there are no loops left once unrolled
+    for block in PostOrder::with_function(function).into_vec() {
+        dfg[block].unwrap_terminator().for_each_value(|value| {
+            live_values.insert(dfg.resolve(value));
+        });
+        for instruction in dfg[block].instructions().iter().rev() {
+            let is_live = !dfg[*instruction].can_eliminate_if_unused(function)
+                || dfg
+                    .instruction_results(*instruction)
+                    .iter()
+                    .any(|result| live_values.contains(result));
+            if is_live {
+                dfg[*instruction].for_each_value(|value| live_values.insert(dfg.resolve(value)));
+            }
+        }
+    }
+    live_values
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::{InternalError, RuntimeError};
+    use crate::ssa::ssa_gen::Ssa;
+
+    #[test]
+    fn errors_on_reference_left_in_acir_function() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v1 = allocate -> &mut Field
+                store v0 at v1
+                v2 = load v1 -> Field
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let Err(RuntimeError::InternalError(InternalError::ReferenceAfterMem2Reg {
+            function, ..
+        })) = ssa.verify_no_references_after_mem2reg()
+        else {
+            panic!("Expected the remaining reference to be reported");
+        };
+        assert_eq!(function, "main");
+
+        // Once mem2reg has run the allocation is left unused
+        let ssa = ssa.mem2reg();
+        assert!(ssa.verify_no_references_after_mem2reg().is_ok());
+    }
+
+    #[test]
+    fn allows_references_only_used_by_dead_instructions() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0():
+                v0 = allocate -> &mut Field
+                v1 = make_array [v0, v0] : [&mut Field; 2]
+                return
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        assert!(ssa.verify_no_references_after_mem2reg().is_ok());
+    }
+
+    #[test]
+    fn errors_on_reference_passed_to_a_call() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v1 = allocate -> &mut Field
+                call f1(v1)
+                return
+            }
+            acir(inline) fn foo f1 {
+              b0(v0: &mut Field):
+                return
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        assert!(matches!(
+            ssa.verify_no_references_after_mem2reg(),
+            Err(RuntimeError::InternalError(InternalError::ReferenceAfterMem2Reg { .. }))
+        ));
+    }
+
+    #[test]
+    fn allows_references_in_brillig_functions() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: &mut Field):
+                v1 = load v0 -> Field
+                return v1
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        assert!(ssa.verify_no_references_after_mem2reg().is_ok());
+    }
+}
@@ -1,5 +1,9 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
+use crate::errors::RuntimeError;
 use crate::ssa::ir::{
     function::RuntimeType,
     types::{NumericType, Type},
@@ -7,6 +11,8 @@ use crate::ssa::ir::{
 };
 use acvm::FieldElement;
 use fxhash::FxHashMap as HashMap;
+use iter_extended::vecmap;
+use noirc_errors::Location;
 use noirc_frontend::ast;
 use noirc_frontend::hir_def::function::FunctionSignature;
 use serde::{Deserialize, Serialize};
@@ -28,6 +34,8 @@ pub(crate) struct DataBusBuilder {
     pub(crate) map: HashMap<ValueId, usize>,
     pub(crate) databus: Option<ValueId>,
     call_data_id: Option<u32>,
+    /// Number of field elements in the finalized databus array
+    len: usize,
 }
 
 impl DataBusBuilder {
@@ -38,12 +46,15 @@ impl DataBusBuilder {
             databus: None,
             values: im::Vector::new(),
             call_data_id: None,
+            len: 0,
         }
     }
 
     /// Generates a vector telling which flattened parameters from the given function signature
-    /// are tagged with databus visibility
-    pub(crate) fn is_databus(main_signature: &FunctionSignature) -> Vec<DatabusVisibility> {
+    /// are tagged with databus visibility, along with the location of the parameter they belong to
+    pub(crate) fn is_databus(
+        main_signature: &FunctionSignature,
+    ) -> Vec<(DatabusVisibility, Location)> {
         let mut params_is_databus = Vec::new();
 
         for param in &main_signature.0 {
@@ -52,8 +63,9 @@ impl DataBusBuilder {
                 ast::Visibility::CallData(id) => DatabusVisibility::CallData(id),
                 ast::Visibility::ReturnData => DatabusVisibility::ReturnData,
             };
-            let len = param.1.field_count(&param.0.location()) as usize;
-            params_is_databus.extend(vec![is_databus; len]);
+            let location = param.0.location();
+            let len = param.1.field_count(&location) as usize;
+            params_is_databus.extend(vec![(is_databus, location); len]);
         }
         params_is_databus
     }
@@ -65,12 +77,16 @@ pub(crate) struct CallData {
     pub(crate) call_data_id: u32,
     pub(crate) array_id: ValueId,
     pub(crate) index_map: HashMap<ValueId, usize>,
+    /// The number of field elements in the calldata array
+    pub(crate) len: usize,
 }
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub(crate) struct DataBus {
     pub(crate) call_data: Vec<CallData>,
     pub(crate) return_data: Option<ValueId>,
+    /// The number of field elements in the return data array, if there is one
+    return_data_len: usize,
 }
 
 impl DataBus {
@@ -88,10 +104,15 @@ impl DataBus {
                     array_id: f(cd.array_id),
                     index_map: call_data_map,
                     call_data_id: cd.call_data_id,
+                    len: cd.len,
                 }
             })
             .collect();
-        DataBus { call_data, return_data: self.return_data.map(&mut f) }
+        DataBus {
+            call_data,
+            return_data: self.return_data.map(&mut f),
+            return_data_len: self.return_data_len,
+        }
     }
 
     /// Updates the databus values in place with the provided function
@@ -111,6 +132,19 @@ impl DataBus {
     pub(crate) fn call_data_array(&self) -> Vec<(u32, ValueId)> {
         self.call_data.iter().map(|cd| (cd.call_data_id, cd.array_id)).collect()
     }
+
+    /// Returns the flattened length of the calldata with the given user id, if it exists
+    #[cfg(test)]
+    pub(crate) fn call_data_len(&self, id: u32) -> Option<usize> {
+        self.call_data.iter().find(|cd| cd.call_data_id == id).map(|cd| cd.len)
+    }
+
+    /// Returns the flattened length of the return data, if there is any
+    #[cfg(test)]
+    pub(crate) fn return_data_len(&self) -> Option<usize> {
+        self.return_data.map(|_| self.return_data_len)
+    }
+
     /// Construct a databus from call_data and return_data data bus builders
     pub(crate) fn get_data_bus(
         call_data: Vec<DataBusBuilder>,
@@ -122,10 +156,19 @@ impl DataBus {
             let Some(array_id) = call_data_item.databus else { continue };
             let call_data_id =
                 call_data_item.call_data_id.expect("Call data should have a user id");
-            call_data_args.push(CallData { array_id, call_data_id, index_map: call_data_item.map });
+            call_data_args.push(CallData {
+                array_id,
+                call_data_id,
+                index_map: call_data_item.map,
+                len: call_data_item.len,
+            });
         }
 
-        DataBus { call_data: call_data_args, return_data: return_data.databus }
+        DataBus {
+            call_data: call_data_args,
+            return_data: return_data.databus,
+            return_data_len: return_data.len,
+        }
     }
 }
 
@@ -192,15 +235,19 @@ impl FunctionBuilder {
             databus: array,
             values: im::Vector::new(),
             call_data_id,
+            len: len as usize,
         }
     }
 
     /// Generate the data bus for call-data, based on the parameters of the entry block
     /// and a vector telling which ones are call-data
+    ///
+    /// Returns an error if the call-data ids do not form a contiguous range starting at 0, or if
+    /// parameters sharing a call-data id are not made of the same numeric types.
     pub(crate) fn call_data_bus(
         &mut self,
-        flattened_databus_visibilities: Vec<DatabusVisibility>,
-    ) -> Vec<DataBusBuilder> {
+        flattened_databus_visibilities: Vec<(DatabusVisibility, Location)>,
+    ) -> Result<Vec<DataBusBuilder>, RuntimeError> {
         //filter parameters of the first block that have call-data visibility
         let first_block = self.current_function.entry_block();
         let params = self.current_function.dfg[first_block].parameters();
@@ -209,29 +256,77 @@ impl FunctionBuilder {
         let is_params_databus =
             self.deflatten_databus_visibilities(params, flattened_databus_visibilities);
 
-        let mut databus_param: BTreeMap<u32, Vec<ValueId>> = BTreeMap::new();
-        for (param, databus_attribute) in params.iter().zip(is_params_databus) {
+        let mut databus_param: BTreeMap<u32, Vec<(ValueId, Location)>> = BTreeMap::new();
+        for (param, (databus_attribute, location)) in params.iter().zip(is_params_databus) {
             match databus_attribute {
                 DatabusVisibility::None | DatabusVisibility::ReturnData => continue,
                 DatabusVisibility::CallData(call_data_id) => {
                     if let std::collections::btree_map::Entry::Vacant(e) =
                         databus_param.entry(call_data_id)
                     {
-                        e.insert(vec![param.to_owned()]);
+                        e.insert(vec![(param.to_owned(), location)]);
                     } else {
-                        databus_param.get_mut(&call_data_id).unwrap().push(param.to_owned());
+                        let params = databus_param.get_mut(&call_data_id).unwrap();
+                        params.push((param.to_owned(), location));
                     }
                 }
             }
         }
+        // The keys are sorted, so they are contiguous from 0 if each one matches its position.
+        // The error points at the first parameter using the id found in place of the missing one.
+        for (expected_id, (id, params)) in databus_param.iter().enumerate() {
+            let expected_id = expected_id as u32;
+            if *id != expected_id {
+                let call_stack = vec![params[0].1];
+                return Err(RuntimeError::NonContiguousCallDataIds {
+                    missing_id: expected_id,
+                    call_stack,
+                });
+            }
+        }
+        // Parameters sharing an id are laid out in the same call-data array
+        for (id, params) in &databus_param {
+            let numeric_types = self.numeric_types_of(params[0].0);
+            if let Some((_, location)) =
+                params[1..].iter().find(|(param, _)| self.numeric_types_of(*param) != numeric_types)
+            {
+                return Err(RuntimeError::MismatchedCallDataTypes {
+                    call_data_id: *id,
+                    call_stack: vec![*location],
+                });
+            }
+        }
+
         // create the call-data-bus from the filtered lists
         let mut result = Vec::new();
-        for id in databus_param.keys() {
+        for (id, params) in &databus_param {
             let builder = DataBusBuilder::new();
-            let call_databus = self.initialize_data_bus(&databus_param[id], builder, Some(*id));
+            let values = vecmap(params, |(param, _)| *param);
+            let call_databus = self.initialize_data_bus(&values, builder, Some(*id));
             result.push(call_databus);
         }
-        result
+        Ok(result)
+    }
+
+    /// Returns the numeric types a value is made of, looking through arrays
+    fn numeric_types_of(&self, value: ValueId) -> BTreeSet<NumericType> {
+        fn collect(typ: &Type, numeric_types: &mut BTreeSet<NumericType>) {
+            match typ {
+                Type::Numeric(numeric_type) => {
+                    numeric_types.insert(*numeric_type);
+                }
+                Type::Array(element_types, _) | Type::Slice(element_types) => {
+                    for element_type in element_types.iter() {
+                        collect(element_type, numeric_types);
+                    }
+                }
+                Type::Reference(_) | Type::Function => (),
+            }
+        }
+
+        let mut numeric_types = BTreeSet::new();
+        collect(&self.current_function.dfg.type_of_value(value), &mut numeric_types);
+        numeric_types
     }
 
     /// This function takes the flattened databus visibilities and generates the databus visibility for each ssa parameter
@@ -239,8 +334,8 @@ impl FunctionBuilder {
     fn deflatten_databus_visibilities(
         &self,
         ssa_params: &[ValueId],
-        mut flattened_params_databus_visibility: Vec<DatabusVisibility>,
-    ) -> Vec<DatabusVisibility> {
+        mut flattened_params_databus_visibility: Vec<(DatabusVisibility, Location)>,
+    ) -> Vec<(DatabusVisibility, Location)> {
         let ssa_param_sizes: Vec<usize> = ssa_params
             .iter()
             .map(|ssa_param| {
@@ -250,14 +345,17 @@ impl FunctionBuilder {
 
         let mut is_ssa_params_databus = Vec::with_capacity(ssa_params.len());
         for size in ssa_param_sizes {
-            let visibilities: Vec<DatabusVisibility> =
+            let visibilities: Vec<(DatabusVisibility, Location)> =
                 flattened_params_databus_visibility.drain(0..size).collect();
-            let visibility = visibilities.first().copied().unwrap_or(DatabusVisibility::None);
+            let (visibility, location) = visibilities
+                .first()
+                .copied()
+                .unwrap_or((DatabusVisibility::None, Location::dummy()));
             assert!(
-                visibilities.iter().all(|v| *v == visibility),
+                visibilities.iter().all(|(v, _)| *v == visibility),
                 "inconsistent databus visibility for ssa param"
             );
-            is_ssa_params_databus.push(visibility);
+            is_ssa_params_databus.push((visibility, location));
         }
 
         assert_eq!(is_ssa_params_databus.len(), ssa_params.len());
@@ -265,3 +363,118 @@ impl FunctionBuilder {
         is_ssa_params_databus
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use noirc_errors::{Location, Span};
+
+    use crate::{
+        errors::RuntimeError,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{map::Id, types::Type},
+        },
+    };
+
+    use super::{DataBus, DataBusBuilder, DatabusVisibility};
+
+    #[test]
+    fn reports_databus_lengths() {
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+
+        // A field and a `[Field; 3]` in the first calldata, a `[[Field; 2]; 2]` in the second
+        let field_array = Type::Array(Arc::new(vec![Type::field()]), 3);
+        let nested_array =
+            Type::Array(Arc::new(vec![Type::Array(Arc::new(vec![Type::field()]), 2)]), 2);
+        builder.add_parameter(Type::field());
+        builder.add_parameter(field_array.clone());
+        builder.add_parameter(nested_array);
+        let returned = builder.add_parameter(field_array);
+
+        let location = Location::dummy();
+        let visibilities = [
+            vec![(DatabusVisibility::CallData(0), location)],
+            vec![(DatabusVisibility::CallData(0), location); 3],
+            vec![(DatabusVisibility::CallData(1), location); 4],
+            vec![(DatabusVisibility::None, location); 3],
+        ]
+        .concat();
+        let call_data = builder.call_data_bus(visibilities).unwrap();
+        let return_data = builder.initialize_data_bus(&[returned], DataBusBuilder::new(), None);
+
+        let data_bus = DataBus::get_data_bus(call_data, return_data);
+        assert_eq!(data_bus.call_data_len(0), Some(4));
+        assert_eq!(data_bus.call_data_len(1), Some(4));
+        assert_eq!(data_bus.call_data_len(2), None);
+        assert_eq!(data_bus.return_data_len(), Some(3));
+
+        let data_bus = data_bus.map_values(|value| value);
+        assert_eq!(data_bus.call_data_len(0), Some(4));
+        assert_eq!(data_bus.return_data_len(), Some(3));
+    }
+
+    #[test]
+    fn reports_no_return_data_length_without_return_data() {
+        let data_bus = DataBus::get_data_bus(Vec::new(), DataBusBuilder::new());
+        assert_eq!(data_bus.return_data_len(), None);
+    }
+
+    /// The location of the `index`th parameter of the functions built by [call_data_bus]
+    fn param_location(index: usize) -> Location {
+        Location::new(Span::single_char(index as u32), Location::dummy().file)
+    }
+
+    /// Builds the call data bus of a function with a parameter of the given type for each
+    /// call data id
+    fn call_data_bus(params: &[(u32, Type)]) -> Result<Vec<DataBusBuilder>, RuntimeError> {
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+        let mut visibilities = Vec::new();
+        for (index, (id, typ)) in params.iter().enumerate() {
+            builder.add_parameter(typ.clone());
+            let visibility = (DatabusVisibility::CallData(*id), param_location(index));
+            visibilities.extend(vec![visibility; typ.flattened_size() as usize]);
+        }
+        builder.call_data_bus(visibilities)
+    }
+
+    /// Builds the call data bus of a function with one field parameter per call data id
+    fn field_call_data_bus(ids: &[u32]) -> Result<Vec<DataBusBuilder>, RuntimeError> {
+        call_data_bus(&ids.iter().map(|id| (*id, Type::field())).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn accepts_contiguous_call_data_ids() {
+        let call_data = field_call_data_bus(&[1, 0, 2, 0]).unwrap();
+        assert_eq!(call_data.len(), 3);
+    }
+
+    #[test]
+    fn rejects_gaps_in_call_data_ids() {
+        // Points at the parameter using `call_data(2)`
+        let error = field_call_data_bus(&[0, 2]).unwrap_err();
+        let call_stack = vec![param_location(1)];
+        assert_eq!(error, RuntimeError::NonContiguousCallDataIds { missing_id: 1, call_stack });
+
+        let error = field_call_data_bus(&[1]).unwrap_err();
+        let call_stack = vec![param_location(0)];
+        assert_eq!(error, RuntimeError::NonContiguousCallDataIds { missing_id: 0, call_stack });
+    }
+
+    #[test]
+    fn accepts_call_data_shared_by_params_of_the_same_numeric_type() {
+        let u32_array = Type::Array(Arc::new(vec![Type::unsigned(32)]), 4);
+        let call_data = call_data_bus(&[(0, Type::unsigned(32)), (0, u32_array)]).unwrap();
+        assert_eq!(call_data.len(), 1);
+    }
+
+    #[test]
+    fn rejects_call_data_shared_by_params_of_different_numeric_types() {
+        let field_array = Type::Array(Arc::new(vec![Type::field()]), 4);
+        let params = [(0, Type::unsigned(32)), (1, Type::field()), (0, field_array)];
+        let error = call_data_bus(&params).unwrap_err();
+        let call_stack = vec![param_location(2)];
+        assert_eq!(error, RuntimeError::MismatchedCallDataTypes { call_data_id: 0, call_stack });
+    }
+}
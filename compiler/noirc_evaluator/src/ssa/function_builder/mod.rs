@@ -121,6 +121,19 @@ impl FunctionBuilder {
         Ssa::new(self.finished_functions, self.error_types)
     }
 
+    /// Consume the FunctionBuilder returning the single function it has built.
+    ///
+    /// This is meant for tests which only need one standalone function, and
+    /// panics if any other function was started with this builder.
+    #[cfg(test)]
+    pub(crate) fn finish_function(self) -> Function {
+        assert!(
+            self.finished_functions.is_empty(),
+            "Attempted to finish a single function on a FunctionBuilder which built several"
+        );
+        self.current_function
+    }
+
     /// Add a parameter to the current function with the given parameter type.
     /// Returns the newly-added parameter.
     pub(crate) fn add_parameter(&mut self, typ: Type) -> ValueId {
@@ -541,7 +554,7 @@ mod tests {
     use acvm::{acir::AcirField, FieldElement};
 
     use crate::ssa::ir::{
-        instruction::{Endian, Intrinsic},
+        instruction::{BinaryOp, Endian, Instruction, Intrinsic, TerminatorInstruction},
         map::Id,
         types::{NumericType, Type},
     };
@@ -571,4 +584,63 @@ mod tests {
         assert_eq!(slice[2], one);
         assert_eq!(slice[3], zero);
     }
+
+    #[test]
+    fn insert_not_on_constants() {
+        let mut builder = FunctionBuilder::new("func".into(), Id::test_new(0));
+        let mut not_constant = |value: u128, typ: NumericType| {
+            let constant = builder.numeric_constant(value, typ);
+            let result = builder.insert_not(constant);
+            builder.current_function.dfg.get_numeric_constant_with_type(result)
+        };
+
+        let bool = NumericType::bool();
+        assert_eq!(not_constant(1, bool), Some((FieldElement::zero(), bool)));
+        let u8 = NumericType::unsigned(8);
+        assert_eq!(not_constant(5, u8), Some((FieldElement::from(250_u128), u8)));
+        let u128 = NumericType::unsigned(128);
+        assert_eq!(not_constant(0, u128), Some((FieldElement::from(u128::MAX), u128)));
+        // -1 in two's complement
+        let i8 = NumericType::signed(8);
+        assert_eq!(not_constant(255, i8), Some((FieldElement::zero(), i8)));
+    }
+
+    #[test]
+    fn finish_function() {
+        let mut builder = FunctionBuilder::new("func".into(), Id::test_new(0));
+        let x = builder.add_parameter(Type::unsigned(8));
+        let condition = builder.add_parameter(Type::bool());
+
+        let then_block = builder.insert_block();
+        let else_block = builder.insert_block();
+        builder.terminate_with_jmpif(condition, then_block, else_block);
+
+        builder.switch_to_block(then_block);
+        let one = builder.numeric_constant(1u128, NumericType::unsigned(8));
+        let sum = builder.insert_binary(x, BinaryOp::Add { unchecked: false }, one);
+        let field = builder.insert_cast(sum, NumericType::NativeField);
+        let array_type = Type::Array(Arc::new(vec![Type::field()]), 1);
+        let array = builder.insert_make_array(vec![field].into(), array_type);
+        builder.terminate_with_return(vec![array]);
+
+        builder.switch_to_block(else_block);
+        builder.terminate_with_return(Vec::new());
+
+        let function = builder.finish_function();
+        assert_eq!(function.id(), Id::test_new(0));
+        assert_eq!(function.parameters(), &[x, condition]);
+        assert_eq!(function.reachable_blocks().len(), 3);
+
+        let instructions = function.dfg[then_block].instructions();
+        assert_eq!(instructions.len(), 3);
+        assert!(matches!(function.dfg[instructions[0]], Instruction::Binary(_)));
+        assert!(matches!(function.dfg[instructions[1]], Instruction::Cast(..)));
+        assert!(matches!(function.dfg[instructions[2]], Instruction::MakeArray { .. }));
+        let Some(TerminatorInstruction::Return { return_values, .. }) =
+            function.dfg[then_block].terminator()
+        else {
+            panic!("Expected a return terminator")
+        };
+        assert_eq!(return_values, &vec![array]);
+    }
 }
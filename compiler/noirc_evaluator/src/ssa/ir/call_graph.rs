@@ -0,0 +1,237 @@
+//! The call graph of an SSA program, built from the direct calls made by each function.
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::ssa::ssa_gen::Ssa;
+
+use super::{
+    function::{Function, FunctionId},
+    instruction::Instruction,
+    value::Value,
+};
+
+/// Maps each function of a program to the functions it calls directly.
+pub(crate) struct CallGraph {
+    callees: BTreeMap<FunctionId, BTreeSet<FunctionId>>,
+}
+
+impl CallGraph {
+    pub(crate) fn from_ssa(ssa: &Ssa) -> Self {
+        let callees =
+            ssa.functions.iter().map(|(id, func)| (*id, called_functions(func))).collect();
+        Self { callees }
+    }
+
+    /// Returns the functions directly called by `function`.
+    pub(crate) fn callees(&self, function: FunctionId) -> impl Iterator<Item = FunctionId> + '_ {
+        self.callees.get(&function).into_iter().flatten().copied()
+    }
+}
+
+/// The result of [Ssa::max_call_depth].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct CallDepth {
+    /// The number of functions on the longest acyclic call chain starting at `main`, including `main`.
+    pub(crate) depth: usize,
+    /// Functions reachable from `main` which can call themselves, directly or not.
+    /// Calls back into these are not counted towards `depth`.
+    pub(crate) recursive_functions: BTreeSet<FunctionId>,
+}
+
+impl Ssa {
+    /// Statically estimates the maximum call stack depth of the program, ignoring recursion.
+    #[allow(unused)]
+    pub(crate) fn max_call_depth(&self) -> CallDepth {
+        let call_graph = CallGraph::from_ssa(self);
+        let mut context = CallDepthContext {
+            call_graph: &call_graph,
+            depths: BTreeMap::new(),
+            stack: Vec::new(),
+            recursive_functions: BTreeSet::new(),
+        };
+        let depth = context.depth(self.main_id);
+        CallDepth { depth, recursive_functions: context.recursive_functions }
+    }
+}
+
+struct CallDepthContext<'a> {
+    call_graph: &'a CallGraph,
+    /// The depth of each function whose depth doesn't depend on the call chain leading to it.
+    depths: BTreeMap<FunctionId, usize>,
+    /// The call chain currently being visited.
+    stack: Vec<FunctionId>,
+    recursive_functions: BTreeSet<FunctionId>,
+}
+
+impl CallDepthContext<'_> {
+    fn depth(&mut self, function: FunctionId) -> usize {
+        self.depth_in_chain(function).0
+    }
+
+    /// Returns the depth of `function` when called at the end of the current call chain, along with
+    /// the lowest position in the chain of a function whose call was skipped as recursive.
+    ///
+    /// Skipping a call back into the chain can shorten the depth found for a function compared to
+    /// reaching it through another chain, so a depth is only cached when every call it skipped was
+    /// back into `function` itself or one of the functions it calls.
+    fn depth_in_chain(&mut self, function: FunctionId) -> (usize, Option<usize>) {
+        if let Some(depth) = self.depths.get(&function) {
+            return (*depth, None);
+        }
+
+        let position = self.stack.len();
+        self.stack.push(function);
+        let mut max_callee_depth = 0;
+        let mut lowest_skipped = None;
+        for callee in self.call_graph.callees(function) {
+            if let Some(callee_position) = self.stack.iter().position(|id| *id == callee) {
+                // Every function from the callee onwards is part of the cycle
+                self.recursive_functions.extend(&self.stack[callee_position..]);
+                lowest_skipped = lowest_skipped.into_iter().chain([callee_position]).min();
+                continue;
+            }
+            let (callee_depth, callee_skipped) = self.depth_in_chain(callee);
+            max_callee_depth = max_callee_depth.max(callee_depth);
+            lowest_skipped = lowest_skipped.into_iter().chain(callee_skipped).min();
+        }
+        self.stack.pop();
+
+        let depth = max_callee_depth + 1;
+        match lowest_skipped {
+            Some(skipped) if skipped < position => (depth, Some(skipped)),
+            _ => {
+                self.depths.insert(function, depth);
+                (depth, None)
+            }
+        }
+    }
+}
+
+/// Returns the functions which are directly called by the given function.
+fn called_functions(func: &Function) -> BTreeSet<FunctionId> {
+    let mut called_function_ids = BTreeSet::new();
+    for block_id in func.reachable_blocks() {
+        for instruction_id in func.dfg[block_id].instructions() {
+            let Instruction::Call { func: called_value_id, .. } = &func.dfg[*instruction_id] else {
+                continue;
+            };
+
+            if let Value::Function(function_id) = func.dfg[*called_value_id] {
+                called_function_ids.insert(function_id);
+            }
+        }
+    }
+    called_function_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::ssa::{ir::map::Id, ssa_gen::Ssa};
+
+    #[test]
+    fn max_call_depth_of_call_chain() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                v4 = call f3(v0) -> Field
+                return v2
+            }
+            acir(inline) fn foo f1 {
+              b0(v0: Field):
+                v2 = call f2(v0) -> Field
+                return v2
+            }
+            acir(inline) fn bar f2 {
+              b0(v0: Field):
+                return v0
+            }
+            acir(inline) fn baz f3 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let call_depth = ssa.max_call_depth();
+        assert_eq!(call_depth.depth, 3);
+        assert!(call_depth.recursive_functions.is_empty());
+    }
+
+    #[test]
+    fn max_call_depth_flags_recursion() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn is_even f1 {
+              b0(v0: Field):
+                v2 = call f2(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn is_odd f2 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let call_depth = ssa.max_call_depth();
+        assert_eq!(call_depth.depth, 3);
+        assert_eq!(
+            call_depth.recursive_functions,
+            BTreeSet::from([Id::test_new(1), Id::test_new(2)])
+        );
+    }
+
+    #[test]
+    fn max_call_depth_does_not_cache_depths_cut_short_by_recursion() {
+        // `main -> f2 -> f1 -> f3 -> f4 -> f5` is the longest chain, but when `f2` is first reached
+        // from `f1` its call back into `f1` is skipped.
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                v3 = call f2(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn a f1 {
+              b0(v0: Field):
+                v2 = call f2(v0) -> Field
+                v3 = call f3(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn b f2 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn c f3 {
+              b0(v0: Field):
+                v2 = call f4(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn d f4 {
+              b0(v0: Field):
+                v2 = call f5(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn e f5 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let call_depth = ssa.max_call_depth();
+        assert_eq!(call_depth.depth, 6);
+        assert_eq!(
+            call_depth.recursive_functions,
+            BTreeSet::from([Id::test_new(1), Id::test_new(2)])
+        );
+    }
+}
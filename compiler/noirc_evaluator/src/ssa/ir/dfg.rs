@@ -7,7 +7,8 @@ use super::{
     call_stack::{CallStack, CallStackHelper, CallStackId},
     function::{FunctionId, RuntimeType},
     instruction::{
-        Instruction, InstructionId, InstructionResultType, Intrinsic, TerminatorInstruction,
+        Binary, BinaryOp, Instruction, InstructionId, InstructionResultType, Intrinsic,
+        TerminatorInstruction,
     },
     map::DenseMap,
     types::{NumericType, Type},
@@ -15,7 +16,7 @@ use super::{
 };
 
 use acvm::{acir::AcirField, FieldElement};
-use fxhash::FxHashMap as HashMap;
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use iter_extended::vecmap;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -162,6 +163,11 @@ impl DataFlowGraph {
         self.blocks[block].parameters()
     }
 
+    /// Returns the number of parameters of the given block.
+    pub(crate) fn num_parameters(&self, block: BasicBlockId) -> usize {
+        self.block_parameters(block).len()
+    }
+
     /// Inserts a new instruction into the DFG.
     /// This does not add the instruction to the block.
     /// Returns the id of the new instruction and its results.
@@ -324,6 +330,23 @@ impl DataFlowGraph {
         }
     }
 
+    /// Rewrites the arguments of every block's terminator through [`Self::resolve`].
+    ///
+    /// Substitutions made with [`Self::set_value_from_id`] are otherwise only applied when
+    /// a value is resolved on read, so passes can call this once they are done replacing
+    /// values to make the terminators themselves refer to the replacements.
+    pub(crate) fn replace_value_in_terminators(&mut self) {
+        let blocks = vecmap(self.blocks.iter(), |(block, _)| block);
+        for block in blocks {
+            let Some(terminator) = self.blocks[block].terminator() else {
+                continue;
+            };
+            let mut terminator = terminator.clone();
+            terminator.map_values_mut(|value| self.resolve(value));
+            self.blocks[block].set_terminator(terminator);
+        }
+    }
+
     /// Creates a new constant value, or returns the Id to an existing one if
     /// one already exists.
     pub(crate) fn make_constant(&mut self, constant: FieldElement, typ: NumericType) -> ValueId {
@@ -482,12 +505,40 @@ impl DataFlowGraph {
         self.results.get(&instruction_id).expect("expected a list of Values").as_slice()
     }
 
-    /// Remove an instruction by replacing it with a `Noop` instruction.
-    /// Doing this avoids shifting over each instruction after this one in its block's instructions vector.
+    /// Iterates over the instructions of `block` in order, each paired with its results.
+    ///
+    /// Results which have been replaced via [`Self::set_value_from_id`] are resolved to the
+    /// values replacing them.
+    #[allow(unused)]
+    pub(crate) fn instructions_with_results(
+        &self,
+        block: BasicBlockId,
+    ) -> impl Iterator<Item = (InstructionId, Vec<ValueId>)> + '_ {
+        self[block].instructions().iter().map(|instruction| {
+            let results = self.instruction_results(*instruction);
+            (*instruction, vecmap(results, |result| self.resolve(*result)))
+        })
+    }
+
+    /// Remove an instruction from the block containing it, leaving a `Noop` tombstone in its place
+    /// in the instruction storage so that the ids of other instructions remain valid.
+    ///
+    /// Finding the containing block requires scanning every block, so passes removing many
+    /// instructions should prefer filtering each block's instructions in one go.
     #[allow(unused)]
     pub(crate) fn remove_instruction(&mut self, instruction: InstructionId) {
+        let block = self
+            .blocks
+            .iter()
+            .find(|(_, block)| block.instructions().contains(&instruction))
+            .map(|(block_id, _)| block_id);
+        if let Some(block) = block {
+            self.blocks[block].instructions_mut().retain(|id| *id != instruction);
+        }
+
         self.instructions[instruction] = Instruction::Noop;
         self.results.insert(instruction, smallvec::SmallVec::new());
+        self.locations.remove(&instruction);
     }
 
     /// Add a parameter to the given block
@@ -539,6 +590,25 @@ impl DataFlowGraph {
         }
     }
 
+    /// Returns the number of items in this array or slice value.
+    ///
+    /// Array lengths come from their type. As a slice's type carries no length, slices are looked
+    /// up in `slice_sizes` instead, falling back to the number of items of a constant slice.
+    pub(crate) fn array_length_of(
+        &self,
+        value: ValueId,
+        slice_sizes: &HashMap<ValueId, u32>,
+    ) -> Option<u32> {
+        match self.type_of_value(value) {
+            Type::Array(_, length) => Some(length),
+            Type::Slice(_) => slice_sizes.get(&value).copied().or_else(|| {
+                let (items, typ) = self.get_array_constant(value)?;
+                Some((items.len() / typ.element_types().len()) as u32)
+            }),
+            _ => None,
+        }
+    }
+
     /// If this value points to an array of constant bytes, returns a string
     /// consisting of those bytes if they form a valid UTF-8 string.
     pub(crate) fn get_string(&self, value: ValueId) -> Option<String> {
@@ -588,6 +658,43 @@ impl DataFlowGraph {
         destination.set_terminator(terminator);
     }
 
+    /// Returns a map from each instruction currently in a block to the block containing it.
+    /// Instructions which aren't in any block, for example because they have been removed with
+    /// [`Self::remove_instruction`], have no entry.
+    ///
+    /// The map is built in a single pass over the blocks and isn't updated as instructions are
+    /// moved afterwards, so passes should build it once and rebuild it after moving instructions.
+    #[allow(unused)]
+    pub(crate) fn instruction_blocks(&self) -> HashMap<InstructionId, BasicBlockId> {
+        let mut instruction_blocks = HashMap::default();
+        for (block_id, block) in self.blocks.iter() {
+            for instruction in block.instructions() {
+                instruction_blocks.insert(*instruction, block_id);
+            }
+        }
+        instruction_blocks
+    }
+
+    /// Splits `block` in two, moving its instructions from `position` onwards along with its
+    /// terminator into a new block which `block` then unconditionally jumps to.
+    /// Returns the new block.
+    pub(crate) fn split_block(&mut self, block: BasicBlockId, position: usize) -> BasicBlockId {
+        let new_block = self.make_block();
+        let instructions = self.blocks[block].instructions_mut().split_off(position);
+        let terminator = self.blocks[block].take_terminator();
+        let call_stack = terminator.call_stack();
+
+        *self.blocks[new_block].instructions_mut() = instructions;
+        self.blocks[new_block].set_terminator(terminator);
+        let jmp = TerminatorInstruction::Jmp {
+            destination: new_block,
+            arguments: Vec::new(),
+            call_stack,
+        };
+        self.blocks[block].set_terminator(jmp);
+        new_block
+    }
+
     pub(crate) fn get_instruction_call_stack(&self, instruction: InstructionId) -> CallStack {
         let call_stack = self.get_instruction_call_stack_id(instruction);
         self.call_stack_data.get_call_stack(call_stack)
@@ -634,6 +741,24 @@ impl DataFlowGraph {
         }
     }
 
+    /// True if the instruction defining `value`, if any, can be moved without changing
+    /// the program's behavior.
+    ///
+    /// Constants, parameters and function references are always pure, while instruction
+    /// results are pure only if their instruction has no side effects.
+    #[allow(unused)]
+    pub(crate) fn is_pure_value(&self, value: ValueId) -> bool {
+        match &self[self.resolve(value)] {
+            Value::Instruction { instruction, .. } => !self[*instruction].has_side_effects(self),
+            Value::Param { .. }
+            | Value::NumericConstant { .. }
+            | Value::Function(_)
+            | Value::Intrinsic(_)
+            | Value::ForeignFunction(_)
+            | Value::Global(_) => true,
+        }
+    }
+
     /// True that the input is a non-zero `Value::NumericConstant`
     pub(crate) fn is_constant_true(&self, argument: ValueId) -> bool {
         if let Some(constant) = self.get_numeric_constant(argument) {
@@ -642,6 +767,71 @@ impl DataFlowGraph {
             false
         }
     }
+
+    /// Returns a `u1` value equal to `value` if `value` is provably either 0 or 1.
+    /// Returns None otherwise.
+    ///
+    /// Casts of booleans are looked through, as are comparisons of such casts against 1,
+    /// so that conditions which are computed differently but are equivalent resolve to the
+    /// same boolean, e.g. both `(b as u8) == 1` and `b as u1` resolve to `b`.
+    pub(crate) fn simplify_known_bool(&mut self, value: ValueId) -> Option<ValueId> {
+        let value = self.resolve(value);
+        if let Some(constant) = self.get_numeric_constant(value) {
+            return (constant.is_zero() || constant.is_one())
+                .then(|| self.make_constant(constant, NumericType::bool()));
+        }
+
+        if let Value::Instruction { instruction, .. } = &self[value] {
+            match &self[*instruction] {
+                Instruction::Cast(original, _) => {
+                    // Casting preserves 0 and 1, but only if the original value is one of them
+                    let original = *original;
+                    if let Some(boolean) = self.simplify_known_bool(original) {
+                        return Some(boolean);
+                    }
+                }
+                Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Eq }) => {
+                    let (lhs, rhs) = (*lhs, *rhs);
+                    let is_one =
+                        |value| self.get_numeric_constant(value).is_some_and(|c| c.is_one());
+                    let compared = if is_one(rhs) {
+                        Some(lhs)
+                    } else if is_one(lhs) {
+                        Some(rhs)
+                    } else {
+                        None
+                    };
+                    if let Some(boolean) =
+                        compared.and_then(|value| self.simplify_known_bool(value))
+                    {
+                        return Some(boolean);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        (self.type_of_value(value) == Type::bool()).then_some(value)
+    }
+
+    /// Returns every value used as an operand by the instructions and terminator of `block`.
+    ///
+    /// Values are resolved, so a value which has been replaced is reported as its replacement.
+    #[allow(unused)]
+    pub(crate) fn collect_used_values(&self, block: BasicBlockId) -> HashSet<ValueId> {
+        let mut used_values = HashSet::default();
+        let mut insert = |value| {
+            used_values.insert(self.resolve(value));
+        };
+
+        for instruction in self.blocks[block].instructions() {
+            self[*instruction].for_each_value(&mut insert);
+        }
+        if let Some(terminator) = self.blocks[block].terminator() {
+            terminator.for_each_value(&mut insert);
+        }
+        used_values
+    }
 }
 
 impl std::ops::Index<InstructionId> for DataFlowGraph {
@@ -748,8 +938,18 @@ impl<'dfg> std::ops::Index<usize> for InsertInstructionResult<'dfg> {
 
 #[cfg(test)]
 mod tests {
+    use acvm::{acir::AcirField, FieldElement};
+    use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
     use super::DataFlowGraph;
-    use crate::ssa::ir::{instruction::Instruction, types::Type};
+    use crate::ssa::{
+        ir::{
+            instruction::{Instruction, TerminatorInstruction},
+            types::{NumericType, Type},
+        },
+        opt::assert_normalized_ssa_equals,
+        ssa_gen::Ssa,
+    };
 
     #[test]
     fn make_instruction() {
@@ -760,4 +960,268 @@ mod tests {
         let results = dfg.instruction_results(ins_id);
         assert_eq!(results.len(), 1);
     }
+
+    #[test]
+    fn simplify_known_bool() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field, v1: u8):
+                v3 = eq v0, Field 0
+                v4 = cast v3 as u8
+                v6 = eq v4, u8 1
+                return v3, v4, v6
+            }
+            ";
+        let mut ssa = Ssa::from_str(src).unwrap();
+        let entry = ssa.main().entry_block();
+        let dfg = &mut ssa.main_mut().dfg;
+        let instructions = dfg[entry].instructions().to_vec();
+        let params = dfg.block_parameters(entry).to_vec();
+        let is_zero = dfg.instruction_results(instructions[0])[0];
+        let is_zero_as_u8 = dfg.instruction_results(instructions[1])[0];
+        let is_zero_as_u8_eq_one = dfg.instruction_results(instructions[2])[0];
+
+        // `(x == 0) as u1` would be simplified on insertion, so build it without simplification
+        let cast = dfg.make_instruction(Instruction::Cast(is_zero, NumericType::bool()), None);
+        let is_zero_as_u1 = dfg.instruction_results(cast)[0];
+
+        assert_eq!(dfg.simplify_known_bool(is_zero), Some(is_zero));
+        assert_eq!(dfg.simplify_known_bool(is_zero_as_u1), Some(is_zero));
+        assert_eq!(dfg.simplify_known_bool(is_zero_as_u8), Some(is_zero));
+        assert_eq!(dfg.simplify_known_bool(is_zero_as_u8_eq_one), Some(is_zero));
+
+        let one = dfg.make_constant(FieldElement::one(), NumericType::unsigned(32));
+        let bool_one = dfg.make_constant(FieldElement::one(), NumericType::bool());
+        assert_eq!(dfg.simplify_known_bool(one), Some(bool_one));
+        let two = dfg.make_constant(FieldElement::from(2_u128), NumericType::unsigned(32));
+        assert_eq!(dfg.simplify_known_bool(two), None);
+        assert_eq!(dfg.simplify_known_bool(params[0]), None);
+        assert_eq!(dfg.simplify_known_bool(params[1]), None);
+    }
+
+    #[test]
+    fn collect_used_values() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field, v1: Field, v2: u1):
+                v3 = add v0, Field 1
+                jmpif v2 then: b1, else: b2
+              b1():
+                return v3
+              b2():
+                return v1
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let dfg = &ssa.main().dfg;
+        let entry = ssa.main().entry_block();
+        let params = dfg.block_parameters(entry);
+
+        let add = dfg[entry].instructions()[0];
+        let Instruction::Binary(binary) = &dfg[add] else {
+            panic!("Expected a binary instruction")
+        };
+        assert_eq!(binary.lhs, params[0]);
+        let expected = HashSet::from_iter([params[0], binary.rhs, params[2]]);
+        assert_eq!(dfg.collect_used_values(entry), expected);
+
+        let Some(TerminatorInstruction::JmpIf { then_destination, .. }) = dfg[entry].terminator()
+        else {
+            panic!("Expected a jmpif terminator")
+        };
+        let add_result = dfg.instruction_results(add)[0];
+        assert_eq!(dfg.collect_used_values(*then_destination), HashSet::from_iter([add_result]));
+    }
+
+    #[test]
+    fn array_length_of() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: [Field; 3], v1: [Field], v2: Field):
+                v3 = make_array [Field 1, Field 2] : [Field]
+                return v0, v1, v3
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let dfg = &ssa.main().dfg;
+        let entry = ssa.main().entry_block();
+        let params = dfg.block_parameters(entry);
+        let (array, slice, field) = (params[0], params[1], params[2]);
+        let constant_slice = dfg.instruction_results(dfg[entry].instructions()[0])[0];
+
+        let mut slice_sizes = HashMap::default();
+        assert_eq!(dfg.array_length_of(array, &slice_sizes), Some(3));
+        assert_eq!(dfg.array_length_of(slice, &slice_sizes), None);
+        assert_eq!(dfg.array_length_of(constant_slice, &slice_sizes), Some(2));
+        assert_eq!(dfg.array_length_of(field, &slice_sizes), None);
+
+        slice_sizes.insert(slice, 5);
+        assert_eq!(dfg.array_length_of(slice, &slice_sizes), Some(5));
+    }
+
+    #[test]
+    fn replace_value_in_terminators() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            ";
+        let mut ssa = Ssa::from_str(src).unwrap();
+        let main = ssa.main_mut();
+        let entry = main.entry_block();
+        let add_result = main.dfg.instruction_results(main.dfg[entry].instructions()[0])[0];
+        let param = main.dfg.block_parameters(entry)[0];
+
+        main.dfg.set_value_from_id(add_result, param);
+        let Some(TerminatorInstruction::Return { return_values, .. }) =
+            main.dfg[entry].terminator()
+        else {
+            panic!("Expected a return terminator")
+        };
+        assert_eq!(return_values, &vec![add_result]);
+
+        main.dfg.replace_value_in_terminators();
+        let Some(TerminatorInstruction::Return { return_values, .. }) =
+            main.dfg[entry].terminator()
+        else {
+            panic!("Expected a return terminator")
+        };
+        assert_eq!(return_values, &vec![param]);
+    }
+
+    #[test]
+    fn is_pure_value() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: [Field; 2], v1: u32):
+                v3 = array_set v0, index v1, value Field 5
+                v4 = make_array [Field 1, Field 2] : [Field; 2]
+                return v3, v4
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let dfg = &ssa.main().dfg;
+        let entry = ssa.main().entry_block();
+        let params = dfg.block_parameters(entry);
+        let instructions = dfg[entry].instructions();
+        let array_set_result = dfg.instruction_results(instructions[0])[0];
+        let make_array_result = dfg.instruction_results(instructions[1])[0];
+        let Instruction::ArraySet { value: constant, .. } = &dfg[instructions[0]] else {
+            panic!("Expected an array_set instruction")
+        };
+
+        assert!(dfg.is_pure_value(*constant));
+        assert!(dfg.is_pure_value(params[0]));
+        assert!(dfg.is_pure_value(params[1]));
+        assert!(dfg.is_pure_value(make_array_result));
+        // Setting an array element may fail on an out of bounds index
+        assert!(!dfg.is_pure_value(array_set_result));
+    }
+
+    #[test]
+    fn remove_instruction() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                v3 = mul v0, Field 2
+                return v3
+            }
+            ";
+        let mut ssa = Ssa::from_str(src).unwrap();
+        let main = ssa.main_mut();
+        let entry = main.entry_block();
+        let add = main.dfg[entry].instructions()[0];
+
+        main.dfg.remove_instruction(add);
+
+        assert!(matches!(main.dfg[add], Instruction::Noop));
+        assert!(main.dfg.instruction_results(add).is_empty());
+        for (_, block) in main.dfg.basic_blocks_iter() {
+            assert!(!block.instructions().contains(&add));
+        }
+
+        // The tombstone is serialized along with the rest of the instructions
+        let serialized = serde_json::to_string(&main.dfg).unwrap();
+        let deserialized: DataFlowGraph = serde_json::from_str(&serialized).unwrap();
+        assert!(matches!(deserialized[add], Instruction::Noop));
+        assert_eq!(deserialized[entry].instructions(), main.dfg[entry].instructions());
+
+        let expected = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = mul v0, Field 2
+                return v2
+            }
+            ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn instructions_with_results() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2, v3 = call f1(v0) -> (Field, Field)
+                v4 = add v2, v3
+                return v4
+            }
+            acir(inline) fn foo f1 {
+              b0(v0: Field):
+                return v0, v0
+            }
+            ";
+        let mut ssa = Ssa::from_str(src).unwrap();
+        let main = ssa.main_mut();
+        let entry = main.entry_block();
+        let instructions = main.dfg[entry].instructions().to_vec();
+        let call_results = main.dfg.instruction_results(instructions[0]).to_vec();
+        let add_result = main.dfg.instruction_results(instructions[1])[0];
+
+        // Replaced results are followed
+        main.dfg.set_value_from_id(call_results[1], main.dfg.block_parameters(entry)[0]);
+
+        let expected = vec![
+            (instructions[0], vec![call_results[0], main.dfg.block_parameters(entry)[0]]),
+            (instructions[1], vec![add_result]),
+        ];
+        assert_eq!(main.dfg.instructions_with_results(entry).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn instruction_blocks() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                jmp b1()
+              b1():
+                v3 = mul v0, Field 2
+                v4 = mul v3, Field 3
+                return v4
+            }
+            ";
+        let mut ssa = Ssa::from_str(src).unwrap();
+        let main = ssa.main_mut();
+        let b0 = main.entry_block();
+        let b1 = main.dfg[b0].successors().next().unwrap();
+        let add = main.dfg[b0].instructions()[0];
+        let [mul, second_mul] = main.dfg[b1].instructions().try_into().unwrap();
+
+        let instruction_blocks = main.dfg.instruction_blocks();
+        assert_eq!(instruction_blocks.len(), 3);
+        assert_eq!(instruction_blocks.get(&add), Some(&b0));
+        assert_eq!(instruction_blocks.get(&mul), Some(&b1));
+        assert_eq!(instruction_blocks.get(&second_mul), Some(&b1));
+
+        // A rebuilt map reflects instructions moved between blocks and removed from them
+        main.dfg.inline_block(b1, b0);
+        main.dfg.remove_instruction(second_mul);
+        let instruction_blocks = main.dfg.instruction_blocks();
+        assert_eq!(instruction_blocks.get(&add), Some(&b0));
+        assert_eq!(instruction_blocks.get(&mul), Some(&b0));
+        assert_eq!(instruction_blocks.get(&second_mul), None);
+    }
 }
@@ -5,6 +5,7 @@ use noirc_frontend::monomorphization::ast::InlineType;
 use serde::{Deserialize, Serialize};
 
 use super::basic_block::BasicBlockId;
+use super::cfg::ControlFlowGraph;
 use super::dfg::DataFlowGraph;
 use super::instruction::TerminatorInstruction;
 use super::map::Id;
@@ -159,6 +160,12 @@ impl Function {
         self.dfg.block_parameters(self.entry_block)
     }
 
+    /// Returns the number of parameters of this function together with their types.
+    pub(crate) fn arity(&self) -> (usize, Vec<Type>) {
+        let parameter_types = vecmap(self.parameters(), |param| self.dfg.type_of_value(*param));
+        (self.dfg.num_parameters(self.entry_block), parameter_types)
+    }
+
     /// Returns the return types of this function.
     pub(crate) fn returns(&self) -> &[ValueId] {
         let blocks = self.reachable_blocks();
@@ -191,8 +198,45 @@ impl Function {
         blocks
     }
 
+    /// Returns the number of instructions in the reachable blocks of this function.
+    /// Terminators are not counted.
+    pub(crate) fn instruction_count(&self) -> usize {
+        self.reachable_blocks().into_iter().map(|block| self.dfg[block].instructions().len()).sum()
+    }
+
+    /// Asserts that every reachable block of this function has a terminator.
+    ///
+    /// Blocks only lack a terminator while they are being constructed,
+    /// so a pass leaving one unterminated has a bug.
+    pub(crate) fn assert_all_blocks_terminated(&self) {
+        for block in self.reachable_blocks() {
+            assert!(
+                self.dfg[block].terminator().is_some(),
+                "Block {block} of function {} ({}) has no terminator",
+                self.name(),
+                self.id(),
+            );
+        }
+    }
+
+    /// Asserts that no block of this function jumps back to its entry block.
+    ///
+    /// The entry block's parameters are the function's parameters, so a jump to it
+    /// would overwrite them, which passes assume never happens.
+    pub(crate) fn assert_entry_block_has_no_predecessors(&self) {
+        let cfg = ControlFlowGraph::with_function(self);
+        let predecessors: Vec<_> = cfg.predecessors(self.entry_block).collect();
+        assert!(
+            predecessors.is_empty(),
+            "Entry block {} of function {} ({}) has predecessors {predecessors:?}",
+            self.entry_block,
+            self.name(),
+            self.id(),
+        );
+    }
+
     pub(crate) fn signature(&self) -> Signature {
-        let params = vecmap(self.parameters(), |param| self.dfg.type_of_value(*param));
+        let (_, params) = self.arity();
         let returns = vecmap(self.returns(), |ret| self.dfg.type_of_value(*ret));
         Signature { params, returns }
     }
@@ -243,3 +287,65 @@ fn sign_smoke() {
     signature.params.push(Type::Numeric(super::types::NumericType::NativeField));
     signature.returns.push(Type::Numeric(super::types::NumericType::Unsigned { bit_size: 32 }));
 }
+
+#[test]
+fn arity() {
+    let mut function = Function::new("foo".to_owned(), Id::test_new(0));
+    let entry_block = function.entry_block();
+    function.dfg.add_block_parameter(entry_block, Type::field());
+    function.dfg.add_block_parameter(entry_block, Type::bool());
+
+    assert_eq!(function.arity(), (2, vec![Type::field(), Type::bool()]));
+}
+
+#[test]
+fn all_blocks_terminated() {
+    let mut function = Function::new("foo".to_owned(), Id::test_new(0));
+    let entry_block = function.entry_block();
+    function.dfg.set_block_terminator(
+        entry_block,
+        TerminatorInstruction::Return {
+            return_values: Vec::new(),
+            call_stack: super::call_stack::CallStackId::root(),
+        },
+    );
+
+    function.assert_all_blocks_terminated();
+}
+
+#[test]
+#[should_panic(expected = "has no terminator")]
+fn unterminated_block_fails_check() {
+    let mut function = Function::new("foo".to_owned(), Id::test_new(0));
+    let entry_block = function.entry_block();
+    let unterminated_block = function.dfg.make_block();
+    function.dfg.set_block_terminator(
+        entry_block,
+        TerminatorInstruction::Jmp {
+            destination: unterminated_block,
+            arguments: Vec::new(),
+            call_stack: super::call_stack::CallStackId::root(),
+        },
+    );
+
+    function.assert_all_blocks_terminated();
+}
+
+#[test]
+#[should_panic(expected = "has predecessors")]
+fn jump_to_entry_block_fails_check() {
+    let mut function = Function::new("foo".to_owned(), Id::test_new(0));
+    let entry_block = function.entry_block();
+    let loop_block = function.dfg.make_block();
+    let call_stack = super::call_stack::CallStackId::root();
+    function.dfg.set_block_terminator(
+        entry_block,
+        TerminatorInstruction::Jmp { destination: loop_block, arguments: Vec::new(), call_stack },
+    );
+    function.dfg.set_block_terminator(
+        loop_block,
+        TerminatorInstruction::Jmp { destination: entry_block, arguments: Vec::new(), call_stack },
+    );
+
+    function.assert_entry_block_has_no_predecessors();
+}
@@ -0,0 +1,64 @@
+//! Renders the control flow graph of a function in the Graphviz DOT format for debugging.
+use std::fmt::Write;
+
+use super::function::Function;
+
+impl Function {
+    /// Returns a DOT digraph with a node for each reachable block, labelled with its
+    /// instruction count, and an edge from each block to each of its successors.
+    pub(crate) fn cfg_to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph \"{} {}\" {{", self.name(), self.id()).unwrap();
+        writeln!(dot, "  node [shape=box];").unwrap();
+
+        let blocks = self.reachable_blocks();
+        for block in &blocks {
+            let count = self.dfg[*block].instructions().len();
+            let plural = if count == 1 { "" } else { "s" };
+            writeln!(dot, "  {block} [label=\"{block}\\n{count} instruction{plural}\"];").unwrap();
+        }
+        for block in &blocks {
+            for successor in self.dfg[*block].successors() {
+                writeln!(dot, "  {block} -> {successor};").unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::ssa_gen::Ssa;
+
+    #[test]
+    fn branching_function_to_dot() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u1, v1: Field):
+                jmpif v0 then: b2, else: b1
+              b1():
+                jmp b3(v1)
+              b2():
+                v3 = add v1, Field 1
+                v4 = mul v3, v3
+                jmp b3(v4)
+              b3(v2: Field):
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let dot = ssa.main().cfg_to_dot();
+
+        assert!(dot.starts_with("digraph \"main f0\" {\n"));
+        assert!(dot.contains("  b0 [label=\"b0\\n0 instructions\"];\n"));
+        assert!(dot.contains("  b1 [label=\"b1\\n0 instructions\"];\n"));
+        assert!(dot.contains("  b2 [label=\"b2\\n2 instructions\"];\n"));
+        assert!(dot.contains("  b3 [label=\"b3\\n0 instructions\"];\n"));
+        for edge in ["b0 -> b1", "b0 -> b2", "b1 -> b3", "b2 -> b3"] {
+            assert!(dot.contains(&format!("  {edge};\n")), "missing edge {edge} in:\n{dot}");
+        }
+        assert_eq!(dot.matches(" -> ").count(), 4);
+    }
+}
@@ -220,6 +220,12 @@ impl Intrinsic {
     }
 }
 
+/// True if `name` is the name of an intrinsic, i.e. a `#[builtin(name)]` function
+/// which can be lowered to SSA.
+pub fn is_intrinsic(name: &str) -> bool {
+    Intrinsic::lookup(name).is_some()
+}
+
 /// The endian-ness of bits when encoding values as bits in e.g. ToBits or ToRadix
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) enum Endian {
@@ -425,7 +431,7 @@ impl Instruction {
                 | BinaryOp::And
                 | BinaryOp::Or
                 | BinaryOp::Xor
-                | BinaryOp::Shl
+                | BinaryOp::Shl { .. }
                 | BinaryOp::Shr => false,
             },
 
@@ -588,7 +594,7 @@ impl Instruction {
                     | BinaryOp::And
                     | BinaryOp::Or
                     | BinaryOp::Xor
-                    | BinaryOp::Shl
+                    | BinaryOp::Shl { .. }
                     | BinaryOp::Shr => false,
                 }
             }
@@ -851,12 +857,16 @@ impl Instruction {
             Instruction::Cast(value, typ) => simplify_cast(*value, *typ, dfg),
             Instruction::Not(value) => {
                 match &dfg[dfg.resolve(*value)] {
-                    // Limit optimizing ! on constants to only booleans. If we tried it on fields,
+                    // Limit optimizing ! on constants to only integers. If we tried it on fields,
                     // there is no Not on FieldElement, so we'd need to convert between u128. This
                     // would be incorrect however since the extra bits on the field would not be flipped.
-                    Value::NumericConstant { constant, typ } if typ.is_unsigned() => {
+                    // Signed constants are stored in two's complement, so they are flipped the same way.
+                    Value::NumericConstant { constant, typ }
+                        if *typ != NumericType::NativeField =>
+                    {
                         // As we're casting to a `u128`, we need to clear out any upper bits that the NOT fills.
-                        let value = !constant.to_u128() % (1 << typ.bit_size());
+                        let mask = u128::MAX >> (128 - typ.bit_size());
+                        let value = !constant.to_u128() & mask;
                         SimplifiedTo(dfg.make_constant(value.into(), *typ))
                     }
                     Value::Instruction { instruction, .. } => {
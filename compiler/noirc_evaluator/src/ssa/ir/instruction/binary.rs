@@ -39,8 +39,9 @@ pub(crate) enum BinaryOp {
     Or,
     /// Bitwise xor (^)
     Xor,
-    /// Bitshift left (<<)
-    Shl,
+    /// Bitshift left (<<).
+    /// An unchecked shift is known to shift by less than the bit size of its operand.
+    Shl { unchecked: bool },
     /// Bitshift right (>>)
     Shr,
 }
@@ -61,7 +62,8 @@ impl std::fmt::Display for BinaryOp {
             BinaryOp::And => write!(f, "and"),
             BinaryOp::Or => write!(f, "or"),
             BinaryOp::Xor => write!(f, "xor"),
-            BinaryOp::Shl => write!(f, "shl"),
+            BinaryOp::Shl { unchecked: false } => write!(f, "shl"),
+            BinaryOp::Shl { unchecked: true } => write!(f, "unchecked_shl"),
             BinaryOp::Shr => write!(f, "shr"),
         }
     }
@@ -87,6 +89,25 @@ impl Binary {
         }
     }
 
+    /// Returns the operands of this instruction in a canonical order if its operator is commutative,
+    /// so that `a + b` and `b + a` are deduplicated.
+    ///
+    /// Constants are placed on the right, other values are ordered by their id.
+    fn canonical_operands(&self, dfg: &DataFlowGraph) -> (ValueId, ValueId) {
+        if !self.operator.is_commutative() {
+            return (self.lhs, self.rhs);
+        }
+        let key = |value: ValueId| {
+            let value = dfg.resolve(value);
+            (dfg.is_constant(value), value)
+        };
+        if key(self.rhs) < key(self.lhs) {
+            (self.rhs, self.lhs)
+        } else {
+            (self.lhs, self.rhs)
+        }
+    }
+
     /// Try to simplify this binary instruction, returning the new value if possible.
     pub(super) fn simplify(&self, dfg: &mut DataFlowGraph) -> SimplifyResult {
         let lhs_value = dfg.get_numeric_constant(self.lhs);
@@ -96,7 +117,7 @@ impl Binary {
         let rhs_type = dfg.type_of_value(self.rhs).unwrap_numeric();
 
         let operator = self.operator;
-        if operator != BinaryOp::Shl && operator != BinaryOp::Shr {
+        if !matches!(operator, BinaryOp::Shl { .. } | BinaryOp::Shr) {
             assert_eq!(
                 lhs_type, rhs_type,
                 "ICE - Binary instruction operands must have the same type"
@@ -123,8 +144,10 @@ impl Binary {
             operator
         };
 
-        // We never return `SimplifyResult::None` here because `operator` might have changed.
-        let simplified = Instruction::Binary(Binary { lhs: self.lhs, rhs: self.rhs, operator });
+        // We never return `SimplifyResult::None` here because `operator` or the operand order
+        // might have changed.
+        let (lhs, rhs) = self.canonical_operands(dfg);
+        let simplified = Instruction::Binary(Binary { lhs, rhs, operator });
 
         if let (Some(lhs), Some(rhs)) = (lhs_value, rhs_value) {
             return match eval_constant_binary_op(lhs, rhs, operator, lhs_type) {
@@ -342,7 +365,7 @@ impl Binary {
                     return SimplifyResult::SimplifiedTo(zero);
                 }
             }
-            BinaryOp::Shl => return SimplifyResult::SimplifiedToInstruction(simplified),
+            BinaryOp::Shl { .. } => return SimplifyResult::SimplifiedToInstruction(simplified),
             BinaryOp::Shr => {
                 // Bit shifts by constants can be treated as divisions.
                 if let Some(rhs_const) = rhs_value {
@@ -506,6 +529,19 @@ fn truncate(int: u128, bit_size: u32) -> u128 {
 }
 
 impl BinaryOp {
+    /// True if swapping the operands of this operator doesn't change its result.
+    pub(crate) fn is_commutative(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Add { .. }
+                | BinaryOp::Mul { .. }
+                | BinaryOp::And
+                | BinaryOp::Or
+                | BinaryOp::Xor
+                | BinaryOp::Eq
+        )
+    }
+
     fn get_field_function(self) -> Option<fn(FieldElement, FieldElement) -> FieldElement> {
         match self {
             BinaryOp::Add { .. } => Some(std::ops::Add::add),
@@ -519,7 +555,7 @@ impl BinaryOp {
             BinaryOp::And => None,
             BinaryOp::Or => None,
             BinaryOp::Xor => None,
-            BinaryOp::Shl => None,
+            BinaryOp::Shl { .. } => None,
             BinaryOp::Shr => None,
         }
     }
@@ -536,7 +572,7 @@ impl BinaryOp {
             BinaryOp::Xor => |x, y| Some(x ^ y),
             BinaryOp::Eq => |x, y| Some((x == y) as u128),
             BinaryOp::Lt => |x, y| Some((x < y) as u128),
-            BinaryOp::Shl => |x, y| Some(x << y),
+            BinaryOp::Shl { .. } => |x, y| Some(x << y),
             BinaryOp::Shr => |x, y| Some(x >> y),
         }
     }
@@ -553,7 +589,7 @@ impl BinaryOp {
             BinaryOp::Xor => |x, y| Some(x ^ y),
             BinaryOp::Eq => |x, y| Some((x == y) as i128),
             BinaryOp::Lt => |x, y| Some((x < y) as i128),
-            BinaryOp::Shl => |x, y| Some(x << y),
+            BinaryOp::Shl { .. } => |x, y| Some(x << y),
             BinaryOp::Shr => |x, y| Some(x >> y),
         }
     }
@@ -563,10 +599,54 @@ impl BinaryOp {
 mod test {
     use proptest::prelude::*;
 
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            instruction::Instruction,
+            map::Id,
+            types::{NumericType, Type},
+            value::Value,
+        },
+    };
+
     use super::{
         convert_signed_integer_to_field_element, try_convert_field_element_to_signed_integer,
+        Binary, BinaryOp,
     };
 
+    #[test]
+    fn commutative_operands_are_canonicalized() {
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+        let a = builder.add_parameter(Type::unsigned(32));
+        let b = builder.add_parameter(Type::unsigned(32));
+        let one = builder.numeric_constant(1u128, NumericType::unsigned(32));
+
+        let add = BinaryOp::Add { unchecked: false };
+        let a_plus_b = builder.insert_binary(a, add, b);
+        let b_plus_a = builder.insert_binary(b, add, a);
+        let one_plus_a = builder.insert_binary(one, add, a);
+        let b_minus_a = builder.insert_binary(b, BinaryOp::Sub { unchecked: false }, a);
+
+        let function = builder.finish_function();
+        let instruction = |value| match &function.dfg[value] {
+            Value::Instruction { instruction, .. } => function.dfg[*instruction].clone(),
+            _ => unreachable!("Expected an instruction result"),
+        };
+
+        let expected = Instruction::Binary(Binary { lhs: a, rhs: b, operator: add });
+        assert_eq!(instruction(a_plus_b), expected);
+        assert_eq!(instruction(b_plus_a), expected);
+
+        // Constants are placed on the right
+        let expected = Instruction::Binary(Binary { lhs: a, rhs: one, operator: add });
+        assert_eq!(instruction(one_plus_a), expected);
+
+        // Non-commutative operators are left untouched
+        let sub = BinaryOp::Sub { unchecked: false };
+        let expected = Instruction::Binary(Binary { lhs: b, rhs: a, operator: sub });
+        assert_eq!(instruction(b_minus_a), expected);
+    }
+
     proptest! {
         #[test]
         fn signed_int_roundtrip(int: i128, bit_size in 1u32..=64) {
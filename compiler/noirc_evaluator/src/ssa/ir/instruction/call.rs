@@ -19,7 +19,7 @@ use crate::ssa::{
         types::{NumericType, Type},
         value::{Value, ValueId},
     },
-    opt::flatten_cfg::value_merger::ValueMerger,
+    opt::flatten_cfg::value_merger::{ValueMerger, DEFAULT_MAX_ARRAY_SET_LOOKBACK},
 };
 
 use super::{Binary, BinaryOp, Endian, Hint, Instruction, SimplifyResult};
@@ -448,15 +448,26 @@ fn simplify_slice_push_back(
     slice_sizes.insert(new_slice, slice_size / element_size);
 
     let unknown = &mut HashMap::default();
-    let mut value_merger =
-        ValueMerger::new(dfg, block, &mut slice_sizes, unknown, None, call_stack);
+    // Without a current condition no ArraySet is looked back at, whatever the limit
+    let mut value_merger = ValueMerger::new(
+        dfg,
+        block,
+        &mut slice_sizes,
+        unknown,
+        None,
+        call_stack,
+        DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+    );
 
-    let new_slice = value_merger.merge_values(
+    // Slices of references can't be merged, so pushing to them is left as is
+    let Ok(new_slice) = value_merger.merge_values(
         len_not_equals_capacity,
         len_equals_capacity,
         set_last_slice_value,
         new_slice,
-    );
+    ) else {
+        return SimplifyResult::None;
+    };
 
     SimplifyResult::SimplifiedToMultiple(vec![new_slice_length, new_slice])
 }
@@ -764,4 +775,46 @@ mod tests {
             "#;
         assert_normalized_ssa_equals(ssa, expected);
     }
+
+    #[test]
+    fn simplifies_to_bits_of_constant() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0():
+                v1 = call to_le_bits(Field 6) -> [u1; 4]
+                return v1
+            }
+            ";
+        let ssa = Ssa::from_str_simplifying(src).unwrap();
+
+        let expected = "
+            acir(inline) fn main f0 {
+              b0():
+                v2 = make_array [u1 0, u1 1, u1 1, u1 0] : [u1; 4]
+                return v2
+            }
+            ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn simplifies_to_radix_of_constant() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0():
+                v2 = call to_be_radix(Field 258, u32 256) -> [u8; 3]
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str_simplifying(src).unwrap();
+
+        let expected = "
+            acir(inline) fn main f0 {
+              b0():
+                v3 = make_array [u8 0, u8 1, u8 2] : [u8; 3]
+                return v3
+            }
+            ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
 }
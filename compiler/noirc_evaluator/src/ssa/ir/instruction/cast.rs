@@ -14,8 +14,14 @@ pub(super) fn simplify_cast(
     let value = dfg.resolve(value);
 
     if let Value::Instruction { instruction, .. } = &dfg[value] {
-        if let Instruction::Cast(original_value, _) = &dfg[*instruction] {
-            return SimplifiedToInstruction(Instruction::Cast(*original_value, dst_typ));
+        if let Instruction::Cast(original_value, intermediate_typ) = &dfg[*instruction] {
+            // `x as a as b` is only `x as b` if casting to `a` drops no bits which `b` keeps,
+            // e.g. `x as u8 as u32` with `x: u32` clears its upper bits while `x as u32` doesn't.
+            let original_typ = dfg.type_of_value(*original_value).unwrap_numeric();
+            let kept_bits = original_typ.bit_size().min(dst_typ.bit_size());
+            if intermediate_typ.bit_size() >= kept_bits {
+                return SimplifiedToInstruction(Instruction::Cast(*original_value, dst_typ));
+            }
         }
     }
 
@@ -37,12 +43,11 @@ pub(super) fn simplify_cast(
                 NumericType::NativeField
                 | NumericType::Unsigned { .. }
                 | NumericType::Signed { .. },
-                NumericType::Unsigned { bit_size },
+                NumericType::Unsigned { .. },
             ) => {
                 // Field/Unsigned -> unsigned: truncate
-                let integer_modulus = BigUint::from(2u128).pow(bit_size);
                 let constant: BigUint = BigUint::from_bytes_be(&constant.to_be_bytes());
-                let truncated = constant % integer_modulus;
+                let truncated = constant % dst_typ.modulus();
                 let truncated = FieldElement::from_be_bytes_reduce(&truncated.to_bytes_be());
                 SimplifiedTo(dfg.make_constant(truncated, dst_typ))
             }
@@ -50,13 +55,12 @@ pub(super) fn simplify_cast(
                 NumericType::NativeField
                 | NumericType::Unsigned { .. }
                 | NumericType::Signed { .. },
-                NumericType::Signed { bit_size },
+                NumericType::Signed { .. },
             ) => {
                 // Field/Unsigned -> signed
                 // We only simplify to signed when we are below the maximum signed integer of the destination type.
-                let integer_modulus = BigUint::from(2u128).pow(bit_size - 1);
-                let constant_uint: BigUint = BigUint::from_bytes_be(&constant.to_be_bytes());
-                if constant_uint < integer_modulus {
+                let max_value = dst_typ.max_value().expect("Signed integers have a maximum value");
+                if constant <= max_value {
                     SimplifiedTo(dfg.make_constant(constant, dst_typ))
                 } else {
                     None
@@ -69,3 +73,78 @@ pub(super) fn simplify_cast(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::{acir::AcirField, FieldElement};
+
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            instruction::Instruction,
+            map::Id,
+            types::{NumericType, Type},
+            value::{Value, ValueId},
+        },
+    };
+
+    /// Returns the value cast by the cast instruction defining `cast`.
+    fn cast_operand(builder: &FunctionBuilder, cast: ValueId) -> ValueId {
+        let dfg = &builder.current_function.dfg;
+        let Value::Instruction { instruction, .. } = &dfg[cast] else {
+            panic!("Expected {cast} to be an instruction result")
+        };
+        let Instruction::Cast(value, _) = &dfg[*instruction] else {
+            panic!("Expected {cast} to be the result of a cast")
+        };
+        *value
+    }
+
+    #[test]
+    fn collapses_only_lossless_cast_chains() {
+        let mut builder = FunctionBuilder::new("func".into(), Id::test_new(0));
+        let x = builder.add_parameter(Type::unsigned(32));
+
+        // The intermediate cast clears bits of `x` which the last cast keeps
+        let narrowed = builder.insert_cast(x, NumericType::unsigned(8));
+        let widened = builder.insert_cast(narrowed, NumericType::unsigned(32));
+        assert_eq!(cast_operand(&builder, widened), narrowed);
+
+        // The intermediate cast keeps every bit of `x`
+        let widened = builder.insert_cast(x, NumericType::unsigned(64));
+        let narrowed = builder.insert_cast(widened, NumericType::unsigned(16));
+        assert_eq!(cast_operand(&builder, narrowed), x);
+
+        // The intermediate cast only clears bits which the last cast clears as well
+        let narrowed = builder.insert_cast(x, NumericType::unsigned(16));
+        let narrowed = builder.insert_cast(narrowed, NumericType::unsigned(8));
+        assert_eq!(cast_operand(&builder, narrowed), x);
+    }
+
+    #[test]
+    fn simplifies_casts_of_constants() {
+        let mut builder = FunctionBuilder::new("func".into(), Id::test_new(0));
+        let mut cast_constant = |value: FieldElement, typ: NumericType| {
+            let constant = builder.field_constant(value);
+            let result = builder.insert_cast(constant, typ);
+            builder.current_function.dfg.get_numeric_constant(result)
+        };
+
+        // Unsigned casts truncate
+        let u1 = NumericType::bool();
+        assert_eq!(cast_constant(FieldElement::from(3_u128), u1), Some(FieldElement::one()));
+        let u127 = NumericType::unsigned(127);
+        let value = FieldElement::from(u128::MAX);
+        assert_eq!(cast_constant(value, u127), Some(FieldElement::from(u128::MAX >> 1)));
+
+        // Signed casts are only simplified for values below the maximum of the type
+        let i8 = NumericType::signed(8);
+        let value = FieldElement::from(127_u128);
+        assert_eq!(cast_constant(value, i8), Some(value));
+        assert_eq!(cast_constant(FieldElement::from(128_u128), i8), None);
+
+        let field = NumericType::NativeField;
+        let value = -FieldElement::one();
+        assert_eq!(cast_constant(value, field), Some(value));
+    }
+}
@@ -194,6 +194,15 @@ impl<T> DenseMap<T> {
         let ids_iter = (0..self.storage.len() as u32).map(|idx| Id::new(idx));
         ids_iter.zip(self.storage.iter())
     }
+
+    /// Gets an iterator to a mutable reference to each element in the dense map paired with its id.
+    ///
+    /// The id-element pairs are ordered by the numeric values of the ids, as with [`Self::iter`].
+    #[allow(unused)]
+    pub(crate) fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (Id<T>, &mut T)> {
+        let ids_iter = (0..self.storage.len() as u32).map(|idx| Id::new(idx));
+        ids_iter.zip(self.storage.iter_mut())
+    }
 }
 
 impl<T> Default for DenseMap<T> {
@@ -295,3 +304,25 @@ impl<T> Default for AtomicCounter<T> {
         Self { next: Default::default(), _marker: Default::default() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DenseMap;
+
+    #[test]
+    fn dense_map_iter_mut_visits_elements_in_id_order() {
+        let mut map = DenseMap::default();
+        let ids = [map.insert(10), map.insert(20), map.insert(30)];
+
+        let iter = map.iter_mut();
+        assert_eq!(iter.len(), 3);
+        for (index, (id, element)) in iter.enumerate() {
+            assert_eq!(id, ids[index]);
+            *element += 1;
+        }
+
+        assert_eq!(map[ids[0]], 11);
+        assert_eq!(map[ids[1]], 21);
+        assert_eq!(map[ids[2]], 31);
+    }
+}
@@ -1,13 +1,16 @@
 pub(crate) mod basic_block;
+pub(crate) mod call_graph;
 pub(crate) mod call_stack;
 pub(crate) mod cfg;
 pub(crate) mod dfg;
 pub(crate) mod dom;
 pub(crate) mod function;
 pub(crate) mod function_inserter;
+pub(crate) mod graphviz;
 pub(crate) mod instruction;
 pub(crate) mod map;
 pub(crate) mod post_order;
 pub(crate) mod printer;
 pub(crate) mod types;
 pub(crate) mod value;
+pub(crate) mod value_ranges;
@@ -3,7 +3,7 @@
 //! This ordering is beneficial to the efficiency of various algorithms, such as those for dead
 //! code elimination and calculating dominance trees.
 
-use std::collections::HashSet;
+use fxhash::FxHashSet as HashSet;
 
 use crate::ssa::ir::{basic_block::BasicBlockId, function::Function};
 
@@ -36,7 +36,7 @@ impl PostOrder {
     // to when the traversal exits it.
     fn compute_post_order(func: &Function) -> Vec<BasicBlockId> {
         let mut stack = vec![(Visit::First, func.entry_block())];
-        let mut visited: HashSet<BasicBlockId> = HashSet::new();
+        let mut visited: HashSet<BasicBlockId> = HashSet::default();
         let mut post_order: Vec<BasicBlockId> = Vec::new();
 
         while let Some((visit, block_id)) = stack.pop() {
@@ -92,14 +92,18 @@ fn value(dfg: &DataFlowGraph, id: ValueId) -> String {
     }
 }
 
+/// Display a value along with its type. E.g. `v5: [Field; 3]`
+///
+/// This is useful in error messages, where a bare value id says little about what went wrong.
+pub(crate) fn value_with_type(dfg: &DataFlowGraph, id: ValueId) -> String {
+    let value = value(dfg, id);
+    let typ = dfg.type_of_value(id);
+    format!("{value}: {typ}")
+}
+
 /// Display each value along with its type. E.g. `v0: Field, v1: u64, v2: u1`
 fn value_list_with_types(dfg: &DataFlowGraph, values: &[ValueId]) -> String {
-    vecmap(values, |id| {
-        let value = value(dfg, *id);
-        let typ = dfg.type_of_value(*id);
-        format!("{value}: {typ}")
-    })
-    .join(", ")
+    vecmap(values, |id| value_with_type(dfg, *id)).join(", ")
 }
 
 /// Display each value separated by a comma
@@ -344,3 +348,29 @@ fn display_constrain_error(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::value_with_type;
+    use crate::ssa::ir::{
+        dfg::DataFlowGraph,
+        instruction::Instruction,
+        types::{NumericType, Type},
+    };
+
+    #[test]
+    fn displays_value_with_its_type() {
+        let mut dfg = DataFlowGraph::default();
+        let typ = Type::Array(Arc::new(vec![Type::field()]), 3);
+        let instruction = Instruction::MakeArray { elements: im::Vector::new(), typ: typ.clone() };
+        let instruction_id = dfg.make_instruction(instruction, Some(vec![typ]));
+        let array = dfg.instruction_results(instruction_id)[0];
+
+        assert_eq!(value_with_type(&dfg, array), format!("{array}: [Field; 3]"));
+
+        let constant = dfg.make_constant(5_u128.into(), NumericType::unsigned(32));
+        assert_eq!(value_with_type(&dfg, constant), "u32 5: u32");
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use acvm::{acir::AcirField, FieldElement};
 use iter_extended::vecmap;
+use num_bigint::BigUint;
 
 use crate::ssa::ssa_gen::SSA_WORD_SIZE;
 
@@ -55,6 +56,27 @@ impl NumericType {
         NumericType::Unsigned { bit_size: SSA_WORD_SIZE }
     }
 
+    /// Returns the modulus of the arithmetic of this type, which is `2^bit_size` for integers
+    /// (in two's complement for signed integers) and the field modulus for fields.
+    pub(crate) fn modulus(&self) -> BigUint {
+        match self {
+            NumericType::NativeField => FieldElement::modulus(),
+            NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => {
+                BigUint::from(1u32) << *bit_size
+            }
+        }
+    }
+
+    /// Returns the largest value of this type, or None for fields as they are not ordered.
+    pub(crate) fn max_value(&self) -> Option<FieldElement> {
+        let max_value = match self {
+            NumericType::NativeField => return None,
+            NumericType::Unsigned { .. } => self.modulus() - 1u32,
+            NumericType::Signed { bit_size } => (BigUint::from(1u32) << (bit_size - 1)) - 1u32,
+        };
+        Some(FieldElement::from_be_bytes_reduce(&max_value.to_bytes_be()))
+    }
+
     /// Returns None if the given Field value is within the numeric limits
     /// for the current NumericType. Otherwise returns a string describing
     /// the limits, as a range.
@@ -304,6 +326,25 @@ impl std::fmt::Display for NumericType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn max_value_and_modulus() {
+        let u1 = NumericType::bool();
+        assert_eq!(u1.max_value(), Some(FieldElement::one()));
+        assert_eq!(u1.modulus(), BigUint::from(2u32));
+
+        let u127 = NumericType::unsigned(127);
+        assert_eq!(u127.max_value(), Some(FieldElement::from(u128::MAX >> 1)));
+        assert_eq!(u127.modulus(), BigUint::from(1u128 << 127));
+
+        let i8 = NumericType::signed(8);
+        assert_eq!(i8.max_value(), Some(FieldElement::from(127_u128)));
+        assert_eq!(i8.modulus(), BigUint::from(256u32));
+
+        let field = NumericType::NativeField;
+        assert_eq!(field.max_value(), None);
+        assert_eq!(field.modulus(), FieldElement::modulus());
+    }
+
     #[test]
     fn test_u8_value_is_outside_limits() {
         let u8 = NumericType::Unsigned { bit_size: 8 };
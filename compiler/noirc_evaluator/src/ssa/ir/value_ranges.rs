@@ -0,0 +1,140 @@
+//! A lightweight range analysis computing an upper bound on the number of bits of each value.
+//!
+//! [`DataFlowGraph::get_value_max_num_bits`] only looks at a value's type and, for casts, at the
+//! type of the original value. This analysis additionally propagates bounds through truncations
+//! and unsigned arithmetic, so that e.g. the result of `and v0, u32 255` is known to fit in 8 bits.
+//! Passes can use these tighter bounds to avoid range checks and truncations.
+use std::collections::BTreeMap;
+
+use fxhash::FxHashMap as HashMap;
+
+use crate::ssa::{
+    ir::{
+        dfg::DataFlowGraph,
+        function::{Function, FunctionId},
+        instruction::{Binary, BinaryOp, Instruction},
+        post_order::PostOrder,
+        types::{NumericType, Type},
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// Computes the [`ValueRanges`] of each function.
+    pub(crate) fn range_analysis(&self) -> BTreeMap<FunctionId, ValueRanges> {
+        self.functions.iter().map(|(id, function)| (*id, ValueRanges::new(function))).collect()
+    }
+}
+
+/// Upper bounds on the number of bits of the instruction results of a function.
+#[derive(Debug, Default)]
+pub(crate) struct ValueRanges {
+    max_num_bits: HashMap<ValueId, u32>,
+}
+
+impl ValueRanges {
+    /// Computes the bounds of `function`'s instruction results in a single forward pass.
+    ///
+    /// Blocks are visited in reverse post-order so that values are visited before their uses,
+    /// except for values flowing through loop back-edges which fall back to their type's size.
+    pub(crate) fn new(function: &Function) -> Self {
+        let mut ranges = Self::default();
+        let dfg = &function.dfg;
+
+        for block in PostOrder::with_function(function).into_vec().into_iter().rev() {
+            for instruction in dfg[block].instructions() {
+                let [result] = dfg.instruction_results(*instruction) else {
+                    continue;
+                };
+                if let Some(max_num_bits) = ranges.instruction_max_num_bits(dfg, &dfg[*instruction])
+                {
+                    let max_num_bits = max_num_bits.min(dfg.get_value_max_num_bits(*result));
+                    ranges.max_num_bits.insert(*result, max_num_bits);
+                }
+            }
+        }
+        ranges
+    }
+
+    /// Returns an upper bound on the number of bits of `value`.
+    ///
+    /// This is never larger than [`DataFlowGraph::get_value_max_num_bits`].
+    pub(crate) fn max_num_bits(&self, dfg: &DataFlowGraph, value: ValueId) -> u32 {
+        let value = dfg.resolve(value);
+        self.max_num_bits.get(&value).copied().unwrap_or_else(|| dfg.get_value_max_num_bits(value))
+    }
+
+    /// Returns a bound on the result of `instruction` tighter than its type, if one is known.
+    fn instruction_max_num_bits(
+        &self,
+        dfg: &DataFlowGraph,
+        instruction: &Instruction,
+    ) -> Option<u32> {
+        match instruction {
+            Instruction::Truncate { value, bit_size, .. } => {
+                Some(self.max_num_bits(dfg, *value).min(*bit_size))
+            }
+            Instruction::Cast(value, _) => Some(self.max_num_bits(dfg, *value)),
+            Instruction::Binary(Binary { lhs, rhs, operator }) => {
+                // Signed values are stored in two's complement, so negative values use all bits
+                // and fields do not wrap around at a power of two.
+                if !matches!(dfg.type_of_value(*lhs), Type::Numeric(NumericType::Unsigned { .. })) {
+                    return None;
+                }
+                let lhs = self.max_num_bits(dfg, *lhs);
+                let rhs = self.max_num_bits(dfg, *rhs);
+                match operator {
+                    // If the result would overflow, the bound is larger than the type's size
+                    // and the latter is used instead.
+                    BinaryOp::Add { .. } => Some(lhs.max(rhs) + 1),
+                    BinaryOp::Mul { .. } => Some(lhs + rhs),
+                    BinaryOp::Div | BinaryOp::Shr => Some(lhs),
+                    BinaryOp::Mod | BinaryOp::And => Some(lhs.min(rhs)),
+                    BinaryOp::Or | BinaryOp::Xor => Some(lhs.max(rhs)),
+                    BinaryOp::Sub { .. } | BinaryOp::Eq | BinaryOp::Lt | BinaryOp::Shl { .. } => {
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{ir::instruction::TerminatorInstruction, ssa_gen::Ssa};
+
+    #[test]
+    fn propagates_bounds_through_unsigned_arithmetic() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: i32):
+                v2 = truncate v0 to 8 bits, max_bit_size: 32
+                v4 = and v0, u32 15
+                v5 = add v2, v4
+                v6 = mul v4, v4
+                v7 = cast v5 as u64
+                v9 = or v0, u32 1
+                v10 = truncate v1 to 8 bits, max_bit_size: 32
+                v11 = add v10, v10
+                return v5, v6, v7, v9, v11
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ranges = ssa.range_analysis();
+        let ranges = &ranges[&ssa.main_id];
+        let main = ssa.main();
+
+        let Some(TerminatorInstruction::Return { return_values, .. }) =
+            main.dfg[main.entry_block()].terminator()
+        else {
+            panic!("Expected a return terminator");
+        };
+        let max_num_bits: Vec<_> =
+            return_values.iter().map(|value| ranges.max_num_bits(&main.dfg, *value)).collect();
+        // Or-ing with an unbounded value is unbounded and signed arithmetic is not bounded
+        assert_eq!(max_num_bits, vec![9, 8, 9, 32, 32]);
+    }
+}
@@ -33,6 +33,8 @@ impl Function {
             // array mutation was only meant for ACIR. We could use it with Brillig as well,
             // but then some of the optimizations that we can do in ACIR around shared
             // references have to be skipped, which makes it more cumbersome.
+            // The exception is an array which nothing else can share, see below.
+            self.make_single_use_arrays_mutable();
             return;
         }
 
@@ -53,6 +55,49 @@ impl Function {
             make_mutable(&mut self.dfg, block, &instructions_to_update);
         }
     }
+
+    /// Marks each `array_set` mutable when its array is created by a `make_array` earlier in
+    /// the same block and the `array_set` is the only use of that array.
+    ///
+    /// Such an array is freshly allocated every time the block runs and never shared,
+    /// so setting it in place saves copying it regardless of its reference count.
+    fn make_single_use_arrays_mutable(&mut self) {
+        let reachable_blocks = self.reachable_blocks();
+
+        let mut use_counts: HashMap<ValueId, usize> = HashMap::default();
+        for block in &reachable_blocks {
+            let mut count_use =
+                |value| *use_counts.entry(self.dfg.resolve(value)).or_default() += 1;
+            for instruction in self.dfg[*block].instructions() {
+                self.dfg[*instruction].for_each_value(&mut count_use);
+            }
+            self.dfg[*block].unwrap_terminator().for_each_value(&mut count_use);
+        }
+
+        let mut instructions_to_update = HashSet::default();
+        for block in &reachable_blocks {
+            let mut arrays_made_in_block = HashSet::default();
+            for instruction_id in self.dfg[*block].instructions() {
+                match &self.dfg[*instruction_id] {
+                    Instruction::MakeArray { .. } => {
+                        arrays_made_in_block
+                            .insert(self.dfg.instruction_results(*instruction_id)[0]);
+                    }
+                    Instruction::ArraySet { array, mutable: false, .. } => {
+                        let array = self.dfg.resolve(*array);
+                        if arrays_made_in_block.contains(&array) && use_counts[&array] == 1 {
+                            instructions_to_update.insert(*instruction_id);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        for block in reachable_blocks {
+            make_mutable(&mut self.dfg, block, &instructions_to_update);
+        }
+    }
 }
 
 struct Context<'f> {
@@ -232,4 +277,33 @@ mod tests {
         let ssa = ssa.array_set_optimization();
         assert_normalized_ssa_equals(ssa, src);
     }
+
+    #[test]
+    fn brillig_array_set_on_single_use_array_is_mutable() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field):
+                v3 = make_array [Field 1, Field 2] : [Field; 2]
+                v5 = array_set v3, index u32 0, value v0
+                v6 = make_array [Field 1, Field 2] : [Field; 2]
+                v7 = array_set v6, index u32 0, value v0
+                return v5, v6, v7
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // Only the first array is not used after being set
+        let expected = "
+            brillig(inline) fn main f0 {
+              b0(v0: Field):
+                v3 = make_array [Field 1, Field 2] : [Field; 2]
+                v5 = array_set mut v3, index u32 0, value v0
+                v6 = make_array [Field 1, Field 2] : [Field; 2]
+                v7 = array_set v6, index u32 0, value v0
+                return v5, v6, v7
+            }
+            ";
+        let ssa = ssa.array_set_optimization();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
 }
@@ -74,6 +74,7 @@ fn replace_known_slice_lengths(
         let known_length = func.dfg.make_constant(known_length.into(), NumericType::length_type());
         func.dfg.set_value_from_id(original_slice_length, known_length);
     });
+    func.dfg.replace_value_in_terminators();
 }
 
 #[cfg(test)]
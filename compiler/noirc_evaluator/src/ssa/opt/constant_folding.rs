@@ -19,7 +19,7 @@
 //!
 //! This is the only pass which removes duplicated pure [`Instruction`]s however and so is needed when
 //! different blocks are merged, i.e. after the [`flatten_cfg`][super::flatten_cfg] pass.
-use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, VecDeque};
 
 use acvm::{
     acir::AcirField,
@@ -49,7 +49,7 @@ use crate::{
         ssa_gen::Ssa,
     },
 };
-use fxhash::FxHashMap as HashMap;
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 impl Ssa {
     /// Performs constant folding on each instruction.
@@ -834,6 +834,7 @@ mod test {
 
     use noirc_frontend::monomorphization::ast::InlineType;
 
+    use crate::brillig::BrilligOptions;
     use crate::ssa::{
         function_builder::FunctionBuilder,
         ir::{
@@ -1320,7 +1321,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
@@ -1348,7 +1349,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
@@ -1376,7 +1377,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
@@ -1404,7 +1405,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
@@ -1433,7 +1434,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
@@ -1467,7 +1468,7 @@ mod test {
             }
             ";
         let ssa = Ssa::from_str(src).unwrap();
-        let brillig = ssa.to_brillig(false);
+        let brillig = ssa.to_brillig(&BrilligOptions::default());
 
         let expected = "
             acir(inline) fn main f0 {
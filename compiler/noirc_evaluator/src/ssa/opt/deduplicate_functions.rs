@@ -0,0 +1,232 @@
+//! Deduplication of structurally identical functions.
+//!
+//! After monomorphization, distinct generic instantiations may lower to exactly the same SSA,
+//! for example a function generic over a type which is only used in an unused parameter.
+//! This pass finds such functions and keeps a single canonical copy of each, redirecting all
+//! references to the others towards it.
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use fxhash::FxHashMap as HashMap;
+
+use crate::ssa::{
+    ir::{
+        dfg::DataFlowGraph,
+        function::{Function, FunctionId},
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// Removes functions which are structurally identical to another function, redirecting
+    /// every call and function reference to a single canonical function per group.
+    ///
+    /// Two functions are considered identical when they share a runtime, their bodies
+    /// print the same once value and block IDs have been normalized, and each of their
+    /// instructions comes from the same source location.
+    /// Since merging callees may make their callers identical in turn, this is repeated
+    /// until no more duplicates are found. Entry points are never removed.
+    pub(crate) fn deduplicate_functions(mut self) -> Self {
+        let mut keys: BTreeMap<FunctionId, String> = self
+            .functions
+            .iter()
+            .filter(|(id, _)| !self.is_entry_point(**id))
+            .map(|(id, function)| (*id, structural_key(function, &self.globals.dfg)))
+            .collect();
+
+        loop {
+            let replacements = find_duplicate_functions(&keys);
+            if replacements.is_empty() {
+                return self;
+            }
+
+            self.functions.retain(|id, _| !replacements.contains_key(id));
+            keys.retain(|id, _| !replacements.contains_key(id));
+
+            // Only the keys of functions referring to a removed duplicate can change
+            for function in self.functions.values_mut() {
+                if redirect_function_references(function, &replacements) {
+                    if let Some(key) = keys.get_mut(&function.id()) {
+                        *key = structural_key(function, &self.globals.dfg);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns a map from each duplicate function to the canonical function replacing it.
+fn find_duplicate_functions(
+    keys: &BTreeMap<FunctionId, String>,
+) -> HashMap<FunctionId, FunctionId> {
+    let mut canonical_functions: BTreeMap<&String, FunctionId> = BTreeMap::new();
+    let mut replacements = HashMap::default();
+
+    for (id, key) in keys {
+        match canonical_functions.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(*id);
+            }
+            Entry::Occupied(entry) => {
+                replacements.insert(*id, *entry.get());
+            }
+        }
+    }
+
+    replacements
+}
+
+/// The printed form of a function without its name and ID, followed by the source locations
+/// of its instructions.
+///
+/// Locations are part of the key since merging functions lowered from different source code
+/// would attribute assertion failures and debug information to the wrong location.
+fn structural_key(function: &Function, globals: &DataFlowGraph) -> String {
+    let function = function.normalized(globals);
+    let printed = function.to_string();
+    let body = printed.split_once('\n').map_or("", |(_, body)| body);
+    let mut key = format!("{}\n{body}", function.runtime());
+
+    let dfg = &function.dfg;
+    for block in function.reachable_blocks() {
+        for instruction in dfg[block].instructions() {
+            let locations = dfg.get_instruction_call_stack(*instruction);
+            key.push_str(&format!("{locations:?}\n"));
+        }
+        let locations = dfg.get_call_stack(dfg[block].unwrap_terminator().call_stack());
+        key.push_str(&format!("{locations:?}\n"));
+    }
+    key
+}
+
+/// Replaces every reference to a duplicate function in `function` with its canonical function.
+/// Returns whether any reference was replaced.
+fn redirect_function_references(
+    function: &mut Function,
+    replacements: &HashMap<FunctionId, FunctionId>,
+) -> bool {
+    let mut new_values: HashMap<ValueId, ValueId> = HashMap::default();
+    let values = function.dfg.values_iter().map(|(id, value)| (id, value.clone()));
+    for (value_id, value) in values.collect::<Vec<_>>() {
+        if let Value::Function(id) = value {
+            if let Some(canonical) = replacements.get(&id) {
+                new_values.insert(value_id, function.dfg.import_function(*canonical));
+            }
+        }
+    }
+    if new_values.is_empty() {
+        return false;
+    }
+
+    let map_value = |function: &Function, value: ValueId| {
+        let value = function.dfg.resolve(value);
+        new_values.get(&value).copied().unwrap_or(value)
+    };
+
+    for block_id in function.reachable_blocks() {
+        for instruction_id in function.dfg[block_id].instructions().to_vec() {
+            let instruction =
+                function.dfg[instruction_id].map_values(|value| map_value(function, value));
+            function.dfg[instruction_id] = instruction;
+        }
+
+        let mut terminator = function.dfg[block_id].unwrap_terminator().clone();
+        terminator.map_values_mut(|value| map_value(function, value));
+        function.dfg[block_id].set_terminator(terminator);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_errors::{Location, Span};
+    use noirc_frontend::monomorphization::ast::InlineType;
+
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            instruction::{BinaryOp, Instruction},
+            map::Id,
+            types::Type,
+            value::Value,
+        },
+        ssa_gen::Ssa,
+    };
+
+    #[test]
+    fn deduplicates_identical_functions() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                v3 = call f2(v2) -> Field
+                return v3
+            }
+            acir(inline) fn foo f1 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            acir(inline) fn foo f2 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            brillig(inline) fn foo f3 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.deduplicate_functions();
+
+        // The brillig function differs in its runtime so it is kept
+        assert_eq!(ssa.functions.len(), 3);
+        assert!(ssa.functions.contains_key(&Id::test_new(1)));
+        assert!(!ssa.functions.contains_key(&Id::test_new(2)));
+
+        let main = ssa.main();
+        let instructions = main.dfg[main.entry_block()].instructions();
+        assert_eq!(instructions.len(), 2);
+        for instruction in instructions {
+            let Instruction::Call { func, .. } = &main.dfg[*instruction] else {
+                panic!("Expected a call instruction");
+            };
+            assert_eq!(main.dfg[main.dfg.resolve(*func)], Value::Function(Id::test_new(1)));
+        }
+    }
+
+    #[test]
+    fn keeps_identical_functions_from_different_locations() {
+        // fn main f0 {
+        //   b0(v0: Field):
+        //     v2 = call f1(v0) -> Field
+        //     v3 = call f2(v2) -> Field
+        //     return v3
+        // }
+        // f1 and f2 both add one to their parameter, but at different source locations
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let f1 = builder.import_function(Id::test_new(1));
+        let f2 = builder.import_function(Id::test_new(2));
+        let v2 = builder.insert_call(f1, vec![v0], vec![Type::field()])[0];
+        let v3 = builder.insert_call(f2, vec![v2], vec![Type::field()])[0];
+        builder.terminate_with_return(vec![v3]);
+
+        for (id, start) in [(1, 10), (2, 20)] {
+            builder.new_function("foo".into(), Id::test_new(id), InlineType::default());
+            let location = Location::new(Span::single_char(start), Default::default());
+            builder.set_location(location);
+            let v0 = builder.add_parameter(Type::field());
+            let one = builder.field_constant(1u128);
+            let v2 = builder.insert_binary(v0, BinaryOp::Add { unchecked: false }, one);
+            builder.terminate_with_return(vec![v2]);
+        }
+
+        let ssa = builder.finish().deduplicate_functions();
+        assert_eq!(ssa.functions.len(), 3);
+    }
+}
@@ -813,16 +813,27 @@ impl<'f> Context<'f> {
 
 #[cfg(test)]
 mod test {
+    use std::sync::Arc;
+
     use acvm::acir::AcirField;
 
-    use crate::ssa::{
-        ir::{
-            dfg::DataFlowGraph,
-            instruction::{Instruction, TerminatorInstruction},
-            value::{Value, ValueId},
+    use crate::{
+        errors::RuntimeError,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                dfg::DataFlowGraph,
+                instruction::{Instruction, TerminatorInstruction},
+                map::Id,
+                types::Type,
+                value::{Value, ValueId},
+            },
+            opt::{
+                assert_normalized_ssa_equals,
+                flatten_cfg::value_merger::DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+            },
+            Ssa,
         },
-        opt::assert_normalized_ssa_equals,
-        Ssa,
     };
 
     #[test]
@@ -881,7 +892,7 @@ mod test {
             acir(inline) fn main f0 {
               b0(v0: u1, v1: u1):
                 enable_side_effects v0
-                v2 = mul v1, v0
+                v2 = mul v0, v1
                 constrain v2 == v0
                 v3 = not v0
                 enable_side_effects u1 1
@@ -917,7 +928,7 @@ mod test {
                 v4 = cast v0 as Field
                 v5 = cast v3 as Field
                 v7 = mul v4, Field 5
-                v8 = mul v5, v2
+                v8 = mul v2, v5
                 v9 = add v7, v8
                 store v9 at v1
                 enable_side_effects u1 1
@@ -955,7 +966,7 @@ mod test {
                 v4 = cast v0 as Field
                 v5 = cast v3 as Field
                 v7 = mul v4, Field 5
-                v8 = mul v5, v2
+                v8 = mul v2, v5
                 v9 = add v7, v8
                 store v9 at v1
                 enable_side_effects v3
@@ -963,7 +974,7 @@ mod test {
                 v11 = cast v3 as Field
                 v12 = cast v0 as Field
                 v14 = mul v11, Field 6
-                v15 = mul v12, v10
+                v15 = mul v10, v12
                 v16 = add v14, v15
                 store v16 at v1
                 enable_side_effects u1 1
@@ -1067,14 +1078,14 @@ mod test {
             v4 = cast v0 as Field
             v5 = cast v3 as Field
             v7 = mul v4, Field 2
-            v8 = add v7, v5
+            v8 = add v5, v7
             v9 = mul v0, v1
             enable_side_effects v9
             v10 = not v9
             v11 = cast v9 as Field
             v12 = cast v10 as Field
             v14 = mul v11, Field 5
-            v15 = mul v12, v8
+            v15 = mul v8, v12
             v16 = add v14, v15
             v17 = not v1
             v18 = mul v0, v17
@@ -1083,14 +1094,14 @@ mod test {
             v20 = cast v18 as Field
             v21 = cast v19 as Field
             v23 = mul v20, Field 6
-            v24 = mul v21, v16
+            v24 = mul v16, v21
             v25 = add v23, v24
             enable_side_effects v0
             enable_side_effects v3
             v26 = cast v3 as Field
             v27 = cast v0 as Field
             v29 = mul v26, Field 3
-            v30 = mul v27, v25
+            v30 = mul v25, v27
             v31 = add v29, v30
             enable_side_effects u1 1
             return v31
@@ -1281,17 +1292,17 @@ mod test {
             v10 = cast v9 as u8
             v11 = load v6 -> u8
             v12 = not v5
-            v13 = cast v4 as u8
+            v13 = cast v5 as u8
             v14 = cast v12 as u8
-            v15 = unchecked_mul v13, v10
-            v16 = unchecked_mul v14, v11
+            v15 = unchecked_mul v10, v13
+            v16 = unchecked_mul v11, v14
             v17 = unchecked_add v15, v16
             store v17 at v6
             enable_side_effects v12
             v18 = load v6 -> u8
             v19 = cast v12 as u8
-            v20 = cast v4 as u8
-            v21 = unchecked_mul v20, v18
+            v20 = cast v5 as u8
+            v21 = unchecked_mul v18, v20
             store v21 at v6
             enable_side_effects u1 1
             constrain v5 == u1 1
@@ -1447,7 +1458,7 @@ mod test {
             v3 = cast v0 as Field
             v4 = cast v2 as Field
             v6 = mul v3, Field 2
-            v7 = mul v4, v3
+            v7 = mul v3, v4
             v8 = add v6, v7
             enable_side_effects u1 1
             return v8
@@ -1482,7 +1493,8 @@ mod test {
         let ssa = ssa
             .flatten_cfg()
             .mem2reg()
-            .remove_if_else()
+            .remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK)
+            .unwrap()
             .fold_constants()
             .dead_instruction_elimination();
 
@@ -1500,4 +1512,217 @@ mod test {
 
         assert_normalized_ssa_equals(ssa, expected);
     }
+
+    #[test]
+    fn does_not_merge_identical_arrays() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u1):
+            jmpif v0 then: b1, else: b2
+          b1():
+            v1 = make_array [Field 1, Field 2] : [Field; 2]
+            jmp b3(v1)
+          b2():
+            v2 = make_array [Field 1, Field 2] : [Field; 2]
+            jmp b3(v2)
+          b3(v3: [Field; 2]):
+            return v3
+        }";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.flatten_cfg().remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK).unwrap();
+
+        // The two arrays are equal so no `array_get`s or arithmetic should be emitted to merge them.
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u1):
+            enable_side_effects v0
+            v3 = make_array [Field 1, Field 2] : [Field; 2]
+            v4 = not v0
+            enable_side_effects v4
+            v5 = make_array [Field 1, Field 2] : [Field; 2]
+            enable_side_effects u1 1
+            return v3
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn errors_when_merging_arrays_of_different_lengths() {
+        // acir(inline) fn main f0 {
+        //   b0(v0: u1, v1: [Field; 2], v2: [Field; 3]):
+        //     v3 = not v0
+        //     v4 = if v0 then v1 else (if v3) v2
+        //     return v4
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::bool());
+        let v1 = builder.add_parameter(Type::Array(Arc::new(vec![Type::field()]), 2));
+        let v2 = builder.add_parameter(Type::Array(Arc::new(vec![Type::field()]), 3));
+        let v3 = builder.insert_not(v0);
+        let if_else = Instruction::IfElse {
+            then_condition: v0,
+            then_value: v1,
+            else_condition: v3,
+            else_value: v2,
+        };
+        let v4 = builder.insert_instruction(if_else, None).first();
+        builder.terminate_with_return(vec![v4]);
+        let ssa = builder.finish();
+
+        let Err(error) = ssa.remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK) else {
+            panic!("Expected merging arrays of different lengths to fail");
+        };
+        assert!(matches!(
+            error,
+            RuntimeError::MismatchedArrayLengths { then_length: 2, else_length: 3, .. }
+        ));
+    }
+
+    #[test]
+    fn errors_when_merging_references() {
+        // fn main(c: bool) -> pub Field {
+        //     let mut a = 1;
+        //     let mut b = 2;
+        //     let r = if c { &mut a } else { &mut b };
+        //     *r = 3;
+        //     a + b
+        // }
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u1):
+                v1 = allocate -> &mut Field
+                store Field 1 at v1
+                v3 = allocate -> &mut Field
+                store Field 2 at v3
+                jmpif v0 then: b1, else: b2
+              b1():
+                jmp b3(v1)
+              b2():
+                jmp b3(v3)
+              b3(v4: &mut Field):
+                store Field 3 at v4
+                v6 = load v1 -> Field
+                v7 = load v3 -> Field
+                v8 = add v6, v7
+                return v8
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let Err(error) = ssa.flatten_cfg().remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK) else {
+            panic!("Expected merging references to fail");
+        };
+        assert!(matches!(error, RuntimeError::ReferenceInIfExpression { .. }));
+    }
+
+    #[test]
+    fn merges_identical_arrays_of_references() {
+        // acir(inline) fn main f0 {
+        //   b0(v0: u1):
+        //     v1 = allocate -> &mut Field
+        //     v2 = make_array [v1] : [&mut Field; 1]
+        //     v3 = make_array [v1] : [&mut Field; 1]
+        //     v4 = not v0
+        //     v5 = if v0 then v2 else (if v4) v3
+        //     return v5
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::bool());
+        let v1 = builder.insert_allocate(Type::field());
+        let array_type = Type::Array(Arc::new(vec![Type::Reference(Arc::new(Type::field()))]), 1);
+        let v2 = builder.insert_make_array(im::vector![v1], array_type.clone());
+        let v3 = builder.insert_make_array(im::vector![v1], array_type);
+        let v4 = builder.insert_not(v0);
+        let if_else = Instruction::IfElse {
+            then_condition: v0,
+            then_value: v2,
+            else_condition: v4,
+            else_value: v3,
+        };
+        let v5 = builder.insert_instruction(if_else, None).first();
+        builder.terminate_with_return(vec![v5]);
+        let ssa = builder.finish();
+
+        // Both arrays hold the same reference, so there is nothing to merge
+        let ssa = ssa.remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK).unwrap();
+        let main = ssa.main();
+        let instructions = main.dfg[main.entry_block()].instructions();
+        assert!(!instructions.iter().any(|id| matches!(main.dfg[*id], Instruction::IfElse { .. })));
+    }
+
+    #[test]
+    fn merges_slices_of_slices_of_different_lengths() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u1):
+                v3 = make_array [Field 1, Field 2] : [Field]
+                v4 = make_array [v3] : [[Field]]
+                v5 = make_array [v3, v3] : [[Field]]
+                jmpif v0 then: b1, else: b2
+              b1():
+                jmp b3(v4)
+              b2():
+                jmp b3(v5)
+              b3(v6: [[Field]]):
+                return v6
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The second item of the merged slice only exists in the else branch,
+        // so the then branch is padded with an empty dummy slice.
+        let ssa = ssa.flatten_cfg().remove_if_else(DEFAULT_MAX_ARRAY_SET_LOOKBACK).unwrap();
+        let main = ssa.main();
+        let return_value = match main.dfg[main.entry_block()].terminator() {
+            Some(TerminatorInstruction::Return { return_values, .. }) => return_values[0],
+            _ => unreachable!("Should have terminator instruction"),
+        };
+        let (items, _) = main.dfg.get_array_constant(return_value).unwrap();
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn merges_only_indices_changed_by_chained_array_sets() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: [Field; 32], v1: u1):
+                jmpif v1 then: b1, else: b2
+              b1():
+                v2 = array_set v0, index u32 0, value Field 1
+                v3 = array_set v2, index u32 1, value Field 1
+                v4 = array_set v3, index u32 2, value Field 1
+                v5 = array_set v4, index u32 3, value Field 1
+                v6 = array_set v5, index u32 4, value Field 1
+                jmp b3(v6)
+              b2():
+                jmp b3(v0)
+              b3(v7: [Field; 32]):
+                return v7
+            }
+            ";
+        let merge = |max_array_set_lookback| {
+            let ssa = Ssa::from_str(src).unwrap();
+            let ssa = ssa.flatten_cfg().remove_if_else(max_array_set_lookback).unwrap();
+            let main = ssa.main();
+            let instructions = main.dfg[main.entry_block()].instructions();
+            let count = |is_kind: fn(&Instruction) -> bool| {
+                instructions.iter().filter(|instruction| is_kind(&main.dfg[**instruction])).count()
+            };
+            let array_sets =
+                count(|instruction| matches!(instruction, Instruction::ArraySet { .. }));
+            let make_arrays =
+                count(|instruction| matches!(instruction, Instruction::MakeArray { .. }));
+            (array_sets, make_arrays)
+        };
+
+        // Without looking back far enough, a new array is made by merging every element
+        assert_eq!(merge(DEFAULT_MAX_ARRAY_SET_LOOKBACK), (5, 1));
+        // The 5 changed indices are set again into the then array, under their own conditions
+        assert_eq!(merge(10), (10, 0));
+    }
 }
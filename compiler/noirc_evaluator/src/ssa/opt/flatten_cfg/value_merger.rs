@@ -1,15 +1,38 @@
 use acvm::{acir::AcirField, FieldElement};
 use fxhash::{FxHashMap as HashMap, FxHashSet};
 
+use crate::errors::RuntimeError;
 use crate::ssa::ir::{
     basic_block::BasicBlockId,
     call_stack::CallStackId,
     dfg::{DataFlowGraph, InsertInstructionResult},
     instruction::{BinaryOp, Instruction},
+    printer::value_with_type,
     types::{NumericType, Type},
     value::{Value, ValueId},
 };
 
+/// How many `array_set` instructions are followed back from each merged array by default when
+/// looking for a common ancestor, so that only the indices which were changed are merged.
+/// Arrays changed by more `array_set`s than this are merged element by element.
+pub const DEFAULT_MAX_ARRAY_SET_LOOKBACK: usize = 2;
+
+/// Returns the flattened lengths of two array types if they differ.
+///
+/// Merging two arrays reads each index of the first one from both, so arrays of different
+/// lengths cannot be merged. Slices have no static length and are never reported.
+fn mismatched_array_lengths(then_type: &Type, else_type: &Type) -> Option<(u32, u32)> {
+    if !matches!(then_type, Type::Array(..)) || !matches!(else_type, Type::Array(..)) {
+        return None;
+    }
+    if then_type.contains_slice_element() || else_type.contains_slice_element() {
+        return None;
+    }
+    let then_length = then_type.flattened_size();
+    let else_length = else_type.flattened_size();
+    (then_length != else_length).then_some((then_length, else_length))
+}
+
 pub(crate) struct ValueMerger<'a> {
     dfg: &'a mut DataFlowGraph,
     block: BasicBlockId,
@@ -23,6 +46,10 @@ pub(crate) struct ValueMerger<'a> {
     array_set_conditionals: &'a mut HashMap<ValueId, ValueId>,
 
     call_stack: CallStackId,
+
+    // How many `array_set` instructions to follow back from each merged array
+    // when looking for a common ancestor.
+    max_array_set_lookback: usize,
 }
 
 impl<'a> ValueMerger<'a> {
@@ -33,6 +60,7 @@ impl<'a> ValueMerger<'a> {
         array_set_conditionals: &'a mut HashMap<ValueId, ValueId>,
         current_condition: Option<ValueId>,
         call_stack: CallStackId,
+        max_array_set_lookback: usize,
     ) -> Self {
         ValueMerger {
             dfg,
@@ -41,6 +69,7 @@ impl<'a> ValueMerger<'a> {
             array_set_conditionals,
             current_condition,
             call_stack,
+            max_array_set_lookback,
         }
     }
 
@@ -50,42 +79,72 @@ impl<'a> ValueMerger<'a> {
     /// Otherwise, if the values being merged are arrays, a new array will be made
     /// recursively from combining each element of both input arrays.
     ///
-    /// It is currently an error to call this function on reference or function values
-    /// as it is less clear how to merge these.
+    /// Merging two different values which are or contain references returns an error, as it is
+    /// less clear how to merge these. It is currently an error to call this function on function
+    /// values for the same reason.
     pub(crate) fn merge_values(
         &mut self,
         then_condition: ValueId,
         else_condition: ValueId,
         then_value: ValueId,
         else_value: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         let then_value = self.dfg.resolve(then_value);
         let else_value = self.dfg.resolve(else_value);
 
-        if then_value == else_value {
-            return then_value;
+        if then_value == else_value || self.is_same_array(then_value, else_value) {
+            return Ok(then_value);
         }
 
-        match self.dfg.type_of_value(then_value) {
-            Type::Numeric(_) => Self::merge_numeric_values(
+        let typ = self.dfg.type_of_value(then_value);
+        if typ.contains_reference() {
+            // Merging would need to select which reference later loads and stores go
+            // through, but references must all be resolved by now
+            let call_stack = self.dfg.get_call_stack(self.call_stack);
+            return Err(RuntimeError::ReferenceInIfExpression { call_stack });
+        }
+
+        match typ {
+            Type::Numeric(_) => Ok(Self::merge_numeric_values(
                 self.dfg,
                 self.block,
                 then_condition,
                 else_condition,
                 then_value,
                 else_value,
-            ),
+            )),
             typ @ Type::Array(_, _) => {
                 self.merge_array_values(typ, then_condition, else_condition, then_value, else_value)
             }
             typ @ Type::Slice(_) => {
                 self.merge_slice_values(typ, then_condition, else_condition, then_value, else_value)
             }
-            Type::Reference(_) => panic!("Cannot return references from an if expression"),
-            Type::Function => panic!("Cannot return functions from an if expression"),
+            Type::Reference(_) => unreachable!("ICE: Merging references is unsupported"),
+            Type::Function => panic!(
+                "Cannot return functions from an if expression: {}",
+                value_with_type(self.dfg, then_value)
+            ),
         }
     }
 
+    /// Returns true if both values are arrays created by `make_array` instructions with the same
+    /// type and the same (recursively) elements, even though the array values themselves differ.
+    fn is_same_array(&self, then_value: ValueId, else_value: ValueId) -> bool {
+        let (Some((then_elements, then_type)), Some((else_elements, else_type))) =
+            (self.dfg.get_array_constant(then_value), self.dfg.get_array_constant(else_value))
+        else {
+            return false;
+        };
+
+        then_type == else_type
+            && then_elements.len() == else_elements.len()
+            && then_elements.iter().zip(else_elements.iter()).all(|(then_element, else_element)| {
+                let then_element = self.dfg.resolve(*then_element);
+                let else_element = self.dfg.resolve(*else_element);
+                then_element == else_element || self.is_same_array(then_element, else_element)
+            })
+    }
+
     /// Merge two numeric values a and b from separate basic blocks to a single value. This
     /// function would return the result of `if c { a } else { b }` as  `c*a + (!c)*b`.
     pub(crate) fn merge_numeric_values(
@@ -146,7 +205,7 @@ impl<'a> ValueMerger<'a> {
         else_condition: ValueId,
         then_value: ValueId,
         else_value: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         let mut merged = im::Vector::new();
 
         let (element_types, len) = match &typ {
@@ -156,14 +215,24 @@ impl<'a> ValueMerger<'a> {
 
         let actual_length = len * element_types.len() as u32;
 
+        let else_type = self.dfg.type_of_value(else_value);
+        if let Some((then_length, else_length)) = mismatched_array_lengths(&typ, &else_type) {
+            let call_stack = self.dfg.get_call_stack(self.call_stack);
+            return Err(RuntimeError::MismatchedArrayLengths {
+                then_length,
+                else_length,
+                call_stack,
+            });
+        }
+
         if let Some(result) = self.try_merge_only_changed_indices(
             then_condition,
             else_condition,
             then_value,
             else_value,
             actual_length,
-        ) {
-            return result;
+        )? {
+            return Ok(result);
         }
 
         for i in 0..len {
@@ -189,14 +258,16 @@ impl<'a> ValueMerger<'a> {
                     else_condition,
                     then_element,
                     else_element,
-                ));
+                )?);
             }
         }
 
         let instruction = Instruction::MakeArray { elements: merged, typ };
-        self.dfg
+        let result = self
+            .dfg
             .insert_instruction_and_results(instruction, self.block, None, self.call_stack)
-            .first()
+            .first();
+        Ok(result)
     }
 
     fn merge_slice_values(
@@ -206,7 +277,7 @@ impl<'a> ValueMerger<'a> {
         else_condition: ValueId,
         then_value_id: ValueId,
         else_value_id: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         let mut merged = im::Vector::new();
 
         let element_types = match &typ {
@@ -214,19 +285,21 @@ impl<'a> ValueMerger<'a> {
             _ => panic!("Expected slice type"),
         };
 
-        let then_len = self.slice_sizes.get(&then_value_id).copied().unwrap_or_else(|| {
-            let (slice, typ) = self.dfg.get_array_constant(then_value_id).unwrap_or_else(|| {
-                panic!("ICE: Merging values during flattening encountered slice {then_value_id} without a preset size");
+        let then_len =
+            self.dfg.array_length_of(then_value_id, self.slice_sizes).unwrap_or_else(|| {
+                panic!(
+                    "ICE: Merging values during flattening encountered slice {} without a preset size",
+                    value_with_type(self.dfg, then_value_id)
+                );
             });
-            (slice.len() / typ.element_types().len()) as u32
-        });
 
-        let else_len = self.slice_sizes.get(&else_value_id).copied().unwrap_or_else(|| {
-            let (slice, typ) = self.dfg.get_array_constant(else_value_id).unwrap_or_else(|| {
-                panic!("ICE: Merging values during flattening encountered slice {else_value_id} without a preset size");
+        let else_len =
+            self.dfg.array_length_of(else_value_id, self.slice_sizes).unwrap_or_else(|| {
+                panic!(
+                    "ICE: Merging values during flattening encountered slice {} without a preset size",
+                    value_with_type(self.dfg, else_value_id)
+                );
             });
-            (slice.len() / typ.element_types().len()) as u32
-        });
 
         let len = then_len.max(else_len);
 
@@ -269,13 +342,17 @@ impl<'a> ValueMerger<'a> {
                     else_condition,
                     then_element,
                     else_element,
-                ));
+                )?);
             }
         }
 
         let instruction = Instruction::MakeArray { elements: merged, typ };
         let call_stack = self.call_stack;
-        self.dfg.insert_instruction_and_results(instruction, self.block, None, call_stack).first()
+        let result = self
+            .dfg
+            .insert_instruction_and_results(instruction, self.block, None, call_stack)
+            .first();
+        Ok(result)
     }
 
     /// Construct a dummy value to be attached to the smaller of two slices being merged.
@@ -302,9 +379,17 @@ impl<'a> ValueMerger<'a> {
                     .first()
             }
             Type::Slice(_) => {
-                // TODO(#3188): Need to update flattening to use true user facing length of slices
-                // to accurately construct dummy data
-                unreachable!("ICE: Cannot return a slice of slices from an if expression")
+                // The dummy data is never read, so an empty slice is enough. Its size is tracked
+                // so that it can itself be merged with the nested slice of the other branch.
+                let instruction =
+                    Instruction::MakeArray { elements: im::Vector::new(), typ: typ.clone() };
+                let call_stack = self.call_stack;
+                let slice = self
+                    .dfg
+                    .insert_instruction_and_results(instruction, self.block, None, call_stack)
+                    .first();
+                self.slice_sizes.insert(slice, 0);
+                slice
             }
             Type::Reference(_) => {
                 unreachable!("ICE: Merging references is unsupported")
@@ -322,16 +407,18 @@ impl<'a> ValueMerger<'a> {
         then_value: ValueId,
         else_value: ValueId,
         array_length: u32,
-    ) -> Option<ValueId> {
+    ) -> Result<Option<ValueId>, RuntimeError> {
         let mut found = false;
-        let current_condition = self.current_condition?;
+        let Some(current_condition) = self.current_condition else {
+            return Ok(None);
+        };
 
         let mut current_then = then_value;
         let mut current_else = else_value;
 
-        // Arbitrarily limit this to looking at most 10 past ArraySet operations.
+        // Limit this to looking at most `max_array_set_lookback` past ArraySet operations.
         // If there are more than that, we assume 2 completely separate arrays are being merged.
-        let max_iters = 2;
+        let max_iters = self.max_array_set_lookback;
         let mut seen_then = Vec::with_capacity(max_iters);
         let mut seen_else = Vec::with_capacity(max_iters);
 
@@ -376,7 +463,7 @@ impl<'a> ValueMerger<'a> {
             .collect();
 
         if !found || changed_indices.len() as u32 >= array_length {
-            return None;
+            return Ok(None);
         }
 
         let mut array = then_value;
@@ -398,14 +485,14 @@ impl<'a> ValueMerger<'a> {
             let else_element = get_element(else_value, typevars);
 
             let value =
-                self.merge_values(then_condition, else_condition, then_element, else_element);
+                self.merge_values(then_condition, else_condition, then_element, else_element)?;
 
             array = self.insert_array_set(array, index, value, Some(condition)).first();
         }
 
         let instruction = Instruction::EnableSideEffectsIf { condition: current_condition };
         self.insert_instruction(instruction);
-        Some(array)
+        Ok(Some(array))
     }
 
     fn insert_instruction(&mut self, instruction: Instruction) -> InsertInstructionResult {
@@ -450,7 +537,8 @@ impl<'a> ValueMerger<'a> {
                     let condition =
                         *self.array_set_conditionals.get(&result).unwrap_or_else(|| {
                             panic!(
-                                "Expected to have conditional for array set {result}\n{:?}",
+                                "Expected to have conditional for array set {}\n{:?}",
+                                value_with_type(self.dfg, result),
                                 self.array_set_conditionals
                             )
                         });
@@ -464,3 +552,53 @@ impl<'a> ValueMerger<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use fxhash::FxHashMap as HashMap;
+
+    use super::{ValueMerger, DEFAULT_MAX_ARRAY_SET_LOOKBACK};
+    use crate::{
+        errors::RuntimeError,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{call_stack::CallStackId, map::Id, types::Type},
+        },
+    };
+
+    #[test]
+    fn errors_when_merging_arrays_of_different_lengths() {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::bool());
+        let v1 = builder.add_parameter(Type::Array(Arc::new(vec![Type::field()]), 2));
+        let v2 = builder.add_parameter(Type::Array(Arc::new(vec![Type::field()]), 3));
+        let v3 = builder.insert_not(v0);
+        let block = builder.current_block();
+        builder.terminate_with_return(vec![]);
+        let mut ssa = builder.finish();
+        let main = ssa.main_mut();
+
+        let mut slice_sizes = HashMap::default();
+        let mut array_set_conditionals = HashMap::default();
+        let mut value_merger = ValueMerger::new(
+            &mut main.dfg,
+            block,
+            &mut slice_sizes,
+            &mut array_set_conditionals,
+            None,
+            CallStackId::root(),
+            DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+        );
+
+        let Err(error) = value_merger.merge_values(v0, v3, v1, v2) else {
+            panic!("Expected merging arrays of different lengths to fail");
+        };
+        assert!(matches!(
+            error,
+            RuntimeError::MismatchedArrayLengths { then_length: 2, else_length: 3, .. }
+        ));
+    }
+}
@@ -3,10 +3,11 @@ mod tests {
     use acvm::acir::circuit::ExpressionWidth;
 
     use crate::{
+        brillig::{RegisterReuseStrategy, DEFAULT_ARRAY_INIT_LOOP_THRESHOLD},
         errors::RuntimeError,
         ssa::{
             opt::assert_normalized_ssa_equals, optimize_all, Ssa, SsaBuilder, SsaEvaluatorOptions,
-            SsaLogging,
+            SsaLogging, DEFAULT_MAX_ARRAY_SET_LOOKBACK,
         },
     };
 
@@ -17,16 +18,38 @@ mod tests {
             print_codegen_timings: false,
             expression_width: ExpressionWidth::default(),
             emit_ssa: None,
+            emit_cfg: None,
+            emit_witness_graph: None,
+            emit_acir_json: None,
+            artifact_dir: None,
+            annotate_acir_json: false,
             skip_underconstrained_check: true,
             skip_brillig_constraints_check: true,
             inliner_aggressiveness: 0,
             max_bytecode_increase_percent: None,
+            ssa_pass_order: None,
+            disabled_passes: Vec::new(),
+            report_unconstrained_functions: false,
+            force_brillig: false,
+            brillig_register_reuse: RegisterReuseStrategy::default(),
+            brillig_array_init_loop_threshold: DEFAULT_ARRAY_INIT_LOOP_THRESHOLD,
+            max_array_set_lookback: DEFAULT_MAX_ARRAY_SET_LOOKBACK,
+            brillig_procedure_inline_threshold: 0,
+            warn_on_print: false,
+            pass_progress: None,
+            convert_panics_to_errors: false,
+            verify_no_references_after_mem2reg: false,
+            show_brillig_disassembly: false,
+            show_constraint_counts: false,
+            show_largest_constant_arrays: None,
+            max_brillig_block_size: None,
         };
 
         let builder = SsaBuilder {
             ssa,
             ssa_logging: options.ssa_logging.clone(),
             print_codegen_timings: false,
+            disabled_passes: Vec::new(),
         };
 
         optimize_all(builder, options)
@@ -89,9 +112,9 @@ mod tests {
               constrain u32 50 == v0
               v4 = call black_box(u32 10) -> u32
               v5 = add v4, v4
-              v6 = add v5, v4
-              v7 = add v6, v4
-              v8 = add v7, v4
+              v6 = add v4, v5
+              v7 = add v4, v6
+              v8 = add v4, v7
               constrain v8 == u32 50
               return
           }
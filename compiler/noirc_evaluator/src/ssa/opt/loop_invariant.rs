@@ -441,7 +441,7 @@ mod test {
         brillig(inline) fn main f0 {
           b0(v0: i32, v1: i32):
             v3 = mul v0, v1
-            v4 = mul v3, v0
+            v4 = mul v0, v3
             v6 = eq v4, i32 12
             jmp b1(i32 0)
           b1(v2: i32):
@@ -575,7 +575,7 @@ mod test {
             return
           b3():
             v10 = array_get v6, index v2 -> u32
-            v11 = eq v10, v0
+            v11 = eq v0, v10
             jmp b4(u32 0)
           b4(v3: u32):
             v12 = lt v3, u32 4
@@ -585,7 +585,7 @@ mod test {
             jmp b1(v14)
           b6():
             v15 = array_get v6, index v3 -> u32
-            v16 = eq v15, v0
+            v16 = eq v0, v15
             jmp b7(u32 0)
           b7(v4: u32):
             v17 = lt v4, u32 4
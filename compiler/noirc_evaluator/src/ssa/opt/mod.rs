@@ -8,6 +8,7 @@ mod array_set;
 mod as_slice_length;
 mod assert_constant;
 mod constant_folding;
+mod deduplicate_functions;
 mod defunctionalize;
 mod die;
 pub(crate) mod flatten_cfg;
@@ -22,6 +23,7 @@ mod remove_enable_side_effects;
 mod remove_if_else;
 mod remove_unreachable;
 mod simplify_cfg;
+mod split_large_blocks;
 mod unrolling;
 
 /// Asserts that the given SSA, after normalizing its IDs and printing it,
@@ -32,6 +32,22 @@ impl Ssa {
     }
 }
 
+impl Function {
+    /// Returns a copy of this function with its values and blocks renumbered in the same
+    /// way as [Ssa::normalize_ids], so that structurally identical functions print identically.
+    ///
+    /// Unlike [Ssa::normalize_ids], references to functions (including this one) keep their IDs.
+    pub(crate) fn normalized(&self, globals: &DataFlowGraph) -> Function {
+        let mut context = Context::default();
+        context.new_ids.preserve_function_ids = true;
+        context.populate_function(self.id(), self);
+
+        let mut function = Function::clone_with_id(self.id(), self);
+        context.normalize_ids(&mut function, globals);
+        context.functions.into_btree().into_values().next().expect("Expected a normalized function")
+    }
+}
+
 #[derive(Default)]
 struct Context {
     functions: SparseMap<Function>,
@@ -47,6 +63,9 @@ struct IdMaps {
     // Maps old function id -> new function id
     function_ids: HashMap<FunctionId, FunctionId>,
 
+    // When set, function values are not mapped through `function_ids`
+    preserve_function_ids: bool,
+
     // Maps old block id -> new block id
     // Cleared in between each function.
     blocks: HashMap<BasicBlockId, BasicBlockId>,
@@ -59,13 +78,17 @@ struct IdMaps {
 impl Context {
     fn populate_functions(&mut self, functions: &BTreeMap<FunctionId, Function>) {
         for (id, function) in functions {
-            self.functions.insert_with_id(|new_id| {
-                self.new_ids.function_ids.insert(*id, new_id);
-                Function::clone_signature(new_id, function)
-            });
+            self.populate_function(*id, function);
         }
     }
 
+    fn populate_function(&mut self, id: FunctionId, function: &Function) {
+        self.functions.insert_with_id(|new_id| {
+            self.new_ids.function_ids.insert(id, new_id);
+            Function::clone_signature(new_id, function)
+        });
+    }
+
     fn normalize_ids(&mut self, old_function: &mut Function, globals: &DataFlowGraph) {
         self.new_ids.blocks.clear();
         self.new_ids.values.clear();
@@ -185,6 +208,9 @@ impl IdMaps {
                 })
             }
 
+            Value::Function(id) if self.preserve_function_ids => {
+                new_function.dfg.import_function(*id)
+            }
             Value::Function(id) => {
                 let new_id = *self.function_ids.get(id).unwrap_or_else(|| {
                     unreachable!("Unmapped function with id {id}")
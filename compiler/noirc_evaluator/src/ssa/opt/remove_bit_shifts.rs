@@ -11,6 +11,7 @@ use crate::ssa::{
         instruction::{Binary, BinaryOp, Endian, Instruction, InstructionId, Intrinsic},
         types::{NumericType, Type},
         value::ValueId,
+        value_ranges::ValueRanges,
     },
     ssa_gen::Ssa,
 };
@@ -21,8 +22,10 @@ impl Ssa {
     /// See [`constant_folding`][self] module for more information.
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) fn remove_bit_shifts(mut self) -> Ssa {
-        for function in self.functions.values_mut() {
-            function.remove_bit_shifts();
+        let mut ranges = self.range_analysis();
+        for (id, function) in self.functions.iter_mut() {
+            let ranges = ranges.remove(id).unwrap_or_default();
+            function.remove_bit_shifts(ranges);
         }
         self
     }
@@ -31,7 +34,9 @@ impl Ssa {
 impl Function {
     /// The structure of this pass is simple:
     /// Go through each block and re-insert all instructions.
-    pub(crate) fn remove_bit_shifts(&mut self) {
+    ///
+    /// `ranges` are used to skip truncating the result of a left shift when it can't overflow.
+    pub(crate) fn remove_bit_shifts(&mut self, ranges: ValueRanges) {
         if self.runtime().is_brillig() {
             return;
         }
@@ -39,6 +44,7 @@ impl Function {
         let block = self.entry_block();
         let mut context = Context {
             function: self,
+            ranges,
             new_instructions: Vec::new(),
             block,
             call_stack: CallStackId::root(),
@@ -50,6 +56,7 @@ impl Function {
 
 struct Context<'f> {
     function: &'f mut Function,
+    ranges: ValueRanges,
     new_instructions: Vec<InstructionId>,
 
     block: BasicBlockId,
@@ -63,7 +70,7 @@ impl Context<'_> {
         for instruction_id in instructions {
             match self.function.dfg[instruction_id] {
                 Instruction::Binary(Binary { lhs, rhs, operator })
-                    if matches!(operator, BinaryOp::Shl | BinaryOp::Shr) =>
+                    if matches!(operator, BinaryOp::Shl { .. } | BinaryOp::Shr) =>
                 {
                     self.call_stack =
                         self.function.dfg.get_instruction_call_stack_id(instruction_id);
@@ -75,8 +82,8 @@ impl Context<'_> {
                         | Type::Numeric(NumericType::Unsigned { bit_size }) => bit_size,
                         _ => unreachable!("ICE: right-shift attempted on non-integer"),
                     };
-                    let new_result = if operator == BinaryOp::Shl {
-                        self.insert_wrapping_shift_left(lhs, rhs, bit_size)
+                    let new_result = if let BinaryOp::Shl { unchecked } = operator {
+                        self.insert_wrapping_shift_left(lhs, rhs, bit_size, unchecked)
                     } else {
                         self.insert_shift_right(lhs, rhs, bit_size)
                     };
@@ -95,11 +102,15 @@ impl Context<'_> {
 
     /// Insert ssa instructions which computes lhs << rhs by doing lhs*2^rhs
     /// and truncate the result to bit_size
+    ///
+    /// If the shift is `unchecked`, `rhs` is known to be less than `bit_size` so the result
+    /// does not need to be nullified for larger shifts.
     pub(crate) fn insert_wrapping_shift_left(
         &mut self,
         lhs: ValueId,
         rhs: ValueId,
         bit_size: u32,
+        unchecked: bool,
     ) -> ValueId {
         let base = self.field_constant(FieldElement::from(2_u128));
         let typ = self.function.dfg.type_of_value(lhs).unwrap_numeric();
@@ -119,12 +130,15 @@ impl Context<'_> {
             }
             let pow = self.numeric_constant(FieldElement::from(rhs_bit_size_pow_2), typ);
 
-            let max_lhs_bits = self.function.dfg.get_value_max_num_bits(lhs);
+            let max_lhs_bits = self.ranges.max_num_bits(&self.function.dfg, lhs);
             let max_bit_size = max_lhs_bits + bit_shift_size;
             // There is no point trying to truncate to more than the Field size.
             // A higher `max_lhs_bits` input can come from trying to left-shift a Field.
             let max_bit_size = max_bit_size.min(NumericType::NativeField.bit_size());
             (max_bit_size, pow)
+        } else if unchecked {
+            let pow = self.pow(base, rhs);
+            (FieldElement::max_num_bits(), self.insert_cast(pow, typ))
         } else {
             // we use a predicate to nullify the result in case of overflow
             let u8_type = NumericType::unsigned(8);
@@ -333,3 +347,66 @@ impl Context<'_> {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn shift_left_which_cannot_overflow_is_not_truncated() {
+        // `v2` fits in 4 bits so shifting it left by 2 can't overflow a u32
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = and v0, u32 15
+                v4 = shl v2, u32 2
+                return v4
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.remove_bit_shifts();
+
+        let expected = "
+            acir(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = and v0, u32 15
+                v4 = unchecked_mul v2, u32 4
+                return v4
+            }
+            ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn shift_left_which_may_overflow_is_truncated() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = shl v0, u32 2
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.remove_bit_shifts();
+        assert!(ssa.to_string().contains("truncate"));
+    }
+
+    #[test]
+    fn unchecked_shift_left_has_no_overflow_predicate() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u8):
+                v2 = shl v0, v1
+                return v2
+            }
+            ";
+        // A checked shift compares `v1` against the bit size to nullify overflowing shifts
+        let ssa = Ssa::from_str(src).unwrap().remove_bit_shifts();
+        assert!(ssa.to_string().contains("lt v1, u8 32"));
+
+        let src = src.replace("shl", "unchecked_shl");
+        let ssa = Ssa::from_str(&src).unwrap().remove_bit_shifts();
+        assert!(!ssa.to_string().contains("lt "));
+        assert!(ssa.to_string().contains("truncate"));
+    }
+}
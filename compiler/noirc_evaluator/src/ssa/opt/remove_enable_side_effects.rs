@@ -8,7 +8,10 @@
 //!       of this one.
 //!     - An [Instruction] with side-effects is encountered, if so then insert the currently saved [Instruction::EnableSideEffectsIf]
 //!       before the [Instruction]. Continue inserting instructions until the next [Instruction::EnableSideEffectsIf] is encountered.
-use std::collections::HashSet;
+//!
+//! An `enable_side_effects u1 1` is dropped when side effects are already enabled unconditionally,
+//! as it doesn't change the predicate of any instruction.
+use fxhash::FxHashSet as HashSet;
 
 use acvm::{acir::AcirField, FieldElement};
 
@@ -72,6 +75,8 @@ impl Context {
 
         let one = FieldElement::one();
         let mut active_condition = function.dfg.make_constant(one, NumericType::bool());
+        // The condition of the last `Instruction::EnableSideEffectsIf` which was kept.
+        let mut inserted_condition = active_condition;
         let mut last_side_effects_enabled_instruction = None;
 
         let mut new_instructions = Vec::with_capacity(instructions.len());
@@ -94,7 +99,11 @@ impl Context {
                     .get_numeric_constant(*condition)
                     .map_or(false, |condition| condition.is_one());
                 if condition_is_one {
-                    new_instructions.push(instruction_id);
+                    // Unless side effects are already enabled, in which case it does nothing.
+                    if inserted_condition != *condition {
+                        new_instructions.push(instruction_id);
+                        inserted_condition = *condition;
+                    }
                     last_side_effects_enabled_instruction = None;
                     active_condition = *condition;
                     continue;
@@ -112,6 +121,7 @@ impl Context {
                     last_side_effects_enabled_instruction.take()
                 {
                     new_instructions.push(enable_side_effects_instruction_id);
+                    inserted_condition = active_condition;
                 }
             }
             new_instructions.push(instruction_id);
@@ -205,6 +215,8 @@ mod test {
             map::Id,
             types::{NumericType, Type},
         },
+        opt::assert_normalized_ssa_equals,
+        ssa_gen::Ssa,
     };
 
     #[test]
@@ -281,4 +293,53 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn removes_redundant_constant_true_condition() {
+        // The second `enable_side_effects u1 1` is dropped along with `v1`, which never
+        // took effect as no instruction responding to it came before the next condition.
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u1):
+                enable_side_effects u1 1
+                v3 = add v0, u32 1
+                enable_side_effects v1
+                enable_side_effects u1 1
+                v4 = add v0, u32 2
+                return v3, v4
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u1):
+                v3 = add v0, u32 1
+                v5 = add v0, u32 2
+                return v3, v5
+            }
+            ";
+        let ssa = ssa.remove_enable_side_effects();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn keeps_store_with_constant_false_condition() {
+        // The store isn't necessarily guarded by flattening, so the reference may be read
+        // after side effects are enabled again and the store can't be removed.
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: &mut Field, v1: Field):
+                enable_side_effects u1 0
+                store v1 at v0
+                enable_side_effects u1 1
+                v2 = load v0 -> Field
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.remove_enable_side_effects();
+        assert_normalized_ssa_equals(ssa, src);
+    }
 }
@@ -1,8 +1,7 @@
-use std::collections::hash_map::Entry;
-
 use acvm::{acir::AcirField, FieldElement};
 use fxhash::FxHashMap as HashMap;
 
+use crate::errors::RuntimeError;
 use crate::ssa::ir::function::RuntimeType;
 use crate::ssa::ir::instruction::Hint;
 use crate::ssa::ir::types::NumericType;
@@ -28,36 +27,56 @@ impl Ssa {
     /// instruction does not need to be to the same array. This is because
     /// the given array may alias another array (e.g. function parameters or
     /// a `load`ed array from a reference).
+    ///
+    /// When merging two arrays, at most `max_array_set_lookback` `array_set` instructions are
+    /// followed back from each of them to find a common ancestor, in which case only the indices
+    /// changed since then are merged rather than every element.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) fn remove_if_else(mut self) -> Ssa {
+    pub(crate) fn remove_if_else(
+        mut self,
+        max_array_set_lookback: usize,
+    ) -> Result<Ssa, RuntimeError> {
         for function in self.functions.values_mut() {
-            function.remove_if_else();
+            function.remove_if_else(max_array_set_lookback)?;
         }
-        self
+        Ok(self)
     }
 }
 
 impl Function {
-    pub(crate) fn remove_if_else(&mut self) {
+    pub(crate) fn remove_if_else(
+        &mut self,
+        max_array_set_lookback: usize,
+    ) -> Result<(), RuntimeError> {
         // This should match the check in flatten_cfg
         if matches!(self.runtime(), RuntimeType::Brillig(_)) {
             // skip
+            Ok(())
         } else {
-            Context::default().remove_if_else(self);
+            Context::new(max_array_set_lookback).remove_if_else(self)
         }
     }
 }
 
-#[derive(Default)]
 struct Context {
     slice_sizes: HashMap<ValueId, u32>,
 
     // Maps array_set result -> enable_side_effects_if value which was active during it.
     array_set_conditionals: HashMap<ValueId, ValueId>,
+
+    max_array_set_lookback: usize,
 }
 
 impl Context {
-    fn remove_if_else(&mut self, function: &mut Function) {
+    fn new(max_array_set_lookback: usize) -> Self {
+        Context {
+            slice_sizes: HashMap::default(),
+            array_set_conditionals: HashMap::default(),
+            max_array_set_lookback,
+        }
+    }
+
+    fn remove_if_else(&mut self, function: &mut Function) -> Result<(), RuntimeError> {
         let block = function.entry_block();
         let instructions = function.dfg[block].take_instructions();
         let one = FieldElement::one();
@@ -75,6 +94,7 @@ impl Context {
                     assert!(!matches!(typ, Type::Numeric(_)));
 
                     let call_stack = function.dfg.get_instruction_call_stack_id(instruction);
+
                     let mut value_merger = ValueMerger::new(
                         &mut function.dfg,
                         block,
@@ -82,6 +102,7 @@ impl Context {
                         &mut self.array_set_conditionals,
                         Some(current_conditional),
                         call_stack,
+                        self.max_array_set_lookback,
                     );
 
                     let value = value_merger.merge_values(
@@ -89,7 +110,7 @@ impl Context {
                         else_condition,
                         then_value,
                         else_value,
-                    );
+                    )?;
 
                     let _typ = function.dfg.type_of_value(value);
                     let results = function.dfg.instruction_results(instruction);
@@ -145,21 +166,13 @@ impl Context {
                 }
             }
         }
+        Ok(())
     }
 
     fn get_or_find_capacity(&mut self, dfg: &DataFlowGraph, value: ValueId) -> u32 {
-        match self.slice_sizes.entry(value) {
-            Entry::Occupied(entry) => return *entry.get(),
-            Entry::Vacant(entry) => {
-                if let Some((array, typ)) = dfg.get_array_constant(value) {
-                    let length = array.len() / typ.element_types().len();
-                    return *entry.insert(length as u32);
-                }
-
-                if let Type::Array(_, length) = dfg.type_of_value(value) {
-                    return *entry.insert(length);
-                }
-            }
+        if let Some(length) = dfg.array_length_of(value, &self.slice_sizes) {
+            self.slice_sizes.insert(value, length);
+            return length;
         }
 
         let dbg_value = &dfg[value];
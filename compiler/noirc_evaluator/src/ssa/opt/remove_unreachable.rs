@@ -4,7 +4,7 @@ use fxhash::FxHashSet as HashSet;
 
 use crate::ssa::{
     ir::{
-        function::{Function, FunctionId},
+        function::{Function, FunctionId, RuntimeType},
         instruction::Instruction,
         value::Value,
     },
@@ -19,8 +19,14 @@ impl Ssa {
     pub(crate) fn remove_unreachable_functions(mut self) -> Self {
         let mut used_functions = HashSet::default();
 
-        for function_id in self.functions.keys() {
-            if self.is_entry_point(*function_id) {
+        for (function_id, function) in &self.functions {
+            // Brillig functions are entry points when inlining, but they still need to be
+            // called from somewhere to be reachable.
+            let is_root = match function.runtime() {
+                RuntimeType::Acir(_) => self.is_entry_point(*function_id),
+                RuntimeType::Brillig(_) => *function_id == self.main_id,
+            };
+            if is_root {
                 collect_reachable_functions(&self, *function_id, &mut used_functions);
             }
         }
@@ -78,3 +84,37 @@ fn used_functions(func: &Function) -> BTreeSet<FunctionId> {
 
     used_function_ids
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{ir::map::Id, ssa_gen::Ssa};
+
+    #[test]
+    fn removes_unreachable_functions() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = call f1(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn called f1 {
+              b0(v0: Field):
+                return v0
+            }
+            brillig(inline) fn unreferenced f2 {
+              b0(v0: Field):
+                v2 = call f3(v0) -> Field
+                return v2
+            }
+            brillig(inline) fn only_called_by_unreferenced f3 {
+              b0(v0: Field):
+                return v0
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.remove_unreachable_functions();
+
+        let function_ids: Vec<_> = ssa.functions.keys().copied().collect();
+        assert_eq!(function_ids, vec![Id::test_new(0), Id::test_new(1)]);
+    }
+}
@@ -9,7 +9,7 @@
 //!    only 1 successor then (2) also will be applied.
 //!
 //! Currently, 1 and 4 are unimplemented.
-use std::collections::HashSet;
+use fxhash::FxHashSet as HashSet;
 
 use acvm::acir::AcirField;
 
@@ -50,7 +50,7 @@ impl Function {
     pub(crate) fn simplify_function(&mut self) {
         let mut cfg = ControlFlowGraph::with_function(self);
         let mut stack = vec![self.entry_block()];
-        let mut visited = HashSet::new();
+        let mut visited = HashSet::default();
 
         while let Some(block) = stack.pop() {
             if visited.insert(block) {
@@ -94,6 +94,19 @@ fn check_for_constant_jmpif(
     block: BasicBlockId,
     cfg: &mut ControlFlowGraph,
 ) {
+    if let Some(TerminatorInstruction::JmpIf { condition, .. }) = function.dfg[block].terminator() {
+        // Canonicalize the condition so that conditions which are casts or comparisons
+        // of a known constant are recognized as such.
+        let condition = *condition;
+        if let Some(boolean) = function.dfg.simplify_known_bool(condition) {
+            if let TerminatorInstruction::JmpIf { condition, .. } =
+                function.dfg[block].unwrap_terminator_mut()
+            {
+                *condition = boolean;
+            }
+        }
+    }
+
     if let Some(TerminatorInstruction::JmpIf {
         condition,
         then_destination,
@@ -415,6 +428,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn canonicalizes_jmpif_condition() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u1):
+            v1 = cast v0 as u8
+            v3 = eq v1, u8 1
+            jmpif v3 then: b1, else: b2
+          b1():
+            return Field 1
+          b2():
+            return Field 2
+        }";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u1):
+            v1 = cast v0 as u8
+            v3 = eq v1, u8 1
+            jmpif v0 then: b2, else: b1
+          b1():
+            return Field 2
+          b2():
+            return Field 1
+        }";
+        assert_normalized_ssa_equals(ssa.simplify_cfg(), expected);
+    }
+
     #[test]
     fn swap_negated_jmpif_branches_in_brillig() {
         let src = "
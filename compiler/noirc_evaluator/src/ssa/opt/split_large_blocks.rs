@@ -0,0 +1,93 @@
+//! Splits basic blocks with more instructions than a given limit into a chain of smaller blocks
+//! connected by unconditional jumps.
+//!
+//! Some analyses and backends scale poorly with the size of a block. Each piece of a split block
+//! dominates the next one, so values defined in one piece remain available in the following
+//! ones and no block arguments are needed.
+//!
+//! ACIR functions must consist of a single block by the time they are compiled, so only Brillig
+//! functions are split.
+use crate::ssa::{ir::function::Function, ssa_gen::Ssa};
+
+impl Ssa {
+    /// Splits every block of a Brillig function which has more than `max_block_size` instructions.
+    ///
+    /// See the [`split_large_blocks`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn split_large_blocks(mut self, max_block_size: usize) -> Ssa {
+        assert!(max_block_size > 0, "blocks cannot be split into empty pieces");
+        for function in self.functions.values_mut() {
+            if function.runtime().is_brillig() {
+                function.split_large_blocks(max_block_size);
+            }
+        }
+        self
+    }
+}
+
+impl Function {
+    fn split_large_blocks(&mut self, max_block_size: usize) {
+        for mut block in self.reachable_blocks() {
+            while self.dfg[block].instructions().len() > max_block_size {
+                block = self.dfg.split_block(block, max_block_size);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn splits_large_brillig_blocks() {
+        let src = "
+            brillig(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = add v0, u32 1
+                v4 = add v2, u32 2
+                v6 = add v4, u32 3
+                v8 = add v6, u32 4
+                v10 = add v8, u32 5
+                return v10
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The original terminator ends the last piece
+        let ssa = ssa.split_large_blocks(2);
+        let expected = "
+            brillig(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = add v0, u32 1
+                v4 = add v2, u32 2
+                jmp b1()
+              b1():
+                v6 = add v4, u32 3
+                v8 = add v6, u32 4
+                jmp b2()
+              b2():
+                v10 = add v8, u32 5
+                return v10
+            }
+            ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_split_acir_blocks() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32):
+                v2 = add v0, u32 1
+                v4 = add v2, u32 2
+                v6 = add v4, u32 3
+                return v6
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.split_large_blocks(1);
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
@@ -24,7 +24,7 @@ use acvm::{acir::AcirField, FieldElement};
 use im::HashSet;
 
 use crate::{
-    brillig::brillig_gen::convert_ssa_function,
+    brillig::{brillig_gen::convert_ssa_function, BrilligOptions},
     errors::RuntimeError,
     ssa::{
         ir::{
@@ -992,7 +992,7 @@ fn brillig_bytecode_size(function: &Function) -> usize {
     // This is to try to prevent hitting ICE.
     temp.dead_instruction_elimination(false);
 
-    convert_ssa_function(&temp, false).byte_code.len()
+    convert_ssa_function(&temp, &BrilligOptions::default()).byte_code.len()
 }
 
 /// Decide if the new bytecode size is acceptable, compared to the original.
@@ -296,11 +296,12 @@ impl<'a> Parser<'a> {
             Token::Keyword(Keyword::And) => BinaryOp::And,
             Token::Keyword(Keyword::Or) => BinaryOp::Or,
             Token::Keyword(Keyword::Xor) => BinaryOp::Xor,
-            Token::Keyword(Keyword::Shl) => BinaryOp::Shl,
+            Token::Keyword(Keyword::Shl) => BinaryOp::Shl { unchecked: false },
             Token::Keyword(Keyword::Shr) => BinaryOp::Shr,
             Token::Keyword(Keyword::UncheckedAdd) => BinaryOp::Add { unchecked: true },
             Token::Keyword(Keyword::UncheckedSub) => BinaryOp::Sub { unchecked: true },
             Token::Keyword(Keyword::UncheckedMul) => BinaryOp::Mul { unchecked: true },
+            Token::Keyword(Keyword::UncheckedShl) => BinaryOp::Shl { unchecked: true },
             _ => return Ok(None),
         };
 
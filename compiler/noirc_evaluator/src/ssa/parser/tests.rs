@@ -342,6 +342,7 @@ fn test_binary() {
         "unchecked_add",
         "unchecked_sub",
         "unchecked_mul",
+        "unchecked_shl",
     ] {
         let src = format!(
             "
@@ -163,6 +163,7 @@ pub(crate) enum Keyword {
     UncheckedAdd,
     UncheckedSub,
     UncheckedMul,
+    UncheckedShl,
     Value,
     Xor,
 }
@@ -223,6 +224,7 @@ impl Keyword {
             "unchecked_add" => Keyword::UncheckedAdd,
             "unchecked_sub" => Keyword::UncheckedSub,
             "unchecked_mul" => Keyword::UncheckedMul,
+            "unchecked_shl" => Keyword::UncheckedShl,
             "value" => Keyword::Value,
             "xor" => Keyword::Xor,
             _ => return None,
@@ -287,6 +289,7 @@ impl Display for Keyword {
             Keyword::UncheckedAdd => write!(f, "unchecked_add"),
             Keyword::UncheckedSub => write!(f, "unchecked_sub"),
             Keyword::UncheckedMul => write!(f, "unchecked_mul"),
+            Keyword::UncheckedShl => write!(f, "unchecked_shl"),
             Keyword::Value => write!(f, "value"),
             Keyword::Xor => write!(f, "xor"),
         }
@@ -43,6 +43,11 @@ pub(super) struct FunctionContext<'a> {
     /// These are ordered such that an inner loop is at the end of the vector and
     /// outer loops are at the beginning. When a loop is finished, it is popped.
     loops: Vec<Loop>,
+
+    /// True if the function being compiled is marked `#[unchecked_arithmetic]`.
+    /// Its left shifts are then emitted as unchecked, so they are lowered without the predicate
+    /// nullifying overflowing shifts. The assertion that they do not overflow is kept.
+    pub(super) unchecked_arithmetic: bool,
 }
 
 /// Shared context for all functions during ssa codegen. This is the only
@@ -119,7 +124,13 @@ impl<'a> FunctionContext<'a> {
         builder.set_runtime(runtime);
 
         let definitions = HashMap::default();
-        let mut this = Self { definitions, builder, shared_context, loops: Vec::new() };
+        let mut this = Self {
+            definitions,
+            builder,
+            shared_context,
+            loops: Vec::new(),
+            unchecked_arithmetic: false,
+        };
         this.add_globals();
         this.add_parameters_to_scope(parameters);
         this
@@ -137,6 +148,7 @@ impl<'a> FunctionContext<'a> {
         } else {
             self.builder.new_function(func.name.clone(), id, func.inline_type);
         }
+        self.unchecked_arithmetic = func.unchecked_arithmetic;
 
         self.add_globals();
 
@@ -574,7 +586,7 @@ impl<'a> FunctionContext<'a> {
         mut rhs: ValueId,
         location: Location,
     ) -> Values {
-        let op = convert_operator(operator);
+        let op = convert_operator(operator, self.unchecked_arithmetic);
         if operator_requires_swapped_operands(operator) {
             std::mem::swap(&mut lhs, &mut rhs);
         }
@@ -1051,7 +1063,9 @@ fn operator_requires_swapped_operands(op: BinaryOpKind) -> bool {
 /// Take care when using this to insert a binary instruction: this requires
 /// checking operator_requires_not and operator_requires_swapped_operands
 /// to represent the full operation correctly.
-fn convert_operator(op: BinaryOpKind) -> BinaryOp {
+///
+/// Left shifts are marked as `unchecked` if they are known to shift by less than the bit size.
+fn convert_operator(op: BinaryOpKind, unchecked_shift: bool) -> BinaryOp {
     match op {
         BinaryOpKind::Add => BinaryOp::Add { unchecked: false },
         BinaryOpKind::Subtract => BinaryOp::Sub { unchecked: false },
@@ -1067,7 +1081,7 @@ fn convert_operator(op: BinaryOpKind) -> BinaryOp {
         BinaryOpKind::And => BinaryOp::And,
         BinaryOpKind::Or => BinaryOp::Or,
         BinaryOpKind::Xor => BinaryOp::Xor,
-        BinaryOpKind::ShiftLeft => BinaryOp::Shl,
+        BinaryOpKind::ShiftLeft => BinaryOp::Shl { unchecked: unchecked_shift },
         BinaryOpKind::ShiftRight => BinaryOp::Shr,
     }
 }
@@ -61,9 +61,10 @@ pub(crate) fn generate_ssa(program: Program) -> Result<Ssa, RuntimeError> {
     };
     let mut function_context =
         FunctionContext::new(main.name.clone(), &main.parameters, main_runtime, &context);
+    function_context.unchecked_arithmetic = main.unchecked_arithmetic;
 
     // Generate the call_data bus from the relevant parameters. We create it *before* processing the function body
-    let call_data = function_context.builder.call_data_bus(is_databus);
+    let call_data = function_context.builder.call_data_bus(is_databus)?;
 
     function_context.codegen_function_body(&main.body)?;
 
@@ -245,9 +246,13 @@ impl<'a> FunctionContext<'a> {
                             let value = value.replace('{', "{{").replace('}', "}}");
                             string.push_str(&value);
                         }
-                        FmtStrFragment::Interpolation(value, _span) => {
+                        FmtStrFragment::Interpolation(value, _span, spec) => {
                             string.push('{');
                             string.push_str(value);
+                            if let Some(spec) = spec {
+                                string.push(':');
+                                string.push_str(&spec.to_string());
+                            }
                             string.push('}');
                         }
                     }
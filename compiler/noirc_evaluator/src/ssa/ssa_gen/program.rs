@@ -71,6 +71,11 @@ impl Ssa {
         self.functions.get_mut(&self.main_id).expect("ICE: Ssa should have a main function")
     }
 
+    /// Returns the number of instructions in the reachable blocks of every function
+    pub(crate) fn count_instructions(&self) -> usize {
+        self.functions.values().map(Function::instruction_count).sum()
+    }
+
     /// Adds a new function to the program
     pub(crate) fn add_fn(
         &mut self,
@@ -141,4 +146,32 @@ mod test {
         }\n";
         assert_eq!(actual_string, expected_string);
     }
+
+    #[test]
+    fn counts_reachable_instructions() {
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: Field):
+                v2 = add v0, Field 1
+                v3 = call f1(v2) -> Field
+                jmp b1()
+              b1():
+                v4 = mul v3, v3
+                return v4
+              b2():
+                v5 = mul v0, v0
+                return v5
+            }
+            acir(inline) fn foo f1 {
+              b0(v0: Field):
+                v2 = mul v0, Field 2
+                return v2
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The instruction in the unreachable `b2` is not counted
+        assert_eq!(ssa.main().instruction_count(), 3);
+        assert_eq!(ssa.count_instructions(), 4);
+    }
 }
@@ -301,13 +301,27 @@ pub trait Recoverable {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ModuleDeclaration {
     pub visibility: ItemVisibility,
+    /// The name of the module's file.
     pub ident: Ident,
+    /// The name the module is declared as in `mod foo as bar;`, if different from its file.
+    pub alias: Option<Ident>,
     pub outer_attributes: Vec<SecondaryAttribute>,
 }
 
+impl ModuleDeclaration {
+    /// The name this module is declared under in its parent module.
+    pub fn name(&self) -> &Ident {
+        self.alias.as_ref().unwrap_or(&self.ident)
+    }
+}
+
 impl std::fmt::Display for ModuleDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "mod {}", self.ident)
+        write!(f, "mod {}", self.ident)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " as {alias}")?;
+        }
+        Ok(())
     }
 }
 
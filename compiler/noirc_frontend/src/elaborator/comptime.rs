@@ -392,6 +392,7 @@ impl<'context> Elaborator<'context> {
                         self.crate_id,
                         self.file,
                         self.local_module,
+                        &mut self.errors,
                     );
 
                 generated_items.trait_impls.push(UnresolvedTraitImpl {
@@ -263,7 +263,7 @@ impl<'context> Elaborator<'context> {
         let mut capture_types = Vec::new();
 
         for fragment in &fragments {
-            if let FmtStrFragment::Interpolation(ident_name, string_span) = fragment {
+            if let FmtStrFragment::Interpolation(ident_name, string_span, _spec) = fragment {
                 let scope_tree = self.scopes.current_scope_tree();
                 let variable = scope_tree.find(ident_name);
 
@@ -125,6 +125,20 @@ pub(super) fn missing_pub(func: &FuncMeta, modifiers: &FunctionModifiers) -> Opt
     }
 }
 
+/// `call_data` only applies to the parameters of entrypoint functions and `return_data` to their return type.
+pub(super) fn misplaced_databus_visibility(
+    ident: &Ident,
+    visibility: Visibility,
+    position: PubPosition,
+) -> Option<ResolverError> {
+    let visibility = match (visibility, &position) {
+        (Visibility::ReturnData, PubPosition::Parameter) => "return_data",
+        (Visibility::CallData(_), PubPosition::ReturnType) => "call_data",
+        _ => return None,
+    };
+    Some(ResolverError::MisplacedDatabusVisibility { ident: ident.clone(), visibility, position })
+}
+
 /// Check that we are not passing a mutable reference from a constrained runtime to an unconstrained runtime.
 pub(super) fn unconstrained_function_args(
     function_args: &[(Type, ExprId, Span)],
@@ -248,7 +262,7 @@ pub(crate) fn overflowing_int(
     errors
 }
 
-fn func_meta_name_ident(func: &FuncMeta, modifiers: &FunctionModifiers) -> Ident {
+pub(super) fn func_meta_name_ident(func: &FuncMeta, modifiers: &FunctionModifiers) -> Ident {
     Ident(Spanned::from(func.name.location.span, modifiers.name.clone()))
 }
 
@@ -22,7 +22,7 @@ use crate::{
         def_collector::{dc_crate::CollectedItems, errors::DefCollectorErrorKind},
         def_map::{DefMaps, ModuleData},
         def_map::{LocalModuleId, ModuleId, MAIN_FUNCTION},
-        resolution::errors::ResolverError,
+        resolution::errors::{PubPosition, ResolverError},
         scope::ScopeForest as GenericScopeForest,
         type_check::{generics::TraitGenerics, TypeCheckError},
         Context,
@@ -906,6 +906,11 @@ impl<'context> Elaborator<'context> {
             self.run_lint(|_| {
                 lints::unnecessary_pub_argument(func, visibility, is_pub_allowed).map(Into::into)
             });
+            self.run_lint(|_| {
+                let position = PubPosition::Parameter;
+                lints::misplaced_databus_visibility(func.name_ident(), visibility, position)
+                    .map(Into::into)
+            });
 
             let type_span = typ.span;
             let typ = match typ.typ {
@@ -941,6 +946,15 @@ impl<'context> Elaborator<'context> {
 
         let return_type = Box::new(self.resolve_type(func.return_type()));
 
+        // The values returned by an entry point are program outputs,
+        // so the same restrictions as for its inputs apply to them
+        self.check_if_type_is_valid_for_program_input(
+            &return_type,
+            is_entry_point,
+            false, // has_inline_attribute
+            func.return_type().span,
+        );
+
         let mut typ = Type::Function(
             parameter_types,
             return_type,
@@ -1057,6 +1071,12 @@ impl<'context> Elaborator<'context> {
             let pub_allowed = func.is_entry_point || modifiers.attributes.is_foldable();
             lints::unnecessary_pub_return(func, modifiers, pub_allowed).map(Into::into)
         });
+        self.run_lint(|_| {
+            let ident = lints::func_meta_name_ident(func, modifiers);
+            let position = PubPosition::ReturnType;
+            lints::misplaced_databus_visibility(&ident, func.return_visibility, position)
+                .map(Into::into)
+        });
         self.run_lint(|_| lints::oracle_not_marked_unconstrained(func, modifiers).map(Into::into));
         self.run_lint(|elaborator| {
             lints::low_level_function_outside_stdlib(func, modifiers, elaborator.crate_id)
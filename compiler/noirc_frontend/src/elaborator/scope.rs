@@ -122,7 +122,11 @@ impl<'context> Elaborator<'context> {
                 let name = &definition_info.name;
                 if name != ERROR_IDENT && !definition_info.is_global() {
                     let ident = Ident(Spanned::from(unused_var.location.span, name.to_owned()));
-                    self.push_err(ResolverError::UnusedVariable { ident });
+                    let warning = ResolverError::UnusedVariable { ident };
+                    let def_map = &self.def_maps[&self.crate_id];
+                    if let Some(error) = def_map.apply_lint_level(self.local_module, warning) {
+                        self.push_err(error);
+                    }
                 }
             }
         }
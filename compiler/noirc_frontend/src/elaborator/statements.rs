@@ -4,7 +4,7 @@ use crate::{
     ast::{
         AssignStatement, BinaryOpKind, ConstrainKind, ConstrainStatement, Expression,
         ExpressionKind, ForLoopStatement, ForRange, Ident, InfixExpression, ItemVisibility, LValue,
-        LetStatement, Path, Statement, StatementKind,
+        LetStatement, Path, Statement, StatementKind, UnresolvedTypeData,
     },
     hir::{
         resolution::{
@@ -78,16 +78,9 @@ impl<'context> Elaborator<'context> {
         let (expression, expr_type) = self.elaborate_expression(let_stmt.expression);
 
         let type_contains_unspecified = let_stmt.r#type.contains_unspecified();
+        let type_is_unannotated = matches!(let_stmt.r#type.typ, UnresolvedTypeData::Unspecified);
         let annotated_type = self.resolve_inferred_type(let_stmt.r#type);
 
-        // Require the top-level of a global's type to be fully-specified
-        if type_contains_unspecified && global_id.is_some() {
-            let span = expr_span;
-            let expected_type = annotated_type.clone();
-            let error = ResolverError::UnspecifiedGlobalType { span, expected_type };
-            self.push_err(error);
-        }
-
         let definition = match global_id {
             None => DefinitionKind::Local(Some(expression)),
             Some(id) => DefinitionKind::Global(id),
@@ -103,6 +96,21 @@ impl<'context> Elaborator<'context> {
             }
         });
 
+        // Require the top-level of a global's type to be fully-specified
+        if type_contains_unspecified && global_id.is_some() {
+            if type_is_unannotated && annotated_type.contains_uninferred_type_variable() {
+                // There is no inferred type worth suggesting, so point at the global instead
+                let name = let_stmt.pattern.name_ident().to_string();
+                let span = let_stmt.pattern.span();
+                self.push_err(ResolverError::UninferredGlobalType { name, span });
+            } else {
+                let span = expr_span;
+                let expected_type = annotated_type.clone();
+                let error = ResolverError::UnspecifiedGlobalType { span, expected_type };
+                self.push_err(error);
+            }
+        }
+
         if annotated_type.is_integer() {
             let errors = lints::overflowing_int(self.interner, &expression, &annotated_type);
             for error in errors {
@@ -1,5 +1,6 @@
 use std::{borrow::Cow, rc::Rc};
 
+use acvm::{AcirField, FieldElement};
 use im::HashSet;
 use iter_extended::vecmap;
 use noirc_errors::{Location, Span};
@@ -262,8 +263,10 @@ impl<'context> Elaborator<'context> {
         match self.lookup_struct_or_error(path) {
             Some(struct_type) => {
                 if self.resolving_ids.contains(&struct_type.borrow().id) {
+                    let name = struct_type.borrow().name.clone();
                     self.push_err(ResolverError::SelfReferentialStruct {
-                        span: struct_type.borrow().name.span(),
+                        name: name.to_string(),
+                        span: name.span(),
                     });
 
                     return Type::Error;
@@ -950,6 +953,18 @@ impl<'context> Elaborator<'context> {
             }
         }
 
+        // A value using every bit of the field modulus can wrap around it in any later arithmetic
+        if let (Some(from_value), Type::FieldElement) = (from_value_opt, to.follow_bindings()) {
+            let max_bits = FieldElement::max_num_bits();
+            if from_value.num_bits() >= max_bits {
+                self.push_err(TypeCheckError::CastOutsideSafeFieldRange {
+                    value: from_value,
+                    max_bits,
+                    span,
+                });
+            }
+        }
+
         match to {
             Type::Integer(sign, bits) => Type::Integer(*sign, *bits),
             Type::FieldElement => Type::FieldElement,
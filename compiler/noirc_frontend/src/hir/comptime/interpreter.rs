@@ -669,11 +669,15 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 FmtStrFragment::String(string) => {
                     result.push_str(&string);
                 }
-                FmtStrFragment::Interpolation(_, span) => {
+                FmtStrFragment::Interpolation(_, span, spec) => {
                     if let Some(value) = values.pop_front() {
-                        // When interpolating a quoted value inside a format string, we don't include the
-                        // surrounding `quote {` ... `}` as if we are unquoting the quoted value inside the string.
-                        if let Value::Quoted(tokens) = value {
+                        // A format specifier only changes how integral values are displayed
+                        let bytes = spec.and_then(|_| value.to_be_bytes());
+                        if let (Some(spec), Some(bytes)) = (spec, bytes) {
+                            result.push_str(&spec.format_be_bytes(&bytes));
+                        } else if let Value::Quoted(tokens) = value {
+                            // When interpolating a quoted value inside a format string, we don't include the
+                            // surrounding `quote {` ... `}` as if we are unquoting the quoted value inside the string.
                             for (index, token) in tokens.iter().enumerate() {
                                 if index > 0 {
                                     result.push(' ');
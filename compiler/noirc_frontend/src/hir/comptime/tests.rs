@@ -320,3 +320,23 @@ fn generic_functions() {
     let result = interpret(program);
     assert_eq!(result, Value::U8(2));
 }
+
+#[test]
+fn format_string_with_format_specs() {
+    let program = "
+    comptime fn main() -> pub fmtstr<17, (u8, i8, bool)> {
+        let x: u8 = 255;
+        let y: i8 = -1;
+        let z = true;
+        f\"{x:x} {y:b} {z:x}\"
+    }";
+    // A format string can't be returned from an entry point, which doesn't prevent interpreting it
+    let result = with_interpreter(program, |interpreter, main, _errors| {
+        interpreter.call_function(main, Vec::new(), HashMap::new(), Location::dummy())
+    });
+    let Ok(Value::FormatString(result, _)) = result else {
+        panic!("Expected a format string");
+    };
+    // Specifiers don't apply to non-integral values
+    assert_eq!(result.as_str(), "ff 11111111 true");
+}
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, rc::Rc, vec};
 
-use acvm::FieldElement;
+use acvm::{AcirField, FieldElement};
 use im::Vector;
 use iter_extended::{try_vecmap, vecmap};
 use noirc_errors::{Location, Span};
@@ -542,6 +542,24 @@ impl Value {
         }
     }
 
+    /// Returns the big-endian bytes of an integral `Value`, negative integers being
+    /// given as their two's complement. Returns `None` for non-integral `Value`s.
+    pub(crate) fn to_be_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Field(value) => Some(value.to_be_bytes()),
+            Self::I8(value) => Some(value.to_be_bytes().to_vec()),
+            Self::I16(value) => Some(value.to_be_bytes().to_vec()),
+            Self::I32(value) => Some(value.to_be_bytes().to_vec()),
+            Self::I64(value) => Some(value.to_be_bytes().to_vec()),
+            Self::U1(value) => Some(vec![*value as u8]),
+            Self::U8(value) => Some(value.to_be_bytes().to_vec()),
+            Self::U16(value) => Some(value.to_be_bytes().to_vec()),
+            Self::U32(value) => Some(value.to_be_bytes().to_vec()),
+            Self::U64(value) => Some(value.to_be_bytes().to_vec()),
+            _ => None,
+        }
+    }
+
     pub(crate) fn into_top_level_items(
         self,
         location: Location,
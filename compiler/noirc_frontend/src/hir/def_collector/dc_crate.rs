@@ -22,8 +22,9 @@ use crate::node_interner::{
 
 use crate::ast::{
     ExpressionKind, GenericTypeArgs, Ident, ItemVisibility, LetStatement, Literal, NoirFunction,
-    NoirStruct, NoirTrait, NoirTypeAlias, Path, PathKind, PathSegment, UnresolvedGenerics,
-    UnresolvedTraitConstraint, UnresolvedType, UnsupportedNumericGenericType,
+    NoirStruct, NoirTrait, NoirTypeAlias, Path, PathKind, PathSegment, UnresolvedGeneric,
+    UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData,
+    UnresolvedTypeExpression, UnsupportedNumericGenericType, Visitor,
 };
 
 use crate::parser::{ParserError, SortedModule};
@@ -450,6 +451,13 @@ impl DefCollector {
             }
         }
 
+        Self::check_undeclared_numeric_generics(
+            context,
+            crate_id,
+            &mut def_collector.items.types,
+            &mut errors,
+        );
+
         let debug_comptime_in_file = debug_comptime_in_file.and_then(|debug_comptime_in_file| {
             let file = context.file_manager.find_by_path_suffix(debug_comptime_in_file);
             file.unwrap_or_else(|error| {
@@ -482,17 +490,81 @@ impl DefCollector {
         let unused_imports = unused_imports.filter(|(module_id, _)| module_id.krate == crate_id);
 
         errors.extend(unused_imports.flat_map(|(module_id, usage_tracker)| {
-            let module = &context.def_maps[&crate_id].modules()[module_id.local_id.0];
-            usage_tracker.iter().map(|(ident, unused_item)| {
+            let def_map = &context.def_maps[&crate_id];
+            let module = &def_map.modules()[module_id.local_id.0];
+            usage_tracker.iter().filter_map(|(ident, unused_item)| {
                 let ident = ident.clone();
-                let error = CompilationError::ResolverError(ResolverError::UnusedItem {
-                    ident,
-                    item: *unused_item,
-                });
-                (error, module.location.file)
+                let warning = ResolverError::UnusedItem { ident, item: *unused_item };
+                let error = def_map.apply_lint_level(module_id.local_id, warning)?;
+                Some((CompilationError::ResolverError(error), module.location.file))
             })
         }));
     }
+
+    /// Reports identifiers used as a length in a field type (e.g. the `M` in `[Field; M]`)
+    /// which are neither a generic of the struct nor resolve to an item from the struct's module.
+    ///
+    /// This runs once imports have been resolved, so that imported globals are found.
+    /// The type of each offending field is replaced with an error type so that the
+    /// elaborator doesn't report the same identifier again.
+    fn check_undeclared_numeric_generics(
+        context: &mut Context,
+        crate_id: CrateId,
+        types: &mut BTreeMap<StructId, UnresolvedStruct>,
+        errors: &mut Vec<(CompilationError, FileId)>,
+    ) {
+        for unresolved in types.values_mut() {
+            let struct_def = &mut unresolved.struct_def;
+
+            // The name of a generic spliced in by a macro isn't known until it is resolved
+            let generics: Option<Vec<_>> = struct_def
+                .generics
+                .iter()
+                .map(|generic| match generic {
+                    UnresolvedGeneric::Variable(ident)
+                    | UnresolvedGeneric::Numeric { ident, .. } => Some(ident.0.contents.as_str()),
+                    UnresolvedGeneric::Resolved(..) => None,
+                })
+                .collect();
+            let Some(generics) = generics else {
+                continue;
+            };
+
+            let module_id = ModuleId { krate: crate_id, local_id: unresolved.module_id };
+            for field in &mut struct_def.fields {
+                let mut lengths = TypeLengthIdents::default();
+                field.item.typ.accept(&mut lengths);
+
+                let mut has_undeclared_length = false;
+                for ident in lengths.0 {
+                    if generics.contains(&ident.0.contents.as_str()) {
+                        continue;
+                    }
+
+                    let resolved = resolve_import(
+                        Path::from_ident(ident.clone()),
+                        module_id,
+                        &context.def_maps,
+                        &mut context.usage_tracker,
+                        None,
+                    );
+                    if resolved.is_err() {
+                        let error = DefCollectorErrorKind::UndeclaredNumericGeneric {
+                            name: ident,
+                            struct_name: struct_def.name.clone(),
+                        };
+                        errors.push((error.into(), unresolved.file_id));
+                        has_undeclared_length = true;
+                    }
+                }
+
+                if has_undeclared_length {
+                    let span = field.item.typ.span;
+                    field.item.typ = UnresolvedTypeData::Error.with_span(span);
+                }
+            }
+        }
+    }
 }
 
 fn add_import_reference(
@@ -566,6 +638,57 @@ fn inject_prelude(
     }
 }
 
+/// Collects the identifiers used as the length of array, string and format string types.
+#[derive(Default)]
+struct TypeLengthIdents(Vec<Ident>);
+
+impl TypeLengthIdents {
+    fn push(&mut self, length: &UnresolvedTypeExpression) {
+        match length {
+            UnresolvedTypeExpression::Variable(path) => {
+                if let Some(ident) = path.as_ident() {
+                    self.0.push(ident.clone());
+                }
+            }
+            UnresolvedTypeExpression::BinaryOperation(lhs, _, rhs, _) => {
+                self.push(lhs);
+                self.push(rhs);
+            }
+            UnresolvedTypeExpression::Constant(..) | UnresolvedTypeExpression::AsTraitPath(_) => {}
+        }
+    }
+}
+
+impl Visitor for TypeLengthIdents {
+    fn visit_array_type(
+        &mut self,
+        length: &UnresolvedTypeExpression,
+        _: &UnresolvedType,
+        _: Span,
+    ) -> bool {
+        self.push(length);
+        true
+    }
+
+    fn visit_string_type(&mut self, length: &UnresolvedTypeExpression, _: Span) {
+        self.push(length);
+    }
+
+    fn visit_format_string_type(
+        &mut self,
+        length: &UnresolvedTypeExpression,
+        _: &UnresolvedType,
+        _: Span,
+    ) -> bool {
+        self.push(length);
+        true
+    }
+
+    fn visit_expression_type(&mut self, expression: &UnresolvedTypeExpression, _: Span) {
+        self.push(expression);
+    }
+}
+
 /// Separate the globals Vec into two. The first element in the tuple will be the
 /// literal globals, except for arrays, and the second will be all other globals.
 /// We exclude array literals as they can contain complex types
@@ -1,5 +1,5 @@
 use core::str;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::vec;
 
@@ -17,7 +17,7 @@ use crate::ast::{
 };
 use crate::hir::resolution::errors::ResolverError;
 use crate::node_interner::{ModuleAttributes, NodeInterner, ReferenceId, StructId};
-use crate::token::SecondaryAttribute;
+use crate::token::{Attributes, FunctionAttribute, SecondaryAttribute};
 use crate::usage_tracker::{UnusedItem, UsageTracker};
 use crate::{
     graph::CrateId,
@@ -204,6 +204,7 @@ impl<'a> ModCollector<'a> {
                     krate,
                     self.file_id,
                     self.module_id,
+                    &mut errors,
                 );
 
             let module = ModuleId { krate, local_id: self.module_id };
@@ -643,6 +644,13 @@ impl<'a> ModCollector<'a> {
         errors
     }
 
+    /// Collects each submodule's definitions in source order.
+    ///
+    /// Collection only declares items in their own module, so the order siblings are visited in
+    /// does not matter: imports, type references and impls between sibling modules are all
+    /// deferred until every module in the crate has been collected. Any new cross-module work
+    /// done here must preserve that property, otherwise a module could observe a sibling
+    /// declared later in the file as missing.
     fn collect_submodules(
         &mut self,
         context: &mut Context,
@@ -720,16 +728,18 @@ impl<'a> ModCollector<'a> {
         let mod_decl = mod_decl.item;
 
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
-        let child_file_id = match find_module(&context.file_manager, self.file_id, &mod_decl.ident)
-        {
-            Ok(child_file_id) => child_file_id,
-            Err(err) => {
-                errors.push((err.into(), self.file_id));
-                return errors;
-            }
-        };
+        let include_dirs = context.include_dirs.get(&crate_id).map_or(&[][..], Vec::as_slice);
+        let child_file_id =
+            match find_module(&context.file_manager, self.file_id, &mod_decl.ident, include_dirs) {
+                Ok(child_file_id) => child_file_id,
+                Err(err) => {
+                    errors.push((err.into(), self.file_id));
+                    return errors;
+                }
+            };
 
-        let location = Location { file: self.file_id, span: mod_decl.ident.span() };
+        // The module is referred to by its alias, if any, so that is where references to it point
+        let location = Location { file: self.file_id, span: mod_decl.name().span() };
 
         if let Some(old_location) = context.visited_files.get(&child_file_id) {
             let error = DefCollectorErrorKind::ModuleAlreadyPartOfCrate {
@@ -756,10 +766,11 @@ impl<'a> ModCollector<'a> {
             parsing_errors.iter().map(|e| (e.clone().into(), child_file_id)).collect::<Vec<_>>(),
         );
 
-        // Add module into def collector and get a ModuleId
+        // Add module into def collector and get a ModuleId.
+        // The file is found from `ident` but the module is declared under its alias, if any.
         match self.push_child_module(
             context,
-            &mod_decl.ident,
+            mod_decl.name(),
             mod_decl.visibility,
             Location::new(Span::empty(0), child_file_id),
             mod_decl.outer_attributes.clone(),
@@ -948,6 +959,12 @@ pub fn collect_function(
         }
     }
 
+    if let Some((first, second)) = conflicting_attributes(&function.def.attributes) {
+        let span = function.name_ident().span();
+        let error = DefCollectorErrorKind::ConflictingAttributes { first, second, span };
+        errors.push((error.into(), file));
+    }
+
     let module_data = &mut def_map.modules[module.local_id.0];
 
     let is_test = function.def.attributes.is_test_function();
@@ -974,6 +991,17 @@ pub fn collect_function(
 
     interner.set_doc_comments(ReferenceId::Function(func_id), doc_comments);
 
+    match function.attributes().function() {
+        Some(FunctionAttribute::Oracle(oracle)) => {
+            interner.add_oracle(oracle.clone(), Location::new(name.span(), file));
+        }
+        // Comptime builtins are only ever evaluated by the interpreter
+        Some(FunctionAttribute::Builtin(builtin)) if !function.def.is_comptime => {
+            interner.add_builtin(builtin.clone(), Location::new(name.span(), file));
+        }
+        _ => (),
+    }
+
     // Add function to scope/ns of the module
     let result = def_map.modules[module.local_id.0].declare_function(name, visibility, func_id);
     if let Err((first_def, second_def)) = result {
@@ -987,6 +1015,39 @@ pub fn collect_function(
     Some(func_id)
 }
 
+/// Returns the first pair of attributes which cannot be placed on the same function.
+///
+/// Only one primary attribute is allowed per function (the parser already rejects more),
+/// so conflicts arise between a function's primary attribute and one of its secondary ones.
+fn conflicting_attributes(attributes: &Attributes) -> Option<(String, String)> {
+    let function_attribute = attributes.function()?;
+    let conflicts_with_export = matches!(
+        function_attribute,
+        FunctionAttribute::Test(_)
+            | FunctionAttribute::InlineAlways
+            | FunctionAttribute::Foreign(_)
+            | FunctionAttribute::Builtin(_)
+            | FunctionAttribute::Oracle(_)
+    );
+    // Functions without a body have no arithmetic to leave unchecked
+    let has_body = !matches!(
+        function_attribute,
+        FunctionAttribute::Foreign(_)
+            | FunctionAttribute::Builtin(_)
+            | FunctionAttribute::Oracle(_)
+    );
+
+    attributes.secondary.iter().find_map(|secondary| match secondary {
+        SecondaryAttribute::Export if conflicts_with_export => {
+            Some((function_attribute.to_string(), secondary.to_string()))
+        }
+        SecondaryAttribute::UncheckedArithmetic if !has_body => {
+            Some((function_attribute.to_string(), secondary.to_string()))
+        }
+        _ => None,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn collect_struct(
     interner: &mut NodeInterner,
@@ -1014,6 +1075,23 @@ pub fn collect_struct(
         file_id,
     );
 
+    // The struct's methods live in a child module of the same name, so a module declared with
+    // that name would clash with it. Modules are collected before structs so this also catches
+    // modules declared after the struct.
+    let parent_module = &def_map.modules[module_id.0];
+    if let Some((module_name, _)) = parent_module
+        .children
+        .get_key_value(&name)
+        .filter(|(_, child)| !def_map.modules[child.0].is_struct)
+    {
+        let error = DefCollectorErrorKind::StructAndModuleNameCollision {
+            struct_name: name,
+            module_name: module_name.clone(),
+        };
+        definition_errors.push((error.into(), file_id));
+        return None;
+    }
+
     // Create the corresponding module for the struct namespace
     let location = Location::new(name.span(), file_id);
     let id = match push_child_module(
@@ -1124,6 +1202,7 @@ fn find_module(
     file_manager: &FileManager,
     anchor: FileId,
     mod_name: &Ident,
+    include_dirs: &[PathBuf],
 ) -> Result<FileId, DefCollectorErrorKind> {
     let anchor_path = file_manager
         .path(anchor)
@@ -1131,10 +1210,6 @@ fn find_module(
         .with_extension("");
     let anchor_dir = anchor_path.parent().unwrap();
 
-    // Assuming anchor is called "anchor.nr" and we are looking up a module named "mod_name"...
-    // This is "mod_name"
-    let mod_name_str = &mod_name.0.contents;
-
     // If we are in a special name like "main.nr", "lib.nr", "mod.nr" or "{mod_name}.nr",
     // the search starts at the same directory, otherwise it starts in a nested directory.
     let start_dir = if should_check_siblings_for_module(&anchor_path, anchor_dir) {
@@ -1143,6 +1218,45 @@ fn find_module(
         anchor_path.as_path()
     };
 
+    let module = find_module_in_dir(file_manager, start_dir, mod_name);
+    if matches!(module, Err(DefCollectorErrorKind::OverlappingModuleDecls { .. })) {
+        return module;
+    }
+
+    // The first include directory containing the module, if any, is only used if the module
+    // can't be found relative to the anchor, but a module found in both places is ambiguous.
+    let included_module = include_dirs
+        .iter()
+        .map(|dir| find_module_in_dir(file_manager, dir, mod_name))
+        .find(|result| !matches!(result, Err(DefCollectorErrorKind::UnresolvedModuleDecl { .. })));
+
+    match (module, included_module) {
+        (Ok(id), Some(Ok(included_id))) => {
+            let path =
+                |id| file_manager.path(id).unwrap().as_os_str().to_string_lossy().to_string();
+            Err(DefCollectorErrorKind::OverlappingModuleDecls {
+                mod_name: mod_name.clone(),
+                expected_path: path(id),
+                alternative_path: path(included_id),
+            })
+        }
+        // The module's file is both `mod_name.nr` and `mod_name/mod.nr` in an include directory
+        (_, Some(Err(error))) => Err(error),
+        (Ok(id), None) | (Err(_), Some(Ok(id))) => Ok(id),
+        (Err(error), None) => Err(error),
+    }
+}
+
+/// Looks for the file of module `mod_name` in `dir`, either `mod_name.nr` or `mod_name/mod.nr`.
+fn find_module_in_dir(
+    file_manager: &FileManager,
+    start_dir: &Path,
+    mod_name: &Ident,
+) -> Result<FileId, DefCollectorErrorKind> {
+    // Assuming we are looking up a module named "mod_name"...
+    // This is "mod_name"
+    let mod_name_str = &mod_name.0.contents;
+
     // Check "mod_name.nr"
     let mod_name_candidate = start_dir.join(format!("{mod_name_str}.{FILE_EXTENSION}"));
     let mod_name_result = file_manager.name_to_id(mod_name_candidate.clone());
@@ -1216,11 +1330,12 @@ pub(crate) fn collect_trait_impl_items(
     krate: CrateId,
     file_id: FileId,
     local_id: LocalModuleId,
+    errors: &mut Vec<(CompilationError, FileId)>,
 ) -> (UnresolvedFunctions, AssociatedTypes, AssociatedConstants) {
     let mut unresolved_functions =
         UnresolvedFunctions { file_id, functions: Vec::new(), trait_id: None, self_type: None };
 
-    let mut associated_types = Vec::new();
+    let mut associated_types: AssociatedTypes = Vec::new();
     let mut associated_constants = Vec::new();
 
     let module = ModuleId { krate, local_id };
@@ -1243,7 +1358,17 @@ pub(crate) fn collect_trait_impl_items(
                 associated_constants.push((name, typ, expr));
             }
             TraitImplItemKind::Type { name, alias } => {
-                associated_types.push((name, alias));
+                let existing = associated_types.iter().find(|(existing, _)| *existing == name);
+                if let Some((first_def, _)) = existing {
+                    let error = DefCollectorErrorKind::Duplicate {
+                        typ: DuplicateType::TraitAssociatedType,
+                        first_def: first_def.clone(),
+                        second_def: name,
+                    };
+                    errors.push((error.into(), file_id));
+                } else {
+                    associated_types.push((name, alias));
+                }
             }
         }
     }
@@ -1309,19 +1434,21 @@ fn check_duplicate_field_names(
     file: FileId,
     definition_errors: &mut Vec<(CompilationError, FileId)>,
 ) {
-    let mut seen_field_names = std::collections::HashSet::new();
+    let mut seen_fields = std::collections::HashMap::new();
     for field in &struct_definition.fields {
-        let field_name = &field.item.name;
+        let field = &field.item;
 
-        if seen_field_names.insert(field_name) {
+        let Some(previous_field) = seen_fields.get(&field.name) else {
+            seen_fields.insert(&field.name, field);
             continue;
-        }
+        };
 
-        let previous_field_name = *seen_field_names.get(field_name).unwrap();
         definition_errors.push((
             DefCollectorErrorKind::DuplicateField {
-                first_def: previous_field_name.clone(),
-                second_def: field_name.clone(),
+                first_def: previous_field.name.clone(),
+                first_type: previous_field.typ.clone(),
+                second_def: field.name.clone(),
+                second_type: field.typ.clone(),
             }
             .into(),
             file,
@@ -1351,9 +1478,18 @@ mod find_module_tests {
         file_manager: &FileManager,
         anchor: FileId,
         mod_name: &str,
+    ) -> Result<FileId, DefCollectorErrorKind> {
+        find_module_with_include_dirs(file_manager, anchor, mod_name, &[])
+    }
+
+    fn find_module_with_include_dirs(
+        file_manager: &FileManager,
+        anchor: FileId,
+        mod_name: &str,
+        include_dirs: &[PathBuf],
     ) -> Result<FileId, DefCollectorErrorKind> {
         let mod_name = Ident(Spanned::from_position(0, 1, mod_name.to_string()));
-        super::find_module(file_manager, anchor, &mod_name)
+        super::find_module(file_manager, anchor, &mod_name, include_dirs)
     }
 
     #[test]
@@ -1512,4 +1648,135 @@ mod find_module_tests {
         let result = find_module(&fm, lib_file_id, "foo");
         assert!(matches!(result, Err(DefCollectorErrorKind::OverlappingModuleDecls { .. })));
     }
+
+    #[test]
+    fn finds_module_in_include_dir() {
+        let dir = PathBuf::new();
+        let mut fm = FileManager::new(&dir);
+
+        let lib_file_id = add_file(&mut fm, &dir, "src/lib.nr");
+        let foo_file_id = add_file(&mut fm, &dir, "vendor/foo.nr");
+        let bar_file_id = add_file(&mut fm, &dir, "other/bar/mod.nr");
+
+        let include_dirs = [dir.join("vendor"), dir.join("other")];
+        let result = find_module_with_include_dirs(&fm, lib_file_id, "foo", &include_dirs);
+        assert_eq!(result.unwrap(), foo_file_id);
+        let result = find_module_with_include_dirs(&fm, lib_file_id, "bar", &include_dirs);
+        assert_eq!(result.unwrap(), bar_file_id);
+
+        // Include directories are not searched by default
+        let result = find_module(&fm, lib_file_id, "foo");
+        assert!(matches!(result, Err(DefCollectorErrorKind::UnresolvedModuleDecl { .. })));
+    }
+
+    #[test]
+    fn finds_module_in_first_include_dir_containing_it() {
+        let dir = PathBuf::new();
+        let mut fm = FileManager::new(&dir);
+
+        let lib_file_id = add_file(&mut fm, &dir, "src/lib.nr");
+        let foo_file_id = add_file(&mut fm, &dir, "first/foo.nr");
+        add_file(&mut fm, &dir, "second/foo.nr");
+
+        let include_dirs = [dir.join("empty"), dir.join("first"), dir.join("second")];
+        let result = find_module_with_include_dirs(&fm, lib_file_id, "foo", &include_dirs);
+        assert_eq!(result.unwrap(), foo_file_id);
+    }
+
+    #[test]
+    fn errors_if_module_is_both_next_to_anchor_and_in_include_dir() {
+        let dir = PathBuf::new();
+        let mut fm = FileManager::new(&dir);
+
+        let lib_file_id = add_file(&mut fm, &dir, "src/lib.nr");
+        add_file(&mut fm, &dir, "src/foo.nr");
+        add_file(&mut fm, &dir, "vendor/foo.nr");
+
+        let include_dirs = [dir.join("vendor")];
+        let result = find_module_with_include_dirs(&fm, lib_file_id, "foo", &include_dirs);
+        assert!(matches!(result, Err(DefCollectorErrorKind::OverlappingModuleDecls { .. })));
+    }
+
+    /// Collects the definitions of a crate rooted at `lib.nr` with the given `(file, source)`s.
+    fn collect_crate(
+        files: &[(&str, &str)],
+    ) -> (Context<'static, 'static>, CrateId, Vec<(CompilationError, FileId)>) {
+        collect_crate_with_lsp_mode(files, false)
+    }
+
+    fn collect_crate_with_lsp_mode(
+        files: &[(&str, &str)],
+        lsp_mode: bool,
+    ) -> (Context<'static, 'static>, CrateId, Vec<(CompilationError, FileId)>) {
+        let dir = PathBuf::new();
+        let mut fm = FileManager::new(&dir);
+        let mut parsed_files = crate::hir::ParsedFiles::default();
+        for (file_name, source) in files {
+            let file_id =
+                fm.add_file_with_source(&dir.join(file_name), source.to_string()).unwrap();
+            parsed_files.insert(file_id, crate::parse_program(source));
+        }
+        let root_file_id = fm.name_to_id(dir.join("lib.nr")).unwrap();
+
+        let mut context = Context::new(fm, parsed_files);
+        context.def_interner.populate_dummy_operator_traits();
+        context.def_interner.lsp_mode = lsp_mode;
+        let crate_id = context.crate_graph.add_crate_root(root_file_id);
+        let errors = CrateDefMap::collect_defs(crate_id, &mut context, None, false);
+        (context, crate_id, errors)
+    }
+
+    #[test]
+    fn finds_aliased_module_from_its_file_name() {
+        let (context, crate_id, errors) = collect_crate(&[
+            ("lib.nr", "mod foo as bar; fn main() { bar::baz() }"),
+            ("foo.nr", "pub fn baz() {}"),
+        ]);
+        assert!(errors.is_empty(), "Expected no errors, got: {errors:?}");
+
+        // The module is loaded from `foo.nr` but declared as `bar`
+        let def_map = context.def_map(&crate_id).unwrap();
+        let root = &def_map.modules()[def_map.root().0];
+        assert!(root.find_name(&"bar".into()).types.is_some());
+        assert!(root.find_name(&"foo".into()).types.is_none());
+        assert!(root.children.contains_key(&"bar".into()));
+    }
+
+    #[test]
+    fn references_aliased_module_at_its_alias() {
+        let source = "mod foo as bar;";
+        let (context, _, errors) =
+            collect_crate_with_lsp_mode(&[("lib.nr", source), ("foo.nr", "")], true);
+        assert!(errors.is_empty(), "Expected no errors, got: {errors:?}");
+
+        let file = context.file_manager.name_to_id(PathBuf::new().join("lib.nr")).unwrap();
+        let span_of = |name: &str| {
+            let start = source.find(name).unwrap() as u32;
+            Span::from(start..start + name.len() as u32)
+        };
+        let interner = &context.def_interner;
+
+        // Renaming or finding references of `bar` must land on `bar`, not on the file name `foo`
+        let alias = Location::new(span_of("bar"), file);
+        assert!(matches!(interner.find_referenced(alias), Some(ReferenceId::Module(_))));
+        assert_eq!(interner.find_all_references(alias, false, false), Some(vec![alias]));
+        assert_eq!(interner.find_referenced(Location::new(span_of("foo"), file)), None);
+    }
+
+    #[test]
+    fn errors_on_aliased_module_with_duplicate_name() {
+        let (_, _, errors) = collect_crate(&[
+            ("lib.nr", "mod foo; mod qux as foo;"),
+            ("foo.nr", ""),
+            ("qux.nr", ""),
+        ]);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {errors:?}");
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::DefinitionError(DefCollectorErrorKind::Duplicate {
+                typ: DuplicateType::Module,
+                ..
+            })
+        ));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::ast::{Ident, ItemVisibility, Path, UnsupportedNumericGenericType};
+use crate::ast::{Ident, ItemVisibility, Path, UnresolvedType, UnsupportedNumericGenericType};
 use crate::hir::resolution::import::PathResolutionError;
 use crate::hir::type_check::generics::TraitGenerics;
 
@@ -28,7 +28,12 @@ pub enum DefCollectorErrorKind {
     #[error("duplicate {typ} found in namespace")]
     Duplicate { typ: DuplicateType, first_def: Ident, second_def: Ident },
     #[error("duplicate struct field {first_def}")]
-    DuplicateField { first_def: Ident, second_def: Ident },
+    DuplicateField {
+        first_def: Ident,
+        first_type: UnresolvedType,
+        second_def: Ident,
+        second_type: UnresolvedType,
+    },
     #[error("unresolved import")]
     UnresolvedModuleDecl { mod_name: Ident, expected_path: String, alternative_path: String },
     #[error("overlapping imports")]
@@ -86,6 +91,12 @@ pub enum DefCollectorErrorKind {
     TestOnAssociatedFunction { span: Span },
     #[error("The `#[export]` attribute may only be used on a non-associated function")]
     ExportOnAssociatedFunction { span: Span },
+    #[error("The `{first}` and `{second}` attributes cannot be used together")]
+    ConflictingAttributes { first: String, second: String, span: Span },
+    #[error("Undeclared numeric generic `{name}` in struct `{struct_name}`")]
+    UndeclaredNumericGeneric { name: Ident, struct_name: Ident },
+    #[error("Struct `{struct_name}` has the same name as a module in the same scope")]
+    StructAndModuleNameCollision { struct_name: Ident, module_name: Ident },
 }
 
 impl DefCollectorErrorKind {
@@ -144,7 +155,7 @@ impl<'a> From<&'a DefCollectorErrorKind> for Diagnostic {
                     diag
                 }
             }
-            DefCollectorErrorKind::DuplicateField { first_def, second_def } => {
+            DefCollectorErrorKind::DuplicateField { first_def, first_type, second_def, second_type } => {
                 let primary_message = format!(
                     "Duplicate definitions of struct field with name {} found",
                     &first_def.0.contents
@@ -154,10 +165,13 @@ impl<'a> From<&'a DefCollectorErrorKind> for Diagnostic {
                     let second_span = second_def.0.span();
                     let mut diag = Diagnostic::simple_error(
                         primary_message,
-                    "First definition found here".to_string(),
+                        format!("First definition found here, with type `{first_type}`"),
                         first_span,
                     );
-                    diag.add_secondary("Second definition found here".to_string(), second_span);
+                    diag.add_secondary(
+                        format!("Second definition found here, with type `{second_type}`"),
+                        second_span,
+                    );
                     diag
                 }
             }
@@ -305,6 +319,25 @@ impl<'a> From<&'a DefCollectorErrorKind> for Diagnostic {
                 String::new(),
                 *span,
             ),
+            DefCollectorErrorKind::ConflictingAttributes { first, second, span } => Diagnostic::simple_error(
+                format!("The `{first}` and `{second}` attributes cannot be used together"),
+                "Conflicting attributes on this function".into(),
+                *span,
+            ),
+            DefCollectorErrorKind::UndeclaredNumericGeneric { name, struct_name } => Diagnostic::simple_error(
+                format!("Undeclared numeric generic `{name}` in struct `{struct_name}`"),
+                format!("`{name}` is not a generic of `{struct_name}`"),
+                name.0.span(),
+            ),
+            DefCollectorErrorKind::StructAndModuleNameCollision { struct_name, module_name } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("Struct `{struct_name}` has the same name as a module in the same scope"),
+                    "Struct declared here".to_string(),
+                    struct_name.span(),
+                );
+                diag.add_secondary(format!("Module `{module_name}` declared here"), module_name.span());
+                diag
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::graph::{CrateGraph, CrateId};
 use crate::hir::def_collector::dc_crate::{CompilationError, DefCollector};
+use crate::hir::resolution::errors::ResolverError;
 use crate::hir::Context;
 use crate::node_interner::{FuncId, GlobalId, NodeInterner, StructId};
 use crate::parse_program;
@@ -21,6 +22,9 @@ pub use namespace::*;
 /// The name that is used for a non-contract program's entry-point function.
 pub const MAIN_FUNCTION: &str = "main";
 
+/// The lint group containing every lint, e.g. `#![allow(unused)]` silences all unused warnings.
+const UNUSED_LINT_GROUP: &str = "unused";
+
 // XXX: Ultimately, we want to constrain an index to be of a certain type just like in RA
 /// Lets first check if this is offered by any external crate
 /// XXX: RA has made this a crate on crates.io
@@ -134,6 +138,43 @@ impl CrateDefMap {
     pub fn root(&self) -> LocalModuleId {
         self.root
     }
+
+    /// Returns the level of `lint` in `module`, as set by the closest module enclosing it
+    /// which sets either the lint itself or the `unused` group all lints belong to.
+    pub fn lint_level(&self, module: LocalModuleId, lint: &str) -> Option<LintLevel> {
+        let mut module = Some(module);
+        while let Some(module_id) = module {
+            let module_data = &self.modules[module_id.0];
+            let level = module_data.lint_levels.get(lint);
+            if let Some(level) = level.or_else(|| module_data.lint_levels.get(UNUSED_LINT_GROUP)) {
+                return Some(*level);
+            }
+            module = module_data.parent;
+        }
+        None
+    }
+
+    /// Applies the lint level set in `module` to `warning`: returns nothing if the lint is
+    /// allowed, an error if it is denied, and the warning unchanged otherwise.
+    ///
+    /// Denied lints are only turned into errors in the root crate. In a dependency they stay
+    /// warnings, so that its lint levels can't fail the compilation of crates using it.
+    pub(crate) fn apply_lint_level(
+        &self,
+        module: LocalModuleId,
+        warning: ResolverError,
+    ) -> Option<ResolverError> {
+        let Some(lint) = warning.lint() else {
+            return Some(warning);
+        };
+        match self.lint_level(module, lint) {
+            Some(LintLevel::Allow) => None,
+            Some(LintLevel::Deny) if self.krate.is_root() => {
+                Some(ResolverError::DeniedLint { lint, warning: Box::new(warning) })
+            }
+            Some(LintLevel::Deny) | None => Some(warning),
+        }
+    }
     pub fn modules(&self) -> &Arena<ModuleData> {
         &self.modules
     }
@@ -35,6 +35,17 @@ pub struct ModuleData {
     pub is_struct: bool,
 
     pub attributes: Vec<SecondaryAttribute>,
+
+    /// The level of each lint set by the module's `#![allow(...)]` and `#![deny(...)]`
+    /// inner attributes, keyed by lint name.
+    pub lint_levels: HashMap<String, LintLevel>,
+}
+
+/// Whether the warnings of a lint are silenced or turned into errors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Deny,
 }
 
 impl ModuleData {
@@ -46,6 +57,15 @@ impl ModuleData {
         is_contract: bool,
         is_struct: bool,
     ) -> ModuleData {
+        let lint_levels = inner_attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                SecondaryAttribute::Allow(lint) => Some((lint.clone(), LintLevel::Allow)),
+                SecondaryAttribute::Deny(lint) => Some((lint.clone(), LintLevel::Deny)),
+                _ => None,
+            })
+            .collect();
+
         let mut attributes = outer_attributes;
         attributes.extend(inner_attributes);
 
@@ -59,6 +79,7 @@ impl ModuleData {
             is_contract,
             is_struct,
             attributes,
+            lint_levels,
         }
     }
 
@@ -52,6 +52,11 @@ pub struct Context<'file_manager, 'parsed_files> {
     pub parsed_files: Cow<'parsed_files, ParsedFiles>,
 
     pub package_build_path: PathBuf,
+
+    /// Additional directories of each crate, searched in order for the file of a `mod foo;`
+    /// declaration in that crate when it isn't found relative to the declaring file.
+    /// Their files must be added to the file manager like any other source file.
+    pub include_dirs: HashMap<CrateId, Vec<PathBuf>>,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -73,6 +78,7 @@ impl Context<'_, '_> {
             debug_instrumenter: DebugInstrumenter::default(),
             parsed_files: Cow::Owned(parsed_files),
             package_build_path: PathBuf::default(),
+            include_dirs: HashMap::new(),
         }
     }
 
@@ -90,6 +96,7 @@ impl Context<'_, '_> {
             debug_instrumenter: DebugInstrumenter::default(),
             parsed_files: Cow::Borrowed(parsed_files),
             package_build_path: PathBuf::default(),
+            include_dirs: HashMap::new(),
         }
     }
 
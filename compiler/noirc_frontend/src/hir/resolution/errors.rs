@@ -1,6 +1,6 @@
 use acvm::FieldElement;
 pub use noirc_errors::Span;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic, Location};
+use noirc_errors::{CustomDiagnostic as Diagnostic, DiagnosticKind, FileDiagnostic, Location};
 use thiserror::Error;
 
 use crate::{
@@ -32,6 +32,8 @@ pub enum ResolverError {
     UnusedVariable { ident: Ident },
     #[error("Unused {}", item.item_type())]
     UnusedItem { ident: Ident, item: UnusedItem },
+    #[error("{warning}")]
+    DeniedLint { lint: &'static str, warning: Box<ResolverError> },
     #[error("Unconditional recursion")]
     UnconditionalRecursion { name: String, span: Span },
     #[error("Could not find variable in this scope")]
@@ -54,6 +56,8 @@ pub enum ResolverError {
     UnnecessaryPub { ident: Ident, position: PubPosition },
     #[error("Required 'pub', main function must return public value")]
     NecessaryPub { ident: Ident },
+    #[error("misplaced {visibility} visibility on {position}")]
+    MisplacedDatabusVisibility { ident: Ident, visibility: &'static str, position: PubPosition },
     #[error("Missing expression for declared constant")]
     MissingRhsExpr { name: String, span: Span },
     #[error("Expression invalid in an array length context")]
@@ -104,6 +108,8 @@ pub enum ResolverError {
     MutableGlobal { span: Span },
     #[error("Globals must have a specified type")]
     UnspecifiedGlobalType { span: Span, expected_type: Type },
+    #[error("Type of global `{name}` cannot be inferred")]
+    UninferredGlobalType { name: String, span: Span },
     #[error("Global failed to evaluate")]
     UnevaluatedGlobalType { span: Span },
     #[error("Globals used in a type position must be non-negative")]
@@ -113,7 +119,7 @@ pub enum ResolverError {
     #[error("Global value `{global_value}` is larger than its kind's maximum value")]
     GlobalLargerThanKind { span: Span, global_value: FieldElement, kind: Kind },
     #[error("Self-referential structs are not supported")]
-    SelfReferentialStruct { span: Span },
+    SelfReferentialStruct { name: String, span: Span },
     #[error("#[no_predicates] attribute is only allowed on constrained functions")]
     NoPredicatesAttributeOnUnconstrained { ident: Ident },
     #[error("#[fold] attribute is only allowed on constrained functions")]
@@ -176,6 +182,17 @@ impl ResolverError {
     pub fn into_file_diagnostic(&self, file: fm::FileId) -> FileDiagnostic {
         Diagnostic::from(self).in_file(file)
     }
+
+    /// The name of the lint controlling this warning in `#![allow(...)]` and `#![deny(...)]`
+    /// module attributes, if any.
+    pub fn lint(&self) -> Option<&'static str> {
+        match self {
+            ResolverError::UnusedVariable { .. } => Some("unused_variables"),
+            ResolverError::UnusedItem { item: UnusedItem::Import, .. } => Some("unused_imports"),
+            ResolverError::UnusedItem { .. } => Some("dead_code"),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> From<&'a ResolverError> for Diagnostic {
@@ -225,6 +242,12 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                 diagnostic.unnecessary = true;
                 diagnostic
             }
+            ResolverError::DeniedLint { lint, warning } => {
+                let mut diagnostic = Diagnostic::from(warning.as_ref());
+                diagnostic.kind = DiagnosticKind::Error;
+                diagnostic.add_note(format!("`{lint}` is denied by a `#![deny(...)]` attribute"));
+                diagnostic
+            }
             ResolverError::UnconditionalRecursion { name, span} => {
                 Diagnostic::simple_warning(
                     format!("function `{name}` cannot return without recursing"),
@@ -328,6 +351,18 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                 diag.add_note("The `pub` keyword is mandatory for the entry-point function return type because the verifier cannot retrieve private witness and thus the function will not be able to return a 'priv' value".to_owned());
                 diag
             }
+            ResolverError::MisplacedDatabusVisibility { ident, visibility, position } => {
+                let name = &ident.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("`{visibility}` cannot be used on the {position} of function {name}"),
+                    format!("misplaced `{visibility}`"),
+                    ident.0.span(),
+                );
+
+                diag.add_note("`call_data` may only be used on the parameters of an entry-point function and `return_data` on its return type".to_owned());
+                diag
+            }
             ResolverError::MissingRhsExpr { name, span } => Diagnostic::simple_error(
                 format!(
                     "no expression specifying the value stored by the constant variable {name}"
@@ -454,6 +489,13 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *span,
                 )
             },
+            ResolverError::UninferredGlobalType { name, span } => {
+                Diagnostic::simple_error(
+                    format!("Type of global `{name}` cannot be inferred"),
+                    "Add a type annotation to this global".to_string(),
+                    *span,
+                )
+            },
             ResolverError::UnevaluatedGlobalType { span } => {
                 Diagnostic::simple_error(
                     "Global failed to evaluate".to_string(),
@@ -482,10 +524,10 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *span,
                 )
             }
-            ResolverError::SelfReferentialStruct { span } => {
+            ResolverError::SelfReferentialStruct { name, span } => {
                 Diagnostic::simple_error(
                     "Self-referential structs are not supported".into(),
-                    "".into(),
+                    format!("'{name}' contains itself: {name} -> {name}"),
                     *span,
                 )
             },
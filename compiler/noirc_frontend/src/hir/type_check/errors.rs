@@ -74,6 +74,8 @@ pub enum TypeCheckError {
     InvalidCast { from: Type, span: Span, reason: String },
     #[error("Casting value of type {from} to a smaller type ({to})")]
     DownsizingCast { from: Type, to: Type, span: Span, reason: String },
+    #[error("Casting value {value} which uses all {max_bits} bits of a field element")]
+    CastOutsideSafeFieldRange { value: FieldElement, max_bits: u32, span: Span },
     #[error("Expected a function, but found a(n) {found}")]
     ExpectedFunction { found: Type, span: Span },
     #[error("Type {lhs_type} has no member named {field_name}")]
@@ -339,6 +341,10 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
             TypeCheckError::DownsizingCast { span, reason, .. } => {
                 Diagnostic::simple_warning(error.to_string(), reason.clone(), *span)
             }
+            TypeCheckError::CastOutsideSafeFieldRange { span, .. } => {
+                let secondary = "Arithmetic on this value may wrap around the field modulus".into();
+                Diagnostic::simple_warning(error.to_string(), secondary, *span)
+            }
 
             TypeCheckError::ExpectedFunction { span, .. }
             | TypeCheckError::AccessUnknownMember { span, .. }
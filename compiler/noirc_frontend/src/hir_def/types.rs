@@ -2332,6 +2332,55 @@ impl Type {
         }
     }
 
+    /// True if self contains an unbound type variable which has no default type to fall back on.
+    /// Unbound integer type variables are not counted as they default to `Field` or `u32`.
+    pub(crate) fn contains_uninferred_type_variable(&self) -> bool {
+        match self {
+            Type::Array(len, elem) => {
+                len.contains_uninferred_type_variable() || elem.contains_uninferred_type_variable()
+            }
+            Type::Slice(elem) => elem.contains_uninferred_type_variable(),
+            Type::String(len) => len.contains_uninferred_type_variable(),
+            Type::FmtString(len, fields) => {
+                len.contains_uninferred_type_variable()
+                    || fields.contains_uninferred_type_variable()
+            }
+            Type::Struct(_, generic_args) | Type::Alias(_, generic_args) => {
+                generic_args.iter().any(|arg| arg.contains_uninferred_type_variable())
+            }
+            Type::Tuple(fields) => {
+                fields.iter().any(|field| field.contains_uninferred_type_variable())
+            }
+            Type::TypeVariable(type_var) => match &*type_var.borrow() {
+                TypeBinding::Bound(binding) => binding.contains_uninferred_type_variable(),
+                TypeBinding::Unbound(_, kind) => {
+                    !matches!(kind, Kind::IntegerOrField | Kind::Integer)
+                }
+            },
+            Type::Function(args, ret, env, _unconstrained) => {
+                args.iter().any(|arg| arg.contains_uninferred_type_variable())
+                    || ret.contains_uninferred_type_variable()
+                    || env.contains_uninferred_type_variable()
+            }
+            Type::MutableReference(element) => element.contains_uninferred_type_variable(),
+            Type::InfixExpr(lhs, _op, rhs) => {
+                lhs.contains_uninferred_type_variable() || rhs.contains_uninferred_type_variable()
+            }
+
+            Type::NamedGeneric(..)
+            | Type::TraitAsType(..)
+            | Type::CheckedCast { .. }
+            | Type::Forall(..)
+            | Type::FieldElement
+            | Type::Integer(_, _)
+            | Type::Bool
+            | Type::Constant(_, _)
+            | Type::Error
+            | Type::Quoted(_)
+            | Type::Unit => false,
+        }
+    }
+
     /// Follow any TypeVariable bindings within this type. Doing so ensures
     /// that if the bindings are rebound or unbound from under the type then the
     /// returned type will not change (because it will no longer contain the
@@ -6,6 +6,7 @@ use super::{
 };
 use acvm::{AcirField, FieldElement};
 use noirc_errors::{Position, Span};
+use noirc_printable_type::FormatSpec;
 use num_bigint::BigInt;
 use num_traits::{Num, One};
 use std::str::{CharIndices, FromStr};
@@ -356,7 +357,7 @@ impl<'a> Lexer<'a> {
         let start = self.position;
 
         let integer_str = self.eat_while(Some(initial_char), |ch| {
-            ch.is_ascii_digit() | ch.is_ascii_hexdigit() | (ch == 'x') | (ch == '_')
+            ch.is_ascii_digit() | ch.is_ascii_hexdigit() | (ch == 'x') | (ch == 'o') | (ch == '_')
         });
 
         let end = self.position;
@@ -379,9 +380,14 @@ impl<'a> Lexer<'a> {
         // Underscores needs to be stripped out before the literal can be converted to a `FieldElement.
         let integer_str = integer_str.replace('_', "");
 
-        let bigint_result = match integer_str.strip_prefix("0x") {
-            Some(integer_str) => BigInt::from_str_radix(integer_str, 16),
-            None => BigInt::from_str(&integer_str),
+        let bigint_result = if let Some(integer_str) = integer_str.strip_prefix("0x") {
+            BigInt::from_str_radix(integer_str, 16)
+        } else if let Some(integer_str) = integer_str.strip_prefix("0o") {
+            BigInt::from_str_radix(integer_str, 8)
+        } else if let Some(integer_str) = integer_str.strip_prefix("0b") {
+            BigInt::from_str_radix(integer_str, 2)
+        } else {
+            BigInt::from_str(&integer_str)
         };
 
         let integer = match bigint_result {
@@ -534,6 +540,8 @@ impl<'a> Lexer<'a> {
             // Interpolation fragment until '}' or '"'
             let mut string = String::new();
             let interpolation_start = self.position + 1; // + 1 because we are at '{'
+            let mut interpolation_end = None;
+            let mut spec = None;
             let mut first_char = true;
             while let Some(next) = self.next_char() {
                 let char = match next {
@@ -550,6 +558,13 @@ impl<'a> Lexer<'a> {
 
                         break;
                     }
+                    ':' if !first_char => {
+                        // A format specifier, which ends the interpolation
+                        interpolation_end = Some(self.position);
+                        spec = Some(self.eat_format_spec()?);
+                        length += 2;
+                        continue;
+                    }
                     other => {
                         let is_valid_char = if first_char {
                             other.is_ascii_alphabetic() || other == '_'
@@ -578,8 +593,9 @@ impl<'a> Lexer<'a> {
 
             length += 1; // for the closing curly brace
 
-            let interpolation_span = Span::from(interpolation_start..self.position);
-            fragments.push(FmtStrFragment::Interpolation(string, interpolation_span));
+            let interpolation_end = interpolation_end.unwrap_or(self.position);
+            let interpolation_span = Span::from(interpolation_start..interpolation_end);
+            fragments.push(FmtStrFragment::Interpolation(string, interpolation_span, spec));
         }
 
         let token = Token::FmtStr(fragments, length);
@@ -587,6 +603,40 @@ impl<'a> Lexer<'a> {
         Ok(token.into_span(start, end))
     }
 
+    /// Eats the format specifier following a `:` in a format string interpolation,
+    /// which must be directly followed by the closing curly brace.
+    fn eat_format_spec(&mut self) -> Result<FormatSpec, LexerErrorKind> {
+        let next = self.next_char();
+        let spec = match next {
+            Some('x') => FormatSpec::LowerHex,
+            Some('b') => FormatSpec::Binary,
+            _ => return Err(self.invalid_format_string(next)),
+        };
+        if self.peek_char_is('}') {
+            Ok(spec)
+        } else {
+            let next = self.next_char();
+            Err(self.invalid_format_string(next))
+        }
+    }
+
+    /// The error for an unexpected char inside a format string interpolation.
+    fn invalid_format_string(&mut self, found: Option<char>) -> LexerErrorKind {
+        let error_position = self.position;
+        let span = Span::inclusive(error_position, error_position);
+        match found {
+            None => LexerErrorKind::UnterminatedStringLiteral { span },
+            Some(found) => {
+                // Keep consuming chars until we find the closing double quote
+                // (unless we bumped into a double quote now, in which case we are done)
+                if found != '"' {
+                    self.skip_until_string_end();
+                }
+                LexerErrorKind::InvalidFormatString { found, span }
+            }
+        }
+    }
+
     fn skip_until_string_end(&mut self) {
         while let Some(next) = self.next_char() {
             if next == '\'' && self.peek_char_is('"') {
@@ -1175,11 +1225,11 @@ mod tests {
             Token::FmtStr(
                 vec![
                     FmtStrFragment::String("hello ".to_string()),
-                    FmtStrFragment::Interpolation("world".to_string(), Span::from(21..26)),
+                    FmtStrFragment::Interpolation("world".to_string(), Span::from(21..26), None),
                     FmtStrFragment::String(" and ".to_string()),
-                    FmtStrFragment::Interpolation("_another".to_string(), Span::from(33..41)),
+                    FmtStrFragment::Interpolation("_another".to_string(), Span::from(33..41), None),
                     FmtStrFragment::String(" ".to_string()),
-                    FmtStrFragment::Interpolation("vAr_123".to_string(), Span::from(44..51)),
+                    FmtStrFragment::Interpolation("vAr_123".to_string(), Span::from(44..51), None),
                 ],
                 38,
             ),
@@ -1192,6 +1242,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_eat_fmt_string_literal_with_format_specs() {
+        let input = "f\"{x:x} {y:b}\"";
+        let expected = Token::FmtStr(
+            vec![
+                FmtStrFragment::Interpolation(
+                    "x".to_string(),
+                    Span::from(3..4),
+                    Some(FormatSpec::LowerHex),
+                ),
+                FmtStrFragment::String(" ".to_string()),
+                FmtStrFragment::Interpolation(
+                    "y".to_string(),
+                    Span::from(9..10),
+                    Some(FormatSpec::Binary),
+                ),
+            ],
+            11,
+        );
+        let mut lexer = Lexer::new(input);
+        assert_eq!(lexer.next_token().unwrap().into_token(), expected);
+    }
+
+    #[test]
+    fn test_eat_fmt_string_literal_invalid_format_spec() {
+        for input in ["f\"{x:y}\" true", "f\"{x:xx}\" true", "f\"{:x}\" true"] {
+            let mut lexer = Lexer::new(input);
+            assert!(matches!(lexer.next_token(), Err(LexerErrorKind::InvalidFormatString { .. })));
+
+            // Make sure the lexer went past the ending double quote for better recovery
+            let token = lexer.next_token().unwrap().into_token();
+            assert!(matches!(token, Token::Bool(true)));
+        }
+    }
+
     #[test]
     fn test_eat_fmt_string_literal_missing_double_quote() {
         let input = "f\"hello";
@@ -1257,6 +1342,10 @@ mod tests {
             ("0x1234_5678", Token::Int(0x1234_5678_u128.into())),
             ("0x_01", Token::Int(0x1_u128.into())),
             ("1_000_000", Token::Int(1_000_000_u128.into())),
+            ("0b1010", Token::Int(10_u128.into())),
+            ("0b_1111_0000", Token::Int(0b1111_0000_u128.into())),
+            ("0o17", Token::Int(15_u128.into())),
+            ("0o7_777", Token::Int(0o7777_u128.into())),
         ];
 
         for (input, expected_token) in test_cases {
@@ -1268,7 +1357,7 @@ mod tests {
 
     #[test]
     fn test_reject_invalid_underscores_in_integer_literal() {
-        let test_cases: Vec<&str> = vec!["0x05_", "5_", "5__5", "0x5__5"];
+        let test_cases: Vec<&str> = vec!["0x05_", "5_", "5__5", "0x5__5", "1__0", "0b1__0"];
 
         for input in test_cases {
             let mut lexer = Lexer::new(input);
@@ -1280,6 +1369,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reject_invalid_digits_in_integer_literal() {
+        // The error points at the whole literal, but not at the tokens after it
+        let test_cases: Vec<&str> = vec!["0b102", "0o18", "0b", "0o"];
+
+        for input in test_cases {
+            let source = format!("{input} + 1");
+            let mut lexer = Lexer::new(&source);
+            let token = lexer.next_token();
+            let Err(LexerErrorKind::InvalidIntegerLiteral { span, .. }) = token else {
+                panic!("expected {input} to throw error, got {token:?}");
+            };
+            assert_eq!(span, Span::inclusive(0, input.len() as u32 - 1), "{input}");
+        }
+    }
+
     #[test]
     fn test_span() {
         let input = "let x = 5";
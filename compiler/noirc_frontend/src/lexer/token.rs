@@ -1,5 +1,6 @@
 use acvm::FieldElement;
 use noirc_errors::{Position, Span, Spanned};
+use noirc_printable_type::FormatSpec;
 use std::fmt::{self, Display};
 
 use crate::{
@@ -315,7 +316,8 @@ pub fn token_to_borrowed_token(token: &Token) -> BorrowedToken<'_> {
 #[derive(Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 pub enum FmtStrFragment {
     String(String),
-    Interpolation(String, Span),
+    /// An interpolated variable, with the span of its name and its optional format specifier
+    Interpolation(String, Span, Option<FormatSpec>),
 }
 
 impl Display for FmtStrFragment {
@@ -334,9 +336,12 @@ impl Display for FmtStrFragment {
                     .replace('\"', "\\\"");
                 write!(f, "{}", string)
             }
-            FmtStrFragment::Interpolation(string, _span) => {
+            FmtStrFragment::Interpolation(string, _span, None) => {
                 write!(f, "{{{}}}", string)
             }
+            FmtStrFragment::Interpolation(string, _span, Some(spec)) => {
+                write!(f, "{{{}:{}}}", string, spec)
+            }
         }
     }
 }
@@ -752,6 +757,11 @@ impl Attributes {
         self.has_secondary_attr(&SecondaryAttribute::UseCallersScope)
     }
 
+    /// True if the function is marked with an `#[unchecked_arithmetic]` attribute.
+    pub fn has_unchecked_arithmetic(&self) -> bool {
+        self.has_secondary_attr(&SecondaryAttribute::UncheckedArithmetic)
+    }
+
     /// True if the function is marked with an `#[export]` attribute.
     pub fn has_export(&self) -> bool {
         self.has_secondary_attr(&SecondaryAttribute::Export)
@@ -904,6 +914,13 @@ pub enum SecondaryAttribute {
 
     /// Allow chosen warnings to happen so they are silenced.
     Allow(String),
+
+    /// The left shifts in this function are known not to overflow, so they are lowered
+    /// without the predicate which nullifies overflowing shifts.
+    UncheckedArithmetic,
+
+    /// Turn chosen warnings into errors.
+    Deny(String),
 }
 
 impl SecondaryAttribute {
@@ -921,6 +938,8 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => Some("varargs".to_string()),
             SecondaryAttribute::UseCallersScope => Some("use_callers_scope".to_string()),
             SecondaryAttribute::Allow(_) => Some("allow".to_string()),
+            SecondaryAttribute::Deny(_) => Some("deny".to_string()),
+            SecondaryAttribute::UncheckedArithmetic => Some("unchecked_arithmetic".to_string()),
         }
     }
 
@@ -950,6 +969,8 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => "varargs".to_string(),
             SecondaryAttribute::UseCallersScope => "use_callers_scope".to_string(),
             SecondaryAttribute::Allow(ref k) => format!("allow({k})"),
+            SecondaryAttribute::Deny(ref k) => format!("deny({k})"),
+            SecondaryAttribute::UncheckedArithmetic => "unchecked_arithmetic".to_string(),
         }
     }
 }
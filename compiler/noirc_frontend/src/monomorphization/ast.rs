@@ -291,6 +291,9 @@ pub struct Function {
     pub unconstrained: bool,
     pub inline_type: InlineType,
     pub func_sig: FunctionSignature,
+    /// True if the function is marked `#[unchecked_arithmetic]`, in which case its left shifts
+    /// are lowered without the predicate nullifying overflowing shifts.
+    pub unchecked_arithmetic: bool,
 }
 
 /// Compared to hir_def::types::Type, this monomorphized Type has:
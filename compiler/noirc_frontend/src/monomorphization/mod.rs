@@ -382,6 +382,7 @@ impl<'interner> Monomorphizer<'interner> {
 
         let attributes = self.interner.function_attributes(&f);
         let inline_type = InlineType::from(attributes);
+        let unchecked_arithmetic = attributes.has_unchecked_arithmetic();
 
         let parameters = self.parameters(&meta.parameters)?;
         let body = self.expr(body_expr_id)?;
@@ -394,6 +395,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type,
             func_sig,
+            unchecked_arithmetic,
         };
 
         self.push_function(id, function);
@@ -1758,6 +1760,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained: self.in_unconstrained_function,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            unchecked_arithmetic: false,
         };
         self.push_function(id, function);
 
@@ -1890,6 +1893,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained: self.in_unconstrained_function,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            unchecked_arithmetic: false,
         };
         self.push_function(id, function);
 
@@ -2014,6 +2018,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            unchecked_arithmetic: false,
         };
         self.push_function(id, function);
 
@@ -2141,6 +2146,21 @@ fn unwrap_struct_type(
     }
 }
 
+/// True if calls to the builtin function `name` are evaluated during monomorphization
+/// (see `try_evaluate_call`), so it never needs to be lowered to an SSA intrinsic.
+pub fn is_evaluated_builtin(name: &str) -> bool {
+    matches!(
+        name,
+        "modulus_num_bits"
+            | "zeroed"
+            | "modulus_le_bits"
+            | "modulus_be_bits"
+            | "modulus_be_bytes"
+            | "modulus_le_bytes"
+            | "checked_transmute"
+    )
+}
+
 pub fn perform_instantiation_bindings(bindings: &TypeBindings) {
     for (var, _kind, binding) in bindings.values() {
         var.force_bind(binding.clone());
@@ -267,6 +267,14 @@ pub struct NodeInterner {
 
     /// Captures the documentation comments for each module, struct, trait, function, etc.
     pub(crate) doc_comments: HashMap<ReferenceId, Vec<String>>,
+
+    /// The oracle name of every `#[oracle(name)]` function, alongside the location of the
+    /// function's name.
+    pub(crate) oracles: Vec<(String, Location)>,
+
+    /// The builtin name of every non-comptime `#[builtin(name)]` function, alongside the
+    /// location of the function's name.
+    pub(crate) builtins: Vec<(String, Location)>,
 }
 
 /// A dependency in the dependency graph may be a type or a definition.
@@ -680,6 +688,8 @@ impl Default for NodeInterner {
             comptime_scopes: vec![HashMap::default()],
             trait_impl_associated_types: HashMap::default(),
             doc_comments: HashMap::default(),
+            oracles: Vec::new(),
+            builtins: Vec::new(),
         }
     }
 }
@@ -2249,6 +2259,25 @@ impl NodeInterner {
         self.doc_comments.get(&id)
     }
 
+    pub fn add_oracle(&mut self, name: String, location: Location) {
+        self.oracles.push((name, location));
+    }
+
+    /// Returns the oracle name and location of every `#[oracle(name)]` function collected so far.
+    pub fn oracles(&self) -> impl Iterator<Item = (&str, Location)> {
+        self.oracles.iter().map(|(name, location)| (name.as_str(), *location))
+    }
+
+    pub fn add_builtin(&mut self, name: String, location: Location) {
+        self.builtins.push((name, location));
+    }
+
+    /// Returns the builtin name and location of every non-comptime `#[builtin(name)]` function
+    /// collected so far.
+    pub fn builtins(&self) -> impl Iterator<Item = (&str, Location)> {
+        self.builtins.iter().map(|(name, location)| (name.as_str(), *location))
+    }
+
     pub fn get_expr_id_from_index(&self, index: impl Into<Index>) -> Option<ExprId> {
         let index = index.into();
         match self.nodes.get(index) {
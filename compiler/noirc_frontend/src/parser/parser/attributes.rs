@@ -50,7 +50,6 @@ impl<'a> Parser<'a> {
     ///     | 'inline_always'
     ///     | 'no_predicates'
     ///     | 'oracle' '(' AttributeValue ')'
-    ///     | 'recursive'
     ///     | 'test'
     ///     | 'test' '(' 'should_fail' ')'
     ///     | 'test' '(' 'should_fail_with' '=' string ')'
@@ -63,6 +62,7 @@ impl<'a> Parser<'a> {
     ///     | 'contract_library_method'
     ///     | 'export'
     ///     | 'field' '(' AttributeValue ')'
+    ///     | 'unchecked_arithmetic'
     ///     | 'use_callers_scope'
     ///     | 'varargs'
     ///     | MetaAttribute
@@ -185,6 +185,9 @@ impl<'a> Parser<'a> {
             "builtin" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
                 Attribute::Function(FunctionAttribute::Builtin(name))
             }),
+            "deny" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
+                Attribute::Secondary(SecondaryAttribute::Deny(name))
+            }),
             "deprecated" => self.parse_deprecated_attribute(ident, arguments),
             "contract_library_method" => {
                 let attr = Attribute::Secondary(SecondaryAttribute::ContractLibraryMethod);
@@ -215,6 +218,10 @@ impl<'a> Parser<'a> {
             "oracle" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
                 Attribute::Function(FunctionAttribute::Oracle(name))
             }),
+            "unchecked_arithmetic" => {
+                let attr = Attribute::Secondary(SecondaryAttribute::UncheckedArithmetic);
+                self.parse_no_args_attribute(ident, arguments, attr)
+            }
             "use_callers_scope" => {
                 let attr = Attribute::Secondary(SecondaryAttribute::UseCallersScope);
                 self.parse_no_args_attribute(ident, arguments, attr)
@@ -521,6 +528,13 @@ mod tests {
         parse_attribute_no_errors(src, expected);
     }
 
+    #[test]
+    fn parses_attribute_unchecked_arithmetic() {
+        let src = "#[unchecked_arithmetic]";
+        let expected = Attribute::Secondary(SecondaryAttribute::UncheckedArithmetic);
+        parse_attribute_no_errors(src, expected);
+    }
+
     #[test]
     fn parses_attribute_field() {
         let src = "#[field(bn254)]";
@@ -542,6 +556,13 @@ mod tests {
         parse_attribute_no_errors(src, expected);
     }
 
+    #[test]
+    fn parses_attribute_deny() {
+        let src = "#[deny(unused_imports)]";
+        let expected = Attribute::Secondary(SecondaryAttribute::Deny("unused_imports".to_string()));
+        parse_attribute_no_errors(src, expected);
+    }
+
     #[test]
     fn parses_attribute_test_no_scope() {
         let src = "#[test]";
@@ -3,14 +3,14 @@ use noirc_errors::Span;
 use crate::{
     ast::{Ident, ItemVisibility, ModuleDeclaration},
     parser::{ItemKind, ParsedSubModule},
-    token::{Attribute, Token},
+    token::{Attribute, Keyword, Token},
 };
 
 use super::Parser;
 
 impl<'a> Parser<'a> {
     /// ModOrContract
-    ///     = ( 'mod' | 'contract' ) identifier ( '{' Module '}' | ';' )
+    ///     = ( 'mod' | 'contract' ) identifier ( '{' Module '}' | ( 'as' identifier )? ';' )
     pub(super) fn parse_mod_or_contract(
         &mut self,
         attributes: Vec<(Attribute, Span)>,
@@ -24,6 +24,7 @@ impl<'a> Parser<'a> {
             return ItemKind::ModuleDecl(ModuleDeclaration {
                 visibility,
                 ident: Ident::default(),
+                alias: None,
                 outer_attributes,
             });
         };
@@ -41,10 +42,20 @@ impl<'a> Parser<'a> {
                 is_contract,
             })
         } else {
+            let alias = if self.eat_keyword(Keyword::As) {
+                let alias = self.eat_ident();
+                if alias.is_none() {
+                    self.expected_identifier();
+                }
+                alias
+            } else {
+                None
+            };
+
             if !self.eat_semicolons() {
                 self.expected_token(Token::Semicolon);
             }
-            ItemKind::ModuleDecl(ModuleDeclaration { visibility, ident, outer_attributes })
+            ItemKind::ModuleDecl(ModuleDeclaration { visibility, ident, alias, outer_attributes })
         }
     }
 }
@@ -68,6 +79,22 @@ mod tests {
             panic!("Expected module declaration");
         };
         assert_eq!("foo", module.ident.to_string());
+        assert!(module.alias.is_none());
+    }
+
+    #[test]
+    fn parse_module_declaration_with_alias() {
+        let src = "mod foo as bar;";
+        let (module, errors) = parse_program(src);
+        expect_no_errors(&errors);
+        assert_eq!(module.items.len(), 1);
+        let item = &module.items[0];
+        let ItemKind::ModuleDecl(module) = &item.kind else {
+            panic!("Expected module declaration");
+        };
+        assert_eq!("foo", module.ident.to_string());
+        assert_eq!("bar", module.alias.as_ref().unwrap().to_string());
+        assert_eq!("mod foo as bar", module.to_string());
     }
 
     #[test]
@@ -20,17 +20,17 @@ use std::collections::BTreeMap;
 use fm::FileId;
 
 use iter_extended::vecmap;
-use noirc_errors::Location;
+use noirc_errors::{CustomDiagnostic, Location, Span};
 
 use crate::ast::IntegerBitSize;
 use crate::hir::comptime::InterpreterError;
 use crate::hir::def_collector::dc_crate::CompilationError;
 use crate::hir::def_collector::errors::{DefCollectorErrorKind, DuplicateType};
 use crate::hir::def_map::ModuleData;
-use crate::hir::resolution::errors::ResolverError;
+use crate::hir::resolution::errors::{PubPosition, ResolverError};
 use crate::hir::resolution::import::PathResolutionError;
 use crate::hir::type_check::TypeCheckError;
-use crate::hir::Context;
+use crate::hir::{Context, FunctionNameMatch};
 use crate::node_interner::{NodeInterner, StmtId};
 
 use crate::hir::def_collector::dc_crate::DefCollector;
@@ -729,6 +729,82 @@ fn check_trait_duplicate_declaration() {
     }
 }
 
+#[test]
+fn check_trait_impl_duplicate_associated_type() {
+    let src = "
+    trait Negate {
+        type Output;
+        fn negate(self) -> Self::Output;
+    }
+
+    struct Foo {}
+
+    impl Negate for Foo {
+        type Output = Field;
+        type Output = u32;
+        fn negate(self) -> Field {
+            0
+        }
+    }
+
+    fn main() {
+        let _ = Foo {}.negate();
+    }";
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::Duplicate {
+        typ,
+        first_def,
+        second_def,
+    }) = &errors[0].0
+    else {
+        panic!("Expected a duplicate definition error, got {:?}", errors[0].0);
+    };
+    assert_eq!(typ, &DuplicateType::TraitAssociatedType);
+    assert_eq!(first_def, "Output");
+    assert_eq!(second_def, "Output");
+    assert!(first_def.span().start() < second_def.span().start());
+}
+
+/// Asserts that `src` only errors on `struct Foo` clashing with `mod Foo`.
+fn assert_struct_and_module_name_collision(src: &str) {
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::StructAndModuleNameCollision {
+        struct_name,
+        module_name,
+    }) = &errors[0].0
+    else {
+        panic!("Expected a struct and module name collision, got {:?}", errors[0].0);
+    };
+    let struct_start = src.find("struct Foo").unwrap() + "struct ".len();
+    let module_start = src.find("mod Foo").unwrap() + "mod ".len();
+    assert_eq!(struct_name.span(), Span::from(struct_start as u32..struct_start as u32 + 3));
+    assert_eq!(module_name.span(), Span::from(module_start as u32..module_start as u32 + 3));
+}
+
+#[test]
+fn errors_on_struct_declared_after_module_with_same_name() {
+    let src = "
+    mod Foo {}
+    struct Foo {}
+    fn main() {}
+    ";
+    assert_struct_and_module_name_collision(src);
+}
+
+#[test]
+fn errors_on_struct_declared_before_module_with_same_name() {
+    let src = "
+    struct Foo {}
+    mod Foo {}
+    fn main() {}
+    ";
+    assert_struct_and_module_name_collision(src);
+}
+
 #[test]
 fn check_trait_duplicate_implementation() {
     let src = "
@@ -1320,6 +1396,44 @@ fn deny_cyclic_type_aliases() {
     assert_eq!(get_program_errors(src).len(), 1);
 }
 
+#[test]
+fn deny_self_referential_struct() {
+    let src = r#"
+        pub struct A { b: A }
+        fn main() {}
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::SelfReferentialStruct { name, .. }) =
+        &errors[0].0
+    else {
+        panic!("Expected a SelfReferentialStruct error, got {:?}", errors[0].0);
+    };
+    assert_eq!(name, "A");
+
+    let diagnostic = CustomDiagnostic::from(&errors[0].0);
+    assert_eq!(diagnostic.secondaries[0].message, "'A' contains itself: A -> A");
+}
+
+#[test]
+fn deny_mutually_recursive_structs() {
+    let src = r#"
+        pub struct A { b: B }
+        pub struct B { a: A }
+        fn main() {}
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::DependencyCycle { cycle, .. }) =
+        &errors[0].0
+    else {
+        panic!("Expected a DependencyCycle error, got {:?}", errors[0].0);
+    };
+    assert!(cycle == "A -> B -> A" || cycle == "B -> A -> B", "unexpected cycle: {cycle}");
+}
+
 #[test]
 fn ensure_nested_type_aliases_type_check() {
     let src = r#"
@@ -1420,6 +1534,27 @@ fn ban_mutable_globals() {
     assert_eq!(get_program_errors(src).len(), 1);
 }
 
+#[test]
+fn ban_mutating_comptime_globals_at_runtime() {
+    // A comptime global is a constant by the time runtime code runs
+    let src = r#"
+        comptime mut global FOO: Field = 0;
+        fn main() {
+            FOO = 1;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    let CompilationError::ResolverError(ResolverError::MutatingComptimeInNonComptimeContext {
+        name,
+        ..
+    }) = &errors[0].0
+    else {
+        panic!("Expected a MutatingComptimeInNonComptimeContext error, got {:?}", errors[0].0);
+    };
+    assert_eq!(name, "FOO");
+}
+
 #[test]
 fn deny_inline_attribute_on_unconstrained() {
     let src = r#"
@@ -2052,6 +2187,26 @@ fn cast_256_to_u8_size_checks() {
     ));
 }
 
+#[test]
+fn cast_value_using_all_field_bits_warns() {
+    // 2^253 + 1 has as many bits as the field modulus
+    let src = r#"
+        fn main() {
+            let _ = 0x2000000000000000000000000000000000000000000000000000000000000001 as Field;
+            let _ = 0x1000000000000000000000000000000000000000000000000000000000000001 as Field;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::CastOutsideSafeFieldRange {
+            max_bits: 254,
+            ..
+        }),
+    ));
+}
+
 // TODO(https://github.com/noir-lang/noir/issues/6247):
 // add negative integer literal checks
 #[test]
@@ -2820,6 +2975,7 @@ fn duplicate_struct_field() {
     let CompilationError::DefinitionError(DefCollectorErrorKind::DuplicateField {
         first_def,
         second_def,
+        ..
     }) = &errors[0].0
     else {
         panic!("Expected a duplicate field error, got {:?}", errors[0].0);
@@ -2832,6 +2988,85 @@ fn duplicate_struct_field() {
     assert_eq!(second_def.span().start(), 46);
 }
 
+#[test]
+fn duplicate_struct_field_with_different_types() {
+    let src = r#"
+    pub struct S {
+        x: Field,
+        x: u32,
+    }
+
+    fn main() {}
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::DefinitionError(
+        error @ DefCollectorErrorKind::DuplicateField { first_type, second_type, .. },
+    ) = &errors[0].0
+    else {
+        panic!("Expected a duplicate field error, got {:?}", errors[0].0);
+    };
+    assert_eq!(first_type.to_string(), "Field");
+    assert_eq!(second_type.to_string(), "u32");
+
+    let diagnostic = CustomDiagnostic::from(error);
+    let labels = vecmap(&diagnostic.secondaries, |label| label.message.clone());
+    assert_eq!(
+        labels,
+        vec![
+            "First definition found here, with type `Field`",
+            "Second definition found here, with type `u32`",
+        ]
+    );
+}
+
+#[test]
+fn errors_on_main_returning_a_slice() {
+    let src = r#"
+    fn main() -> pub [Field] {
+        &[1]
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+
+    let CompilationError::TypeError(TypeCheckError::InvalidTypeForEntryPoint { span }) =
+        &errors[0].0
+    else {
+        panic!("Expected an invalid type for entry point error, got {:?}", errors[0].0);
+    };
+    let start = src.find("[Field]").unwrap() as u32;
+    assert_eq!(*span, Span::from(start..start + 7));
+}
+
+#[test]
+fn errors_on_main_with_misplaced_databus_visibilities() {
+    let src = r#"
+    fn main(x: return_data Field) -> call_data(0) Field {
+        x
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 2, "Expected 2 errors, got: {:?}", errors);
+
+    let visibilities = vecmap(&errors, |(error, _)| match error {
+        CompilationError::ResolverError(ResolverError::MisplacedDatabusVisibility {
+            ident,
+            visibility,
+            position,
+        }) => {
+            assert_eq!(ident.0.contents, "main");
+            (*visibility, position.clone())
+        }
+        _ => panic!("Expected a misplaced databus visibility error, got {error:?}"),
+    });
+    assert_eq!(
+        visibilities,
+        vec![("return_data", PubPosition::Parameter), ("call_data", PubPosition::ReturnType)]
+    );
+}
+
 #[test]
 fn trait_constraint_on_tuple_type() {
     let src = r#"
@@ -3241,6 +3476,28 @@ fn dont_infer_partial_global_types() {
     }
 }
 
+#[test]
+fn uninferred_global_type_points_at_global() {
+    let src = r#"
+        global EMPTY = [];
+
+        fn main() {
+            let _ = EMPTY;
+        }
+    "#;
+
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::UninferredGlobalType { name, span }) =
+        &errors[0].0
+    else {
+        panic!("Expected an UninferredGlobalType error, got {:?}", errors[0].0);
+    };
+    assert_eq!(name, "EMPTY");
+    assert_eq!(&src[span.start() as usize..span.end() as usize], "EMPTY");
+}
+
 #[test]
 fn u32_globals_as_sizes_in_types() {
     let src = r#"
@@ -3259,6 +3516,22 @@ fn u32_globals_as_sizes_in_types() {
     assert_eq!(errors.len(), 0);
 }
 
+#[test]
+fn globals_with_radix_prefixes_and_digit_separators() {
+    let src = r#"
+        global MILLION: u32 = 1_000_000;
+        global TEN: u32 = 0b1010;
+        global SEVEN: u32 = 0o7;
+
+        fn main() {
+            let _a: [u8; TEN] = [0; 0b_1010];
+            let _b: [u8; SEVEN] = [0; 0o7];
+            let _c: u32 = MILLION;
+        }
+    "#;
+    assert_no_errors(src);
+}
+
 #[test]
 fn struct_array_len() {
     let src = r#"
@@ -3844,6 +4117,30 @@ fn disallows_export_attribute_on_trait_impl_method() {
     });
 }
 
+#[test]
+fn disallows_test_and_export_attributes_on_same_function() {
+    let src = r#"
+        #[test]
+        #[export]
+        fn foo() { }
+
+        fn main() { }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::ConflictingAttributes {
+        first,
+        second,
+        ..
+    }) = &errors[0].0
+    else {
+        panic!("Expected a conflicting attributes error, got {:?}", errors[0].0);
+    };
+    assert_eq!(first, "#[test]");
+    assert_eq!(second, "#[export]");
+}
+
 #[test]
 fn allows_multiple_underscore_parameters() {
     let src = r#"
@@ -3978,3 +4275,107 @@ fn checks_visibility_of_trait_related_to_trait_impl_on_method_call() {
     "#;
     assert_no_errors(src);
 }
+
+#[test]
+fn errors_on_undeclared_numeric_generic_in_struct_field() {
+    let src = r#"
+    pub struct Buf<let N: u32> {
+        data: [Field; N],
+        extra: [Field; M],
+    }
+
+    fn main() {
+        let _ = Buf::<3> { data: [1, 2, 3], extra: [] };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::UndeclaredNumericGeneric {
+        name,
+        struct_name,
+    }) = &errors[0].0
+    else {
+        panic!("Expected an undeclared numeric generic error, got {:?}", errors[0].0);
+    };
+    assert_eq!(name.to_string(), "M");
+    assert_eq!(struct_name.to_string(), "Buf");
+}
+
+#[test]
+fn allows_globals_as_struct_field_lengths() {
+    let src = r#"
+    global M: u32 = 3;
+
+    pub struct Buf<let N: u32> {
+        data: [Field; N],
+        extra: [Field; M],
+        both: [Field; N + M],
+    }
+
+    fn main() {
+        let _ = Buf::<2> { data: [1, 2], extra: [1, 2, 3], both: [0; 5] };
+    }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn allows_imported_globals_as_struct_field_lengths() {
+    let src = r#"
+    pub mod consts {
+        pub global M: u32 = 3;
+    }
+
+    mod reexports {
+        pub use super::consts::M as LEN;
+    }
+
+    use reexports::LEN;
+
+    pub struct Buf {
+        data: [Field; LEN],
+    }
+
+    fn main() {
+        let _ = Buf { data: [1, 2, 3] };
+    }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn matches_test_functions_by_exact_name() {
+    let src = r#"
+    #[test]
+    fn foo() {}
+
+    #[test]
+    fn foo_bar() {}
+
+    mod bar {
+        #[test]
+        fn foo() {}
+    }
+    "#;
+    let (_, context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "Expected no errors, got: {errors:?}");
+
+    let crate_id = *context.root_crate_id();
+    let matching_names = |pattern| {
+        let mut names: Vec<_> = context
+            .get_all_test_functions_in_crate_matching(&crate_id, pattern)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(matching_names(FunctionNameMatch::Exact("foo")), vec!["foo"]);
+    assert_eq!(matching_names(FunctionNameMatch::Exact("bar::foo")), vec!["bar::foo"]);
+    assert_eq!(
+        matching_names(FunctionNameMatch::Contains("foo")),
+        vec!["bar::foo", "foo", "foo_bar"]
+    );
+}
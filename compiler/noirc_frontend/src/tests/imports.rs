@@ -154,3 +154,42 @@ fn errors_if_using_alias_in_import() {
     assert_eq!(ident.to_string(), "bar");
     assert_eq!(*kind, "type alias");
 }
+
+#[test]
+fn sibling_modules_reference_each_others_types() {
+    let src = r#"
+    pub mod foo {
+        use super::bar::Bar;
+
+        pub struct Foo {
+            pub bar: Bar,
+        }
+
+        pub fn make_foo() -> Foo {
+            Foo { bar: super::bar::make_bar() }
+        }
+    }
+
+    pub mod bar {
+        use super::foo::Foo;
+
+        pub struct Bar {
+            pub x: Field,
+        }
+
+        pub fn make_bar() -> Bar {
+            Bar { x: 1 }
+        }
+
+        pub fn unwrap(foo: Foo) -> Field {
+            foo.bar.x
+        }
+    }
+
+    fn main() {
+        let foo = foo::make_foo();
+        assert_eq(bar::unwrap(foo), 1);
+    }
+    "#;
+    assert_no_errors(src);
+}
@@ -1,3 +1,5 @@
+use noirc_errors::CustomDiagnostic;
+
 use crate::{
     hir::{def_collector::dc_crate::CompilationError, resolution::errors::ResolverError},
     tests::assert_no_errors,
@@ -336,3 +338,50 @@ fn resolves_trait_where_clause_in_the_correct_module() {
     "#;
     assert_no_errors(src);
 }
+
+#[test]
+fn errors_on_unused_import_denied_by_module_attribute() {
+    let src = r#"
+    #![deny(unused_imports)]
+
+    mod foo {
+        pub fn bar() {}
+    }
+
+    use foo::bar;
+
+    fn main() {}
+    "#;
+
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::DeniedLint { lint, warning }) = &errors[0].0
+    else {
+        panic!("Expected a denied lint error, got {:?}", errors[0].0);
+    };
+    assert_eq!(*lint, "unused_imports");
+    let ResolverError::UnusedItem { ident, item } = warning.as_ref() else {
+        panic!("Expected an unused item warning");
+    };
+    assert_eq!(ident.to_string(), "bar");
+    assert_eq!(item.item_type(), "import");
+
+    assert!(CustomDiagnostic::from(&errors[0].0).is_error());
+}
+
+#[test]
+fn silences_unused_warnings_allowed_by_enclosing_module_attribute() {
+    let src = r#"
+    mod foo {
+        #![allow(unused)]
+
+        mod bar {
+            fn baz(x: Field) {}
+        }
+    }
+
+    fn main() {}
+    "#;
+    assert_no_errors(src);
+}
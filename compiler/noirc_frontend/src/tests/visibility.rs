@@ -621,3 +621,54 @@ fn errors_if_accessing_private_struct_member_inside_function_generated_at_compti
 
     assert_eq!(ident.to_string(), "foo_inner");
 }
+
+#[test]
+fn errors_if_using_private_type_alias_from_sibling_module() {
+    let src = r#"
+    pub mod foo {
+        type Bar = Field;
+    }
+    pub mod baz {
+        use super::foo::Bar;
+
+        pub fn qux(x: Bar) -> Bar {
+            x
+        }
+    }
+    fn main() {
+        let _ = baz::qux(1);
+    }
+    "#;
+
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::PathResolutionError(
+        PathResolutionError::Private(ident),
+    )) = &errors[0].0
+    else {
+        panic!("Expected a private error, got {:?}", errors[0].0);
+    };
+
+    assert_eq!(ident.to_string(), "Bar");
+}
+
+#[test]
+fn does_not_error_if_using_pub_crate_type_alias_from_sibling_module() {
+    let src = r#"
+    pub mod foo {
+        pub(crate) type Bar = Field;
+    }
+    pub mod baz {
+        use super::foo::Bar;
+
+        pub fn qux(x: Bar) -> Bar {
+            x
+        }
+    }
+    fn main() {
+        let _ = baz::qux(1);
+    }
+    "#;
+    assert_no_errors(src);
+}
@@ -78,14 +78,89 @@ impl<F: AcirField> std::fmt::Display for PrintableValueDisplay<F> {
             }
             Self::FmtString(template, values) => {
                 let mut values_iter = values.iter();
-                write_template_replacing_interpolations(template, fmt, || {
-                    values_iter.next().and_then(|(value, typ)| to_string(value, typ))
+                write_template_replacing_interpolations(template, fmt, |interpolation| {
+                    let spec = FormatSpec::parse(interpolation);
+                    values_iter
+                        .next()
+                        .and_then(|(value, typ)| to_string_with_spec(value, typ, spec))
                 })
             }
         }
     }
 }
 
+/// How an interpolated value is formatted, as given after a `:` in its interpolation,
+/// e.g. `{x:x}` for lowercase hexadecimal or `{x:b}` for binary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FormatSpec {
+    LowerHex,
+    Binary,
+}
+
+impl FormatSpec {
+    /// Returns the format specifier at the end of an interpolation, if any.
+    fn parse(interpolation: &str) -> Option<Self> {
+        match interpolation.rsplit_once(':') {
+            Some((_, "x")) => Some(FormatSpec::LowerHex),
+            Some((_, "b")) => Some(FormatSpec::Binary),
+            _ => None,
+        }
+    }
+
+    /// Formats the unsigned big-endian integer in `bytes` in this radix, without a prefix.
+    pub fn format_be_bytes(self, bytes: &[u8]) -> String {
+        let digits: String = bytes
+            .iter()
+            .map(|byte| match self {
+                FormatSpec::LowerHex => format!("{byte:02x}"),
+                FormatSpec::Binary => format!("{byte:08b}"),
+            })
+            .collect();
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            "0".to_string()
+        } else {
+            digits.to_string()
+        }
+    }
+}
+
+impl std::fmt::Display for FormatSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatSpec::LowerHex => write!(f, "x"),
+            FormatSpec::Binary => write!(f, "b"),
+        }
+    }
+}
+
+/// Like [to_string], but formats integers and fields in the radix given by `spec`.
+/// Other values are displayed as usual.
+fn to_string_with_spec<F: AcirField>(
+    value: &PrintableValue<F>,
+    typ: &PrintableType,
+    spec: Option<FormatSpec>,
+) -> Option<String> {
+    let Some(spec) = spec else {
+        return to_string(value, typ);
+    };
+
+    let bytes = match (value, typ) {
+        // Fields are shown in full, as they don't fit in any integer type
+        (PrintableValue::Field(f), PrintableType::Field) => f.to_be_bytes(),
+        (
+            PrintableValue::Field(f),
+            PrintableType::UnsignedInteger { width } | PrintableType::SignedInteger { width },
+        ) => {
+            // Negative signed integers are shown as their two's complement, like Rust does
+            let mask = if *width >= 128 { u128::MAX } else { (1 << width) - 1 };
+            (f.to_u128() & mask).to_be_bytes().to_vec()
+        }
+        _ => return to_string(value, typ),
+    };
+    Some(spec.format_be_bytes(&bytes))
+}
+
 fn to_string<F: AcirField>(value: &PrintableValue<F>, typ: &PrintableType) -> Option<String> {
     let mut output = String::new();
     match (value, typ) {
@@ -183,10 +258,12 @@ fn to_string<F: AcirField>(value: &PrintableValue<F>, typ: &PrintableType) -> Op
     Some(output)
 }
 
+/// Writes `template`, replacing each interpolation with the string `replacement` returns
+/// for what is inside its curly braces.
 fn write_template_replacing_interpolations(
     template: &str,
     fmt: &mut std::fmt::Formatter<'_>,
-    mut replacement: impl FnMut() -> Option<String>,
+    mut replacement: impl FnMut(&str) -> Option<String>,
 ) -> std::fmt::Result {
     let mut last_index = 0; // How far we've written from the template
     let mut char_indices = template.char_indices().peekable();
@@ -225,20 +302,23 @@ fn write_template_replacing_interpolations(
             continue;
         }
 
-        // Write the interpolation
-        if let Some(string) = replacement() {
-            write!(fmt, "{}", string)?;
-        } else {
-            return Err(std::fmt::Error);
-        }
-
-        // Whatever was inside '{...}' doesn't matter, so skip until we find '}'
-        while let Some((_, char)) = char_indices.next() {
+        // Find the closing '}' to know what's inside '{...}'
+        let interpolation_start = char_index + 1;
+        let mut interpolation_end = template.len();
+        while let Some((index, char)) = char_indices.next() {
             if char == '}' {
+                interpolation_end = index;
                 last_index = char_indices.peek().map(|(index, _)| *index).unwrap_or(template.len());
                 break;
             }
         }
+
+        // Write the interpolation
+        if let Some(string) = replacement(&template[interpolation_start..interpolation_end]) {
+            write!(fmt, "{}", string)?;
+        } else {
+            return Err(std::fmt::Error);
+        }
     }
 
     write!(fmt, "{}", &template[last_index..])
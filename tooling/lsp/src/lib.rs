@@ -310,6 +310,7 @@ pub(crate) fn resolve_workspace_for_source_path(file_path: &Path) -> Result<Work
         name: crate_name,
         dependencies: BTreeMap::new(),
         expression_width: None,
+        include_dirs: Vec::new(),
     };
     let workspace = Workspace {
         root_dir: PathBuf::from(parent_folder),
@@ -0,0 +1,132 @@
+use std::{future::Future, time::Duration};
+
+use acvm::{acir::brillig::ForeignCallResult, pwg::ForeignCallWaitInfo};
+
+use super::{ForeignCallError, ForeignCallExecutor};
+
+/// Bridges an async foreign call handler, such as an oracle fetching data over the network,
+/// to the synchronous [ForeignCallExecutor] interface by blocking on a runtime until it resolves.
+///
+/// The handler is called with each foreign call and returns a future of its result.
+/// Like any other executor it can return `NoHandler` to let an enclosing layer handle the call.
+pub struct BlockingForeignCallExecutor<H> {
+    handler: H,
+    /// How long to wait for the handler before failing the call, if set.
+    timeout: Option<Duration>,
+    /// Runtime to execute the handler on.
+    /// See [bridging](https://tokio.rs/tokio/topics/bridging).
+    runtime: tokio::runtime::Runtime,
+}
+
+impl<H> BlockingForeignCallExecutor<H> {
+    pub fn new(handler: H) -> Self {
+        // Foreign calls are resolved one at a time, we don't need a concurrent thread pool.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .enable_io()
+            .build()
+            .expect("failed to build tokio runtime");
+
+        BlockingForeignCallExecutor { handler, timeout: None, runtime }
+    }
+
+    /// Fail calls which the handler takes longer than `timeout` to resolve.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl<F, H, Fut> ForeignCallExecutor<F> for BlockingForeignCallExecutor<H>
+where
+    F: Clone,
+    H: FnMut(ForeignCallWaitInfo<F>) -> Fut,
+    Fut: Future<Output = Result<ForeignCallResult<F>, ForeignCallError>>,
+{
+    /// Execute the async handler blocking the current thread.
+    /// Like [RPCForeignCallExecutor][super::rpc::RPCForeignCallExecutor], this method cannot
+    /// be called from inside a `tokio` runtime.
+    fn execute(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<F>,
+    ) -> Result<ForeignCallResult<F>, ForeignCallError> {
+        // Call the handler inside the runtime, in case it spawns tasks or sets up I/O eagerly.
+        let handler = &mut self.handler;
+        match self.timeout {
+            Some(timeout) => self
+                .runtime
+                .block_on(async {
+                    tokio::time::timeout(timeout, handler(foreign_call.clone())).await
+                })
+                .map_err(|_| ForeignCallError::Timeout(foreign_call.function.clone(), timeout))?,
+            None => self.runtime.block_on(async { handler(foreign_call.clone()).await }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use acvm::{
+        acir::brillig::{ForeignCallParam, ForeignCallResult},
+        pwg::ForeignCallWaitInfo,
+        FieldElement,
+    };
+
+    use super::BlockingForeignCallExecutor;
+    use crate::foreign_calls::{ForeignCallError, ForeignCallExecutor};
+
+    /// An oracle which echoes its inputs once the given delay has passed.
+    async fn delayed_echo(
+        delay: Duration,
+        foreign_call: ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, ForeignCallError> {
+        tokio::time::sleep(delay).await;
+        Ok(ForeignCallResult { values: foreign_call.inputs })
+    }
+
+    fn echo_call() -> ForeignCallWaitInfo<FieldElement> {
+        ForeignCallWaitInfo {
+            function: "echo".to_string(),
+            inputs: vec![ForeignCallParam::Single(1_u128.into())],
+        }
+    }
+
+    #[test]
+    fn delivers_result_of_async_handler() {
+        let mut executor =
+            BlockingForeignCallExecutor::new(|call| delayed_echo(Duration::from_millis(20), call))
+                .with_timeout(Duration::from_secs(10));
+
+        let foreign_call = echo_call();
+        let result = executor.execute(&foreign_call).unwrap();
+        assert_eq!(result, ForeignCallResult { values: foreign_call.inputs });
+    }
+
+    #[test]
+    fn calls_handler_inside_the_runtime() {
+        // `Handle::current` panics when called outside of a runtime.
+        let mut executor = BlockingForeignCallExecutor::new(|call| {
+            let handle = tokio::runtime::Handle::current();
+            let task = handle.spawn(delayed_echo(Duration::from_millis(20), call));
+            async move { task.await.expect("handler task should not panic") }
+        });
+
+        let foreign_call = echo_call();
+        let result = executor.execute(&foreign_call).unwrap();
+        assert_eq!(result, ForeignCallResult { values: foreign_call.inputs });
+    }
+
+    #[test]
+    fn fails_calls_resolving_after_timeout() {
+        let mut executor =
+            BlockingForeignCallExecutor::new(|call| delayed_echo(Duration::from_secs(10), call))
+                .with_timeout(Duration::from_millis(20));
+
+        let result = executor.execute(&echo_call());
+        assert!(
+            matches!(result, Err(ForeignCallError::Timeout(function, _)) if function == "echo")
+        );
+    }
+}
@@ -5,6 +5,8 @@ pub mod layers;
 pub mod mocker;
 pub mod print;
 
+#[cfg(feature = "rpc")]
+pub mod blocking;
 pub mod default;
 #[cfg(feature = "rpc")]
 pub mod rpc;
@@ -83,4 +85,10 @@ pub enum ForeignCallError {
 
     #[error("Assert message resolved after an unsatisfied constrain. {0}")]
     ResolvedAssertMessage(String),
+
+    #[error("Foreign call `{0}` timed out after {1:?}")]
+    Timeout(String, std::time::Duration),
+
+    #[error("Failed to write printed output. {0}")]
+    PrintOutput(std::io::Error),
 }
@@ -1,3 +1,5 @@
+use std::io::Write;
+
 use acvm::{
     acir::brillig::{ForeignCallParam, ForeignCallResult},
     pwg::ForeignCallWaitInfo,
@@ -8,12 +10,25 @@ use noirc_printable_type::{PrintableType, PrintableValueDisplay};
 
 use super::{ForeignCall, ForeignCallError, ForeignCallExecutor};
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub enum PrintOutput<'a> {
     #[default]
     None,
     Stdout,
     String(&'a mut String),
+    /// Write printed output to the given sink, for example to capture it as bytes.
+    Writer(&'a mut (dyn Write + Send)),
+}
+
+impl std::fmt::Debug for PrintOutput<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintOutput::None => write!(f, "None"),
+            PrintOutput::Stdout => write!(f, "Stdout"),
+            PrintOutput::String(string) => f.debug_tuple("String").field(string).finish(),
+            PrintOutput::Writer(_) => write!(f, "Writer"),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -54,6 +69,9 @@ impl<F: AcirField> ForeignCallExecutor<F> for PrintForeignCallExecutor<'_> {
                     PrintOutput::String(string) => {
                         string.push_str(&display_string);
                     }
+                    PrintOutput::Writer(writer) => writer
+                        .write_all(display_string.as_bytes())
+                        .map_err(ForeignCallError::PrintOutput)?,
                 }
 
                 Ok(ForeignCallResult::default())
@@ -131,3 +149,117 @@ fn fetch_printable_type<F: AcirField>(
 
     Ok(printable_type)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use acvm::{
+        acir::{brillig::ForeignCallParam, native_types::WitnessMap},
+        blackbox_solver::StubbedBlackBoxSolver,
+        pwg::ForeignCallWaitInfo,
+        FieldElement,
+    };
+    use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions};
+    use noirc_frontend::hir::{def_map::parse_file, Context};
+
+    use super::{PrintForeignCallExecutor, PrintOutput};
+    use crate::foreign_calls::ForeignCallExecutor;
+    use crate::ops::execute_program;
+
+    fn string_param(string: &str) -> ForeignCallParam<FieldElement> {
+        ForeignCallParam::Array(
+            string.bytes().map(|byte| FieldElement::from(byte as u128)).collect(),
+        )
+    }
+
+    /// The foreign call made to print the format string `template` interpolating a single
+    /// value of the given printable type, followed by a newline if `newline` is set.
+    fn print_call(
+        newline: bool,
+        template: &str,
+        value: FieldElement,
+        printable_type: &str,
+    ) -> ForeignCallWaitInfo<FieldElement> {
+        ForeignCallWaitInfo {
+            function: "print".to_string(),
+            inputs: vec![
+                ForeignCallParam::Single(FieldElement::from(newline as u128)),
+                string_param(template),
+                ForeignCallParam::Single(FieldElement::from(1_u128)),
+                ForeignCallParam::Single(value),
+                string_param(printable_type),
+                // This is a format string
+                ForeignCallParam::Single(FieldElement::from(1_u128)),
+            ],
+        }
+    }
+
+    const FIELD: &str = r#"{"kind":"field"}"#;
+    const U32: &str = r#"{"kind":"unsignedinteger","width":32}"#;
+
+    fn print(template: &str, value: FieldElement, printable_type: &str) -> String {
+        let mut output = String::new();
+        let mut executor = PrintForeignCallExecutor::new(PrintOutput::String(&mut output));
+        executor.execute(&print_call(false, template, value, printable_type)).unwrap();
+        output
+    }
+
+    #[test]
+    fn prints_to_writer() {
+        let mut output = Vec::new();
+        let mut executor = PrintForeignCallExecutor::new(PrintOutput::Writer(&mut output));
+        executor.execute(&print_call(true, "x = {x}", 5_u128.into(), FIELD)).unwrap();
+        executor.execute(&print_call(false, "x = {x}", 6_u128.into(), FIELD)).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "x = 0x05\nx = 0x06");
+    }
+
+    #[test]
+    fn prints_in_hex_and_binary() {
+        let x = FieldElement::from(0xbeef_u128);
+        assert_eq!(print("x = {x:x}", x, U32), "x = beef");
+        assert_eq!(print("x = {x:b}", x, U32), "x = 1011111011101111");
+        assert_eq!(print("x = {x}", x, U32), "x = 48879");
+
+        // Fields are printed in full, even beyond the width of any integer type
+        let x = FieldElement::from(u128::MAX) + FieldElement::from(0xbeef_u128);
+        assert_eq!(print("x = {x:x}", x, FIELD), "x = 10000000000000000000000000000beee");
+        assert_eq!(print("x = {x:x}", FieldElement::from(0_u128), FIELD), "x = 0");
+    }
+
+    #[test]
+    fn prints_format_specs_of_noir_format_strings() {
+        let source = "
+fn main() {
+    let x: u32 = 0xbeef;
+    let y: i8 = -1;
+    print(f\"{x:x} {y:b} {x}\");
+}";
+        let file_name = Path::new("main.nr");
+        let mut file_manager = file_manager_with_stdlib(Path::new(""));
+        file_manager.add_file_with_source(file_name, source.to_owned()).unwrap();
+        let parsed_files = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+            .collect();
+        let mut context = Context::new(file_manager, parsed_files);
+        let crate_id = prepare_crate(&mut context, file_name);
+        let (program, _) =
+            noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+                .expect("Expected the program to compile");
+
+        let mut output = String::new();
+        let mut executor = PrintForeignCallExecutor::new(PrintOutput::String(&mut output));
+        execute_program(
+            &program.program,
+            WitnessMap::new(),
+            &StubbedBlackBoxSolver::default(),
+            &mut executor,
+        )
+        .expect("Expected the program to execute");
+
+        assert_eq!(output, "beef 11111111 48879");
+    }
+}
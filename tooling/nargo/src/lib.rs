@@ -41,6 +41,7 @@ pub fn prepare_dependencies(
         match dep {
             Dependency::Remote { package } | Dependency::Local { package } => {
                 let crate_id = prepare_dependency(context, &package.entry_path);
+                add_include_dirs(context, crate_id, package);
                 add_dep(context, parent_crate, crate_id, dep_name.clone());
                 prepare_dependencies(context, crate_id, &package.dependencies);
             }
@@ -97,7 +98,11 @@ fn insert_all_files_for_package_into_file_manager(
         .parent()
         .unwrap_or_else(|| panic!("The entry path is expected to be a single file within a directory and so should have a parent {:?}", package.entry_path));
 
-    for entry in WalkDir::new(entry_path_parent).sort_by_file_name() {
+    // Modules may also be loaded from the package's include directories.
+    let source_dirs =
+        std::iter::once(entry_path_parent).chain(package.include_dirs.iter().map(PathBuf::as_path));
+
+    for entry in source_dirs.flat_map(|dir| WalkDir::new(dir).sort_by_file_name()) {
         let Ok(entry) = entry else {
             continue;
         };
@@ -181,8 +186,16 @@ pub fn prepare_package<'file_manager, 'parsed_files>(
     let mut context = Context::from_ref_file_manager(file_manager, parsed_files);
 
     let crate_id = prepare_crate(&mut context, &package.entry_path);
+    add_include_dirs(&mut context, crate_id, package);
 
     prepare_dependencies(&mut context, crate_id, &package.dependencies);
 
     (context, crate_id)
 }
+
+/// Makes the package's include directories available when resolving the modules of its crate.
+fn add_include_dirs(context: &mut Context, crate_id: CrateId, package: &Package) {
+    if !package.include_dirs.is_empty() {
+        context.include_dirs.insert(crate_id, package.include_dirs.clone());
+    }
+}
@@ -1,22 +1,120 @@
+use acvm::acir::circuit::OpcodeLocation;
 use acvm::compiler::CircuitSimulator;
 use noirc_driver::{CompiledProgram, ErrorsAndWarnings};
 use noirc_errors::{CustomDiagnostic, FileDiagnostic};
 
 /// Run each function through a circuit simulator to check that they are solvable.
+///
+/// If a function is not solvable, the returned diagnostic points at the first opcode which could not
+/// be simulated, using the program's debug info to recover its source location when available.
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn check_program(compiled_program: &CompiledProgram) -> Result<(), ErrorsAndWarnings> {
     for (i, circuit) in compiled_program.program.functions.iter().enumerate() {
         let mut simulator = CircuitSimulator::default();
-        if !simulator.check_circuit(circuit) {
-            let diag = FileDiagnostic {
-                file_id: fm::FileId::dummy(),
-                diagnostic: CustomDiagnostic::from_message(&format!(
-                    "Circuit \"{}\" is not solvable",
-                    compiled_program.names[i]
-                )),
+        if let Some(opcode_index) = simulator.find_unsolvable_opcode(circuit) {
+            let opcode_location = OpcodeLocation::Acir(opcode_index);
+            let primary_message =
+                format!("Circuit \"{}\" is not solvable", compiled_program.names[i]);
+            let secondary_message = format!("Opcode {opcode_location} cannot be solved");
+
+            let call_stack = compiled_program
+                .debug
+                .get(i)
+                .and_then(|debug_info| debug_info.opcode_location(&opcode_location))
+                .unwrap_or_default();
+
+            let diag = match call_stack.last() {
+                Some(location) => CustomDiagnostic::simple_error(
+                    primary_message,
+                    secondary_message,
+                    location.span,
+                )
+                .with_call_stack(call_stack.clone())
+                .in_file(location.file),
+                None => FileDiagnostic {
+                    file_id: fm::FileId::dummy(),
+                    diagnostic: CustomDiagnostic::from_message(&format!(
+                        "{primary_message}: {secondary_message}"
+                    )),
+                },
             };
             return Err(vec![diag]);
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::PathBuf};
+
+    use acvm::{
+        acir::{
+            circuit::{Circuit, Opcode, OpcodeLocation, Program},
+            native_types::{Expression, Witness},
+        },
+        AcirField, FieldElement,
+    };
+    use fm::{FileId, FileMap};
+    use noirc_driver::CompiledProgram;
+    use noirc_errors::{debug_info::DebugInfo, Location, Span};
+
+    use super::check_program;
+
+    #[test]
+    fn reports_location_of_unsolvable_opcode() {
+        let one = FieldElement::one();
+        let opcodes = vec![
+            // w2 = w1
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(1)), (-one, Witness(2))],
+                q_c: FieldElement::zero(),
+            }),
+            // w3 = w4, neither of which can be solved
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(3)), (-one, Witness(4))],
+                q_c: FieldElement::zero(),
+            }),
+        ];
+        let circuit = Circuit {
+            current_witness_index: 4,
+            opcodes,
+            private_parameters: [Witness(1)].into(),
+            ..Circuit::default()
+        };
+
+        let mut file_map = FileMap::default();
+        file_map.add_file(PathBuf::from("std.nr").into(), String::new());
+        let file = file_map.add_file(PathBuf::from("main.nr").into(), "fn main() {}".to_string());
+        assert_ne!(file, FileId::dummy());
+
+        let location = Location::new(Span::from(3..7), file);
+        let debug_info = DebugInfo {
+            locations: BTreeMap::from([(OpcodeLocation::Acir(1), vec![location])]),
+            ..DebugInfo::default()
+        };
+
+        let compiled_program = CompiledProgram {
+            noir_version: String::new(),
+            hash: 0,
+            program: Program { functions: vec![circuit], unconstrained_functions: Vec::new() },
+            abi: Default::default(),
+            debug: vec![debug_info],
+            file_map: BTreeMap::new(),
+            warnings: Vec::new(),
+            names: vec!["main".to_string()],
+            brillig_names: Vec::new(),
+        };
+
+        let errors = check_program(&compiled_program).expect_err("circuit should not be solvable");
+        assert_eq!(errors.len(), 1);
+        let error = &errors[0];
+        assert_eq!(error.file_id, file);
+        assert_eq!(error.diagnostic.message, "Circuit \"main\" is not solvable");
+        assert_eq!(error.diagnostic.secondaries.len(), 1);
+        assert_eq!(error.diagnostic.secondaries[0].message, "Opcode 1 cannot be solved");
+        assert_eq!(error.diagnostic.secondaries[0].span, location.span);
+    }
+}
@@ -37,6 +37,20 @@ struct ProgramExecutor<'a, F, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecut
     // Flag that states whether we want to profile the VM. Profiling can add extra
     // execution costs so we want to make sure we only trigger it explicitly.
     profiling_active: bool,
+
+    // The opcode where execution failed and the witness of its circuit solved up to that point.
+    // Only the innermost failing circuit is recorded when the failure propagates up through ACIR calls.
+    failure_state: Option<(ResolvedOpcodeLocation, WitnessMap<F>)>,
+}
+
+/// The state of an execution which stopped at its first failure.
+#[derive(Debug)]
+pub struct PartialExecution<F: AcirField> {
+    pub error: NargoError<F>,
+    /// The opcode at which execution stopped.
+    pub failing_opcode: ResolvedOpcodeLocation,
+    /// The witness of the circuit containing `failing_opcode`, as solved before it failed.
+    pub partial_witness: WitnessMap<F>,
 }
 
 impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
@@ -58,6 +72,18 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
             call_stack: Vec::default(),
             current_function_index: 0,
             profiling_active,
+            failure_state: None,
+        }
+    }
+
+    /// Records the current opcode and witness of a failing circuit, unless a nested call already failed.
+    fn record_failure(&mut self, instruction_pointer: usize, witness: &WitnessMap<F>) {
+        if self.failure_state.is_none() {
+            let failing_opcode = ResolvedOpcodeLocation {
+                acir_function_index: self.current_function_index,
+                opcode_location: OpcodeLocation::Acir(instruction_pointer),
+            };
+            self.failure_state = Some((failing_opcode, witness.clone()));
         }
     }
 
@@ -89,6 +115,8 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
                     unreachable!("Execution should not stop while in `InProgress` state.")
                 }
                 ACVMStatus::Failure(error) => {
+                    self.record_failure(acvm.instruction_pointer(), acvm.witness_map());
+
                     let call_stack = match &error {
                         OpcodeResolutionError::UnsatisfiedConstrain {
                             opcode_location: ErrorLocation::Resolved(opcode_location),
@@ -146,7 +174,14 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
                     }));
                 }
                 ACVMStatus::RequiresForeignCall(foreign_call) => {
-                    let foreign_call_result = self.foreign_call_executor.execute(&foreign_call)?;
+                    let foreign_call_result =
+                        match self.foreign_call_executor.execute(&foreign_call) {
+                            Ok(foreign_call_result) => foreign_call_result,
+                            Err(error) => {
+                                self.record_failure(acvm.instruction_pointer(), acvm.witness_map());
+                                return Err(error.into());
+                            }
+                        };
                     acvm.resolve_pending_foreign_call(foreign_call_result);
                 }
                 ACVMStatus::RequiresAcirCall(call_info) => {
@@ -176,6 +211,7 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
                         {
                             call_resolved_outputs.push(*return_value);
                         } else {
+                            self.record_failure(acvm.instruction_pointer(), acvm.witness_map());
                             return Err(ExecutionError::SolvingError(
                                 OpcodeNotSolvable::MissingAssignment(return_witness_index).into(),
                                 None, // Missing assignment errors do not supply user-facing diagnostics so we do not need to attach a call stack
@@ -198,6 +234,10 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
     }
 }
 
+/// Executes the program, returning the witness solved for each circuit call.
+///
+/// Each witness holds every witness solved in its circuit, not only the ones in the ABI,
+/// so intermediate values are available for debugging.
 pub fn execute_program<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>(
     program: &Program<F>,
     initial_witness: WitnessMap<F>,
@@ -217,6 +257,40 @@ pub fn execute_program<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCal
     Ok(witness_stack)
 }
 
+/// Executes the program like [execute_program], but on failure also returns the witness solved
+/// so far by the failing circuit, along with the opcode at which it failed.
+pub fn execute_program_with_partial_witness<
+    F: AcirField,
+    B: BlackBoxFunctionSolver<F>,
+    E: ForeignCallExecutor<F>,
+>(
+    program: &Program<F>,
+    initial_witness: WitnessMap<F>,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut E,
+) -> Result<WitnessStack<F>, Box<PartialExecution<F>>> {
+    let profiling_active = false;
+    let mut executor = ProgramExecutor::new(
+        &program.functions,
+        &program.unconstrained_functions,
+        blackbox_solver,
+        foreign_call_executor,
+        profiling_active,
+    );
+    match executor.execute_circuit(initial_witness) {
+        Ok((main_witness, _)) => {
+            executor.witness_stack.push(0, main_witness);
+            Ok(executor.finalize())
+        }
+        Err(error) => {
+            let (failing_opcode, partial_witness) = executor
+                .failure_state
+                .expect("Should have recorded the state of a failed execution");
+            Err(Box::new(PartialExecution { error, failing_opcode, partial_witness }))
+        }
+    }
+}
+
 pub fn execute_program_with_profiling<
     F: AcirField,
     B: BlackBoxFunctionSolver<F>,
@@ -257,3 +331,100 @@ fn execute_program_inner<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignC
 
     Ok((executor.finalize(), profiling_samples))
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::{
+        acir::{
+            circuit::{Circuit, Opcode, OpcodeLocation, Program},
+            native_types::{Expression, Witness, WitnessMap},
+        },
+        blackbox_solver::StubbedBlackBoxSolver,
+        AcirField, FieldElement,
+    };
+
+    use crate::foreign_calls::DefaultForeignCallBuilder;
+
+    use super::{execute_program, execute_program_with_partial_witness};
+
+    #[test]
+    fn returns_intermediate_witnesses() {
+        let one = FieldElement::one();
+        let opcodes = vec![
+            // w3 = w1 + w2, an intermediate witness
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(1)), (one, Witness(2)), (-one, Witness(3))],
+                q_c: FieldElement::zero(),
+            }),
+            // w4 = w3 * w3, the return value
+            Opcode::AssertZero(Expression {
+                mul_terms: vec![(one, Witness(3), Witness(3))],
+                linear_combinations: vec![(-one, Witness(4))],
+                q_c: FieldElement::zero(),
+            }),
+        ];
+        let circuit = Circuit { current_witness_index: 4, opcodes, ..Circuit::default() };
+        let program = Program { functions: vec![circuit], unconstrained_functions: Vec::new() };
+
+        let initial_witness = WitnessMap::from(std::collections::BTreeMap::from([
+            (Witness(1), FieldElement::from(2_u128)),
+            (Witness(2), FieldElement::from(3_u128)),
+        ]));
+        let mut witness_stack = execute_program(
+            &program,
+            initial_witness,
+            &StubbedBlackBoxSolver::default(),
+            &mut DefaultForeignCallBuilder::default().build(),
+        )
+        .expect("Execution should succeed");
+
+        let main_witness = witness_stack.pop().expect("Expected the witness of main").witness;
+        assert_eq!(main_witness[&Witness(3)], FieldElement::from(5_u128));
+        assert_eq!(main_witness[&Witness(4)], FieldElement::from(25_u128));
+    }
+
+    #[test]
+    fn returns_partial_witness_on_failure() {
+        let one = FieldElement::one();
+        let opcodes = vec![
+            // w3 = w1 + w2
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(1)), (one, Witness(2)), (-one, Witness(3))],
+                q_c: FieldElement::zero(),
+            }),
+            // w3 == 10, which fails
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(3))],
+                q_c: -FieldElement::from(10_u128),
+            }),
+            // w4 = w3, which is never reached
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![(one, Witness(3)), (-one, Witness(4))],
+                q_c: FieldElement::zero(),
+            }),
+        ];
+        let circuit = Circuit { current_witness_index: 4, opcodes, ..Circuit::default() };
+        let program = Program { functions: vec![circuit], unconstrained_functions: Vec::new() };
+
+        let initial_witness = WitnessMap::from(std::collections::BTreeMap::from([
+            (Witness(1), FieldElement::from(2_u128)),
+            (Witness(2), FieldElement::from(3_u128)),
+        ]));
+        let partial = execute_program_with_partial_witness(
+            &program,
+            initial_witness,
+            &StubbedBlackBoxSolver::default(),
+            &mut DefaultForeignCallBuilder::default().build(),
+        )
+        .expect_err("Execution should fail on the second opcode");
+
+        assert_eq!(partial.failing_opcode.acir_function_index, 0);
+        assert_eq!(partial.failing_opcode.opcode_location, OpcodeLocation::Acir(1));
+        assert_eq!(partial.partial_witness[&Witness(3)], FieldElement::from(5_u128));
+        assert!(!partial.partial_witness.contains_key(&Witness(4)));
+    }
+}
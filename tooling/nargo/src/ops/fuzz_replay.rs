@@ -0,0 +1,231 @@
+use std::fmt;
+
+use acvm::{acir::native_types::WitnessStack, BlackBoxFunctionSolver, FieldElement};
+use noirc_abi::{errors::AbiError, input_parser::InputValue, InputMap};
+use noirc_driver::{
+    compile_no_check, CompileError, CompileOptions, CompiledProgram, DEFAULT_EXPRESSION_WIDTH,
+};
+use noirc_frontend::{hir::Context, node_interner::FuncId};
+
+use crate::{foreign_calls::ForeignCallExecutor, NargoError};
+
+use super::execute_program;
+
+/// A fuzzing harness compiled once for each executor, so an input can be replayed through both.
+pub struct ReplayPrograms {
+    pub acir: CompiledProgram,
+    pub brillig: CompiledProgram,
+}
+
+/// The result of executing a program on a single input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayOutcome {
+    /// Execution succeeded and produced the given return value, if any.
+    Returned(Option<InputValue>),
+    /// Execution failed with the given message.
+    Failed(String),
+}
+
+impl fmt::Display for ReplayOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayOutcome::Returned(Some(value)) => write!(f, "returned {value:?}"),
+            ReplayOutcome::Returned(None) => write!(f, "returned nothing"),
+            ReplayOutcome::Failed(message) => write!(f, "failed: {message}"),
+        }
+    }
+}
+
+/// The outcomes of replaying the same input through the ACIR and Brillig executors.
+#[derive(Debug)]
+pub struct FuzzReplay {
+    pub acir: ReplayOutcome,
+    pub brillig: ReplayOutcome,
+}
+
+impl FuzzReplay {
+    /// True if the executors disagree on the return value, or only one of them failed.
+    ///
+    /// Failure messages are not compared as the executors report the same failure differently.
+    pub fn diverged(&self) -> bool {
+        match (&self.acir, &self.brillig) {
+            (ReplayOutcome::Returned(acir), ReplayOutcome::Returned(brillig)) => acir != brillig,
+            (ReplayOutcome::Failed(_), ReplayOutcome::Failed(_)) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Shows both outcomes side by side, highlighting whether they diverge.
+impl fmt::Display for FuzzReplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let marker = if self.diverged() { ">>" } else { "  " };
+        writeln!(f, "{marker} acir    | {}", self.acir)?;
+        writeln!(f, "{marker} brillig | {}", self.brillig)?;
+        if self.diverged() {
+            write!(f, "The ACIR and Brillig executors diverge on this input")
+        } else {
+            write!(f, "The ACIR and Brillig executors agree on this input")
+        }
+    }
+}
+
+/// Compiles the given function twice: once as requested by `options`, and once forced into Brillig.
+pub fn compile_for_replay(
+    context: &mut Context,
+    function_id: FuncId,
+    options: &CompileOptions,
+) -> Result<ReplayPrograms, CompileError> {
+    let target_width = options.expression_width.unwrap_or(DEFAULT_EXPRESSION_WIDTH);
+
+    let acir_options = CompileOptions { force_brillig: false, ..options.clone() };
+    let acir = compile_no_check(context, &acir_options, function_id, None, false)?;
+    let acir = crate::ops::transform_program(acir, target_width);
+
+    let brillig_options = CompileOptions { force_brillig: true, ..options.clone() };
+    let brillig = compile_no_check(context, &brillig_options, function_id, None, false)?;
+    let brillig = crate::ops::transform_program(brillig, target_width);
+
+    Ok(ReplayPrograms { acir, brillig })
+}
+
+/// Executes both programs on `inputs`, building a fresh foreign call executor for each run.
+pub fn replay_fuzzing_input<B, E>(
+    blackbox_solver: &B,
+    programs: &ReplayPrograms,
+    inputs: &InputMap,
+    mut build_foreign_call_executor: impl FnMut() -> E,
+) -> Result<FuzzReplay, AbiError>
+where
+    B: BlackBoxFunctionSolver<FieldElement>,
+    E: ForeignCallExecutor<FieldElement>,
+{
+    let mut replay = |program: &CompiledProgram| -> Result<ReplayOutcome, AbiError> {
+        let initial_witness = program.abi.encode(inputs, None)?;
+        let execution = execute_program(
+            &program.program,
+            initial_witness,
+            blackbox_solver,
+            &mut build_foreign_call_executor(),
+        );
+        replay_outcome(program, execution)
+    };
+
+    Ok(FuzzReplay { acir: replay(&programs.acir)?, brillig: replay(&programs.brillig)? })
+}
+
+fn replay_outcome(
+    program: &CompiledProgram,
+    execution: Result<WitnessStack<FieldElement>, NargoError<FieldElement>>,
+) -> Result<ReplayOutcome, AbiError> {
+    match execution {
+        Ok(witness_stack) => {
+            let main_witness = &witness_stack
+                .peek()
+                .expect("Should have at least one witness on the stack")
+                .witness;
+            let (_, return_value) = program.abi.decode(main_witness)?;
+            Ok(ReplayOutcome::Returned(return_value))
+        }
+        Err(error) => {
+            let message = error
+                .user_defined_failure_message(&program.abi.error_types)
+                .unwrap_or_else(|| error.to_string());
+            Ok(ReplayOutcome::Failed(message))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use acvm::{blackbox_solver::StubbedBlackBoxSolver, FieldElement};
+    use noirc_abi::{input_parser::InputValue, InputMap};
+    use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions};
+    use noirc_frontend::hir::{def_map::parse_file, Context};
+
+    use super::{compile_for_replay, replay_fuzzing_input, FuzzReplay, ReplayOutcome};
+    use crate::foreign_calls::print::{PrintForeignCallExecutor, PrintOutput};
+
+    fn returned(value: u128) -> ReplayOutcome {
+        ReplayOutcome::Returned(Some(InputValue::Field(FieldElement::from(value))))
+    }
+
+    #[test]
+    fn matching_outcomes_do_not_diverge() {
+        let replay = FuzzReplay { acir: returned(3), brillig: returned(3) };
+        assert!(!replay.diverged());
+        assert!(replay.to_string().ends_with("agree on this input"));
+
+        let replay = FuzzReplay {
+            acir: ReplayOutcome::Failed("Failed constraint".to_string()),
+            brillig: ReplayOutcome::Failed("Failed assertion".to_string()),
+        };
+        assert!(!replay.diverged());
+    }
+
+    #[test]
+    fn highlights_divergent_outcomes() {
+        let replay = FuzzReplay {
+            acir: returned(3),
+            brillig: ReplayOutcome::Failed("Failed assertion".to_string()),
+        };
+        assert!(replay.diverged());
+
+        let lines: Vec<_> = replay.to_string().lines().map(str::to_string).collect();
+        assert!(lines[0].starts_with(">> acir    | returned"));
+        assert_eq!(lines[1], ">> brillig | failed: Failed assertion");
+        assert_eq!(lines[2], "The ACIR and Brillig executors diverge on this input");
+
+        let replay = FuzzReplay { acir: returned(3), brillig: returned(4) };
+        assert!(replay.diverged());
+    }
+
+    #[test]
+    fn replays_stored_inputs_through_both_executors() {
+        let source = "
+fn main(x: u32, y: u32) -> pub u32 {
+    assert(y != 0, \"y must not be zero\");
+    x / y
+}";
+        let file_name = Path::new("main.nr");
+        let mut file_manager = file_manager_with_stdlib(Path::new(""));
+        file_manager.add_file_with_source(file_name, source.to_owned()).unwrap();
+        let parsed_files = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+            .collect();
+        let mut context = Context::new(file_manager, parsed_files);
+        let crate_id = prepare_crate(&mut context, file_name);
+        let options = CompileOptions::default();
+        noirc_driver::check_crate(&mut context, crate_id, &options)
+            .expect("Expected the program to type check");
+        let main = context.get_main_function(&crate_id).expect("Expected a main function");
+        let programs = compile_for_replay(&mut context, main, &options)
+            .expect("Expected the program to compile");
+
+        let replay = |x: u128, y: u128| {
+            let inputs = InputMap::from([
+                ("x".to_string(), InputValue::Field(FieldElement::from(x))),
+                ("y".to_string(), InputValue::Field(FieldElement::from(y))),
+            ]);
+            replay_fuzzing_input(&StubbedBlackBoxSolver::default(), &programs, &inputs, || {
+                PrintForeignCallExecutor::new(PrintOutput::None)
+            })
+            .expect("Expected the inputs to match the ABI")
+        };
+
+        let replay_passing = replay(6, 3);
+        assert_eq!(replay_passing.acir, returned(2));
+        assert_eq!(replay_passing.brillig, returned(2));
+        assert!(!replay_passing.diverged());
+
+        let replay_failing = replay(6, 0);
+        let failure = ReplayOutcome::Failed("y must not be zero".to_string());
+        assert_eq!(replay_failing.acir, failure);
+        assert_eq!(replay_failing.brillig, failure);
+        assert!(!replay_failing.diverged());
+    }
+}
@@ -6,12 +6,19 @@ pub use self::compile::{
 pub use self::optimize::{optimize_contract, optimize_program};
 pub use self::transform::{transform_contract, transform_program};
 
-pub use self::execute::{execute_program, execute_program_with_profiling};
+pub use self::execute::{
+    execute_program, execute_program_with_partial_witness, execute_program_with_profiling,
+    PartialExecution,
+};
+pub use self::fuzz_replay::{
+    compile_for_replay, replay_fuzzing_input, FuzzReplay, ReplayOutcome, ReplayPrograms,
+};
 pub use self::test::{run_test, TestStatus};
 
 mod check;
 mod compile;
 mod execute;
+mod fuzz_replay;
 mod optimize;
 mod test;
 mod transform;
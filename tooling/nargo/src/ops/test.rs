@@ -53,6 +53,9 @@ where
         .0
         .is_empty();
 
+    // Tests are expected to print, so their prints are never reported.
+    let config = &CompileOptions { warn_on_print: false, ..config.clone() };
+
     match compile_no_check(context, config, test_function.get_id(), None, false) {
         Ok(compiled_program) => {
             // Do the same optimizations as `compile_cmd`.
@@ -98,11 +101,13 @@ where
             } else {
                 use acvm::acir::circuit::Program;
                 use noir_fuzzer::FuzzedExecutor;
-                use proptest::test_runner::Config;
+                use proptest::prelude::RngCore;
                 use proptest::test_runner::TestRunner;
 
-                let runner =
-                    TestRunner::new(Config { failure_persistence: None, ..Config::default() });
+                // Failures are reported along with the seed, so a random one can be reused to reproduce them.
+                let seed =
+                    config.fuzz_seed.unwrap_or_else(|| TestRunner::default().rng().next_u64());
+                let runner = fuzzing_test_runner(seed);
 
                 let abi = compiled_program.abi.clone();
                 let debug = compiled_program.debug.clone();
@@ -145,8 +150,11 @@ where
                 if result.success {
                     TestStatus::Pass
                 } else {
+                    let reason = result.reason.unwrap_or_default();
                     TestStatus::Fail {
-                        message: result.reason.unwrap_or_default(),
+                        message: format!(
+                            "{reason}\nRerun with `--fuzz-seed {seed}` to reproduce this failure"
+                        ),
                         error_diagnostic: None,
                     }
                 }
@@ -156,6 +164,18 @@ where
     }
 }
 
+/// Creates the runner fuzzing a test which takes arguments.
+///
+/// The same seed always generates the same sequence of inputs.
+fn fuzzing_test_runner(seed: u64) -> proptest::test_runner::TestRunner {
+    use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+    let mut seed_bytes = [0; 32];
+    seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &seed_bytes);
+    TestRunner::new_with_rng(Config { failure_persistence: None, ..Config::default() }, rng)
+}
+
 /// Test function failed to compile
 ///
 /// Note: This could be because the compiler was able to deduce
@@ -289,3 +309,56 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, path::Path};
+
+    use acvm::{
+        acir::{
+            circuit::Program,
+            native_types::{WitnessMap, WitnessStack},
+        },
+        FieldElement,
+    };
+    use noir_fuzzer::FuzzedExecutor;
+    use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions};
+    use noirc_frontend::hir::{def_map::parse_file, Context};
+
+    use super::fuzzing_test_runner;
+
+    #[test]
+    fn fuzzing_with_the_same_seed_is_reproducible() {
+        let source = "fn main(x: Field, y: [u8; 2]) {}";
+        let file_name = Path::new("main.nr");
+        let mut file_manager = file_manager_with_stdlib(Path::new(""));
+        file_manager.add_file_with_source(file_name, source.to_owned()).unwrap();
+        let parsed_files = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+            .collect();
+        let mut context = Context::new(file_manager, parsed_files);
+        let crate_id = prepare_crate(&mut context, file_name);
+        let (program, _) =
+            noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+                .expect("Expected the program to compile");
+
+        // Fuzzes the program with a harness which always fails, recording every input it is run on
+        let fuzz = |seed| {
+            let inputs = RefCell::new(Vec::new());
+            let executor = |_: &Program<FieldElement>,
+                            initial_witness: WitnessMap<FieldElement>|
+             -> Result<WitnessStack<FieldElement>, String> {
+                inputs.borrow_mut().push(initial_witness);
+                Err("always fails".to_string())
+            };
+            let fuzzer =
+                FuzzedExecutor::new(program.clone().into(), executor, fuzzing_test_runner(seed));
+            let counterexample = fuzzer.fuzz().counterexample;
+            (counterexample.expect("Expected a counterexample"), inputs.into_inner())
+        };
+
+        assert_eq!(fuzz(42), fuzz(42));
+    }
+}
@@ -53,6 +53,8 @@ pub struct Package {
     pub name: CrateName,
     pub dependencies: BTreeMap<CrateName, Dependency>,
     pub expression_width: Option<ExpressionWidth>,
+    /// Directories searched for the files of modules not found next to the file declaring them
+    pub include_dirs: Vec<PathBuf>,
 }
 
 impl Package {
@@ -7,13 +7,13 @@ use fm::FileManager;
 use nargo::ops::{collect_errors, compile_contract, compile_program, report_errors};
 use nargo::package::Package;
 use nargo::workspace::Workspace;
-use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all, prepare_package};
 use nargo_toml::{
     get_package_manifest, resolve_workspace_from_toml, ManifestError, PackageSelection,
 };
 use noirc_driver::DEFAULT_EXPRESSION_WIDTH;
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
-use noirc_driver::{CompilationResult, CompileOptions, CompiledContract};
+use noirc_driver::{check_crate, CompilationResult, CompileOptions, CompiledContract};
 
 use clap::Args;
 use noirc_frontend::hir::ParsedFiles;
@@ -38,19 +38,44 @@ pub(crate) struct CompileCommand {
     /// Watch workspace and recompile on changes.
     #[clap(long, hide = true)]
     watch: bool,
+
+    /// Only check the program for errors, stopping after name resolution and type checking.
+    /// No SSA is generated and no artifacts are written.
+    #[clap(long, conflicts_with = "watch")]
+    check_only: bool,
+
+    /// Print the debug information of each compiled program: the source locations of each opcode,
+    /// the `main` parameter held by each witness and the type of each error selector.
+    #[clap(long, conflicts_with_all = ["watch", "check_only"])]
+    dump_debug_info: bool,
 }
 
 pub(crate) fn run(args: CompileCommand, config: NargoConfig) -> Result<(), CliError> {
     let selection = args.package_options.package_selection();
     let workspace = read_workspace(&config.program_dir, selection)?;
 
-    if args.watch {
+    if args.check_only {
+        check_workspace_full(&workspace, &args.compile_options)?;
+    } else if args.watch {
         watch_workspace(&workspace, &args.compile_options)
             .map_err(|err| CliError::Generic(err.to_string()))?;
     } else {
         compile_workspace_full(&workspace, &args.compile_options)?;
     }
 
+    if args.dump_debug_info {
+        dump_debug_info(&workspace)?;
+    }
+
+    Ok(())
+}
+
+/// Print the debug information of the compiled binary packages in the workspace.
+fn dump_debug_info(workspace: &Workspace) -> Result<(), CliError> {
+    for package in workspace.into_iter().filter(|package| package.is_binary()) {
+        let program = read_program_from_file(workspace.package_build_path(package))?;
+        println!("{}:\n{}", package.name, program.dump_debug_info());
+    }
     Ok(())
 }
 
@@ -143,6 +168,26 @@ pub(super) fn compile_workspace_full(
     Ok(())
 }
 
+/// Parse and type check the entire workspace without compiling it, then report errors.
+fn check_workspace_full(
+    workspace: &Workspace,
+    compile_options: &CompileOptions,
+) -> Result<(), CliError> {
+    let (workspace_file_manager, parsed_files) = parse_workspace(workspace);
+
+    let checked_workspace =
+        check_workspace(&workspace_file_manager, &parsed_files, workspace, compile_options);
+
+    report_errors(
+        checked_workspace,
+        &workspace_file_manager,
+        compile_options.deny_warnings,
+        compile_options.silence_warnings,
+    )?;
+
+    Ok(())
+}
+
 /// Compile binary and contract packages.
 /// Returns the merged warnings or errors.
 fn compile_workspace(
@@ -181,6 +226,28 @@ fn compile_workspace(
     }
 }
 
+/// Check binary and contract packages for errors without compiling them.
+/// Returns the merged warnings or errors.
+fn check_workspace(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    compile_options: &CompileOptions,
+) -> CompilationResult<()> {
+    let packages: Vec<_> =
+        workspace.into_iter().filter(|package| !package.is_library()).cloned().collect();
+
+    let results: Vec<CompilationResult<()>> = packages
+        .par_iter()
+        .map(|package| {
+            let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+            check_crate(&mut context, crate_id, compile_options)
+        })
+        .collect();
+
+    collect_errors(results).map(|(_, warnings)| ((), warnings))
+}
+
 /// Compile the given binary packages in the workspace.
 fn compile_programs(
     file_manager: &FileManager,
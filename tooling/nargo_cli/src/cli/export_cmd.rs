@@ -1,27 +1,23 @@
-use nargo::errors::CompileError;
 use nargo::ops::report_errors;
-use noirc_errors::FileDiagnostic;
 use noirc_frontend::hir::ParsedFiles;
 use rayon::prelude::*;
 
 use fm::FileManager;
-use iter_extended::try_vecmap;
 use nargo::package::Package;
 use nargo::prepare_package;
 use nargo::workspace::Workspace;
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml};
 use noirc_driver::{
-    compile_no_check, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
+    compile_exported_functions, CompileOptions, ExportedFunctionSchema,
+    NOIR_ARTIFACT_VERSION_STRING,
 };
 
 use clap::Args;
 
 use crate::errors::CliError;
 
-use super::check_cmd::check_crate_and_report_errors;
-
-use super::fs::program::save_program_to_file;
+use super::fs::program::{save_export_schema_to_file, save_program_to_file};
 use super::{NargoConfig, PackageOptions};
 
 /// Exports functions marked with #[export] attribute
@@ -53,7 +49,7 @@ pub(crate) fn run(args: ExportCommand, config: NargoConfig) -> Result<(), CliErr
     library_packages
         .par_iter()
         .map(|package| {
-            compile_exported_functions(
+            export_package(
                 &workspace_file_manager,
                 &parsed_files,
                 &workspace,
@@ -64,7 +60,7 @@ pub(crate) fn run(args: ExportCommand, config: NargoConfig) -> Result<(), CliErr
         .collect()
 }
 
-fn compile_exported_functions(
+fn export_package(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
     workspace: &Workspace,
@@ -72,31 +68,21 @@ fn compile_exported_functions(
     compile_options: &CompileOptions,
 ) -> Result<(), CliError> {
     let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
-    check_crate_and_report_errors(&mut context, crate_id, compile_options)?;
-
-    let exported_functions = context.get_all_exported_functions_in_crate(&crate_id);
-
-    let exported_programs = try_vecmap(
-        exported_functions,
-        |(function_name, function_id)| -> Result<(String, CompiledProgram), CompileError> {
-            // TODO: We should to refactor how to deal with compilation errors to avoid this.
-            let program = compile_no_check(&mut context, compile_options, function_id, None, false)
-                .map_err(|error| vec![FileDiagnostic::from(error)]);
-
-            let program = report_errors(
-                program.map(|program| (program, Vec::new())),
-                file_manager,
-                compile_options.deny_warnings,
-                compile_options.silence_warnings,
-            )?;
 
-            Ok((function_name, program))
-        },
+    let exported_programs = report_errors(
+        compile_exported_functions(&mut context, crate_id, compile_options),
+        file_manager,
+        compile_options.deny_warnings,
+        compile_options.silence_warnings,
     )?;
 
     let export_dir = workspace.export_directory_path();
+    let mut schema = Vec::with_capacity(exported_programs.len());
     for (function_name, program) in exported_programs {
+        schema.push(ExportedFunctionSchema::new(function_name.clone(), &program));
         save_program_to_file(&program.into(), &function_name.parse().unwrap(), &export_dir);
     }
+    schema.sort_by(|a, b| a.name.cmp(&b.name));
+    save_export_schema_to_file(&schema, &package.name, workspace.target_directory_path());
     Ok(())
 }
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use nargo::package::CrateName;
 use noirc_artifacts::{contract::ContractArtifact, program::ProgramArtifact};
+use noirc_driver::ExportedFunctionSchema;
 
 use crate::errors::FilesystemError;
 
@@ -24,6 +25,23 @@ pub(crate) fn save_contract_to_file<P: AsRef<Path>>(
     save_build_artifact_to_file(compiled_contract, circuit_name, circuit_dir)
 }
 
+/// Saves the input/output schema of all functions exported by a package to the target directory.
+///
+/// The schema is kept out of the export directory, whose files are all expected to be
+/// program artifacts (e.g. by `noir-codegen`).
+pub(crate) fn save_export_schema_to_file<P: AsRef<Path>>(
+    schema: &[ExportedFunctionSchema],
+    crate_name: &CrateName,
+    target_dir: P,
+) -> PathBuf {
+    create_named_dir(target_dir.as_ref(), "target");
+    let schema_name = format!("{}.exports.schema.json", String::from(crate_name));
+    let schema_path = target_dir.as_ref().join(schema_name);
+    write_to_file(&serde_json::to_vec(schema).unwrap(), &schema_path);
+
+    schema_path
+}
+
 fn save_build_artifact_to_file<P: AsRef<Path>, T: ?Sized + serde::Serialize>(
     build_artifact: &T,
     artifact_name: &str,
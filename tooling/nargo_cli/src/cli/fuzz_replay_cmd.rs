@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+use clap::Args;
+
+use nargo::foreign_calls::DefaultForeignCallBuilder;
+use nargo::ops::{compile_for_replay, replay_fuzzing_input, report_errors};
+use nargo::{
+    insert_all_files_for_workspace_into_file_manager, parse_all, prepare_package, PrintOutput,
+};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml};
+use noirc_abi::input_parser::Format;
+use noirc_driver::{CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_frontend::hir::FunctionNameMatch;
+
+use super::check_cmd::check_crate_and_report_errors;
+use super::fs::inputs::read_inputs_from_file;
+use super::{NargoConfig, PackageOptions};
+use crate::errors::{CliError, FilesystemError};
+
+/// Replays a saved fuzzing input through both the ACIR and Brillig executors
+#[derive(Debug, Clone, Args)]
+pub(crate) struct FuzzReplayCommand {
+    /// The name of the fuzzing harness to replay
+    harness: String,
+
+    /// The toml file which contains the input to replay
+    inputs: PathBuf,
+
+    #[clap(flatten)]
+    pub(super) package_options: PackageOptions,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+pub(crate) fn run(args: FuzzReplayCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let selection = args.package_options.package_selection();
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        selection,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+    )?;
+
+    let mut file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = parse_all(&file_manager);
+
+    let inputs_dir = args.inputs.parent().unwrap_or(&config.program_dir);
+    let inputs_name = args
+        .inputs
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| FilesystemError::PathNotValid(args.inputs.clone()))?;
+
+    for package in &workspace {
+        let (mut context, crate_id) = prepare_package(&file_manager, &parsed_files, package);
+        check_crate_and_report_errors(&mut context, crate_id, &args.compile_options)?;
+
+        let harnesses = context.get_all_test_functions_in_crate_matching(
+            &crate_id,
+            FunctionNameMatch::Exact(&args.harness),
+        );
+        let Some((_, harness)) = harnesses.first() else {
+            continue;
+        };
+
+        let programs = compile_for_replay(&mut context, harness.get_id(), &args.compile_options)
+            .map_err(|error| vec![error.into()]);
+        let programs = report_errors(
+            programs.map(|programs| (programs, Vec::new())),
+            &file_manager,
+            args.compile_options.deny_warnings,
+            args.compile_options.silence_warnings,
+        )?;
+
+        let (inputs, _) =
+            read_inputs_from_file(inputs_dir, inputs_name, Format::Toml, &programs.acir.abi)?;
+        let blackbox_solver = Bn254BlackBoxSolver(args.compile_options.pedantic_solving);
+        let replay = replay_fuzzing_input(&blackbox_solver, &programs, &inputs, || {
+            DefaultForeignCallBuilder::default().with_output(PrintOutput::None).build()
+        })?;
+
+        println!("[{}] Replaying {} on {}", package.name, args.harness, args.inputs.display());
+        println!("{replay}");
+        return Ok(());
+    }
+
+    Err(CliError::Generic(format!("Could not find a fuzzing harness named `{}`", args.harness)))
+}
@@ -15,6 +15,7 @@ mod debug_cmd;
 mod execute_cmd;
 mod export_cmd;
 mod fmt_cmd;
+mod fuzz_replay_cmd;
 mod generate_completion_script_cmd;
 mod info_cmd;
 mod init_cmd;
@@ -106,6 +107,8 @@ enum NargoCommand {
     Execute(execute_cmd::ExecuteCommand),
     #[command(hide = true)] // Hidden while the feature is being built out
     Export(export_cmd::ExportCommand),
+    #[command(hide = true)] // Hidden while fuzzing is being built out
+    FuzzReplay(fuzz_replay_cmd::FuzzReplayCommand),
     Debug(debug_cmd::DebugCommand),
     Test(test_cmd::TestCommand),
     Info(info_cmd::InfoCommand),
@@ -164,6 +167,7 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Compile(args) => compile_cmd::run(args, config),
         NargoCommand::Debug(args) => debug_cmd::run(args, config),
         NargoCommand::Execute(args) => execute_cmd::run(args, config),
+        NargoCommand::FuzzReplay(args) => fuzz_replay_cmd::run(args, config),
         NargoCommand::Export(args) => export_cmd::run(args, config),
         NargoCommand::Test(args) => test_cmd::run(args, config),
         NargoCommand::Info(args) => info_cmd::run(args, config),
@@ -196,6 +200,7 @@ fn command_scope(cmd: &NargoCommand) -> CommandScope {
         NargoCommand::Compile(cmd) => cmd.package_options.scope(),
         NargoCommand::Execute(cmd) => cmd.package_options.scope(),
         NargoCommand::Export(cmd) => cmd.package_options.scope(),
+        NargoCommand::FuzzReplay(cmd) => cmd.package_options.scope(),
         NargoCommand::Test(cmd) => cmd.package_options.scope(),
         NargoCommand::Info(cmd) => cmd.package_options.scope(),
         NargoCommand::Fmt(cmd) => cmd.package_options.scope(),
@@ -231,7 +236,9 @@ fn needs_lock(cmd: &NargoCommand) -> Option<bool> {
         | NargoCommand::Execute(..)
         | NargoCommand::Export(..)
         | NargoCommand::Info(..) => Some(true),
-        NargoCommand::Debug(..) | NargoCommand::Test(..) => Some(false),
+        NargoCommand::Debug(..) | NargoCommand::FuzzReplay(..) | NargoCommand::Test(..) => {
+            Some(false)
+        }
         NargoCommand::Fmt(..)
         | NargoCommand::New(..)
         | NargoCommand::Init(..)
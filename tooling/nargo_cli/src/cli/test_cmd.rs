@@ -1,4 +1,5 @@
 use std::{
+    cmp::Reverse,
     collections::{BTreeMap, HashMap},
     fmt::Display,
     panic::{catch_unwind, UnwindSafe},
@@ -12,7 +13,7 @@ use acvm::{BlackBoxFunctionSolver, FieldElement};
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
 use fm::FileManager;
-use formatters::{Formatter, JsonFormatter, PrettyFormatter, TerseFormatter};
+use formatters::{format_duration, Formatter, JsonFormatter, PrettyFormatter, TerseFormatter};
 use nargo::{
     foreign_calls::DefaultForeignCallBuilder, insert_all_files_for_workspace_into_file_manager,
     ops::TestStatus, package::Package, parse_all, prepare_package, workspace::Workspace,
@@ -53,7 +54,7 @@ pub(crate) struct TestCommand {
     #[clap(long)]
     oracle_resolver: Option<String>,
 
-    /// Number of threads used for running tests in parallel
+    /// Number of threads used for running tests in parallel (0 = one per available core)
     #[clap(long, default_value_t = rayon::current_num_threads())]
     test_threads: usize,
 
@@ -64,6 +65,10 @@ pub(crate) struct TestCommand {
     /// Display one character per test instead of one line
     #[clap(short = 'q', long = "quiet")]
     quiet: bool,
+
+    /// Once all tests have run, display the given number of slowest tests, slowest first
+    #[clap(long, value_name = "N")]
+    report_slowest: Option<usize>,
 }
 
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
@@ -97,12 +102,15 @@ impl Display for Format {
 }
 
 struct Test<'a> {
+    /// Position of the test among the tests of its package, in the order they were collected.
+    index: usize,
     name: String,
     package_name: String,
     runner: Box<dyn FnOnce() -> (TestStatus, String) + Send + UnwindSafe + 'a>,
 }
 
 struct TestResult {
+    index: usize,
     name: String,
     package_name: String,
     status: TestStatus,
@@ -150,7 +158,11 @@ pub(crate) fn run(args: TestCommand, config: NargoConfig) -> Result<(), CliError
         workspace,
         args: &args,
         pattern,
-        num_threads: args.test_threads,
+        num_threads: if args.test_threads == 0 {
+            rayon::current_num_threads()
+        } else {
+            args.test_threads
+        },
         formatter,
     };
     runner.run()
@@ -182,7 +194,12 @@ impl<'a> TestRunner<'a> {
 
         // Now run all tests in parallel, but show output for each package sequentially
         let tests_count = tests.len();
-        let all_passed = self.run_all_tests(tests, &test_count_per_package);
+        let test_results = self.run_all_tests(tests, &test_count_per_package);
+        let all_passed = test_results.iter().all(|test_result| !test_result.status.failed());
+
+        if let Some(count) = self.args.report_slowest {
+            eprint!("{}", format_slowest_tests(&test_results, count));
+        }
 
         if tests_count == 0 {
             match &self.pattern {
@@ -208,13 +225,13 @@ impl<'a> TestRunner<'a> {
         }
     }
 
-    /// Runs all tests. Returns `true` if all tests passed, `false` otherwise.
+    /// Runs all tests and returns their results, package by package.
     fn run_all_tests(
         &self,
         tests: Vec<Test<'a>>,
         test_count_per_package: &BTreeMap<String, usize>,
-    ) -> bool {
-        let mut all_passed = true;
+    ) -> Vec<TestResult> {
+        let mut test_results = Vec::new();
 
         for (package_name, total_test_count) in test_count_per_package {
             self.formatter
@@ -263,6 +280,7 @@ impl<'a> TestRunner<'a> {
                         let time_to_run = time_before_test.elapsed();
 
                         let test_result = TestResult {
+                            index: test.index,
                             name: test.name,
                             package_name: test.package_name,
                             status,
@@ -290,9 +308,10 @@ impl<'a> TestRunner<'a> {
             // Also drop main sender so the channel closes
             drop(sender);
 
-            // We'll go package by package, but we might get test results from packages ahead of us.
-            // We'll buffer those here and show them all at once when we get to those packages.
-            let mut buffer: HashMap<String, Vec<TestResult>> = HashMap::new();
+            // We'll go package by package and test by test, but we might get test results from
+            // tests ahead of us. We'll buffer those here and show them once we get to those tests,
+            // so that results are shown in the same order regardless of the number of threads.
+            let mut buffer = TestResultBuffer::default();
             for (package_name, total_test_count) in test_count_per_package {
                 let mut test_report = Vec::new();
 
@@ -303,47 +322,30 @@ impl<'a> TestRunner<'a> {
                     .package_start_sync(package_name, total_test_count)
                     .expect("Could not display package start");
 
-                // Check if we have buffered test results for this package
-                if let Some(buffered_tests) = buffer.remove(package_name) {
-                    for test_result in buffered_tests {
-                        self.display_test_result(
-                            &test_result,
-                            current_test_count + 1,
-                            total_test_count,
-                        )
-                        .expect("Could not display test status");
-                        test_report.push(test_result);
-                        current_test_count += 1;
-                    }
-                }
-
-                if current_test_count < total_test_count {
-                    while let Ok(test_result) = receiver.recv() {
-                        if test_result.status.failed() {
-                            all_passed = false;
-                        }
-
-                        // This is a test result from a different package: buffer it.
-                        if &test_result.package_name != package_name {
-                            buffer
-                                .entry(test_result.package_name.clone())
-                                .or_default()
-                                .push(test_result);
+                while current_test_count < total_test_count {
+                    let test_result = match buffer.take(package_name, current_test_count) {
+                        Some(test_result) => test_result,
+                        None => {
+                            let Ok(test_result) = receiver.recv() else {
+                                break;
+                            };
+                            buffer.insert(
+                                test_result.package_name.clone(),
+                                test_result.index,
+                                test_result,
+                            );
                             continue;
                         }
+                    };
 
-                        self.display_test_result(
-                            &test_result,
-                            current_test_count + 1,
-                            total_test_count,
-                        )
-                        .expect("Could not display test status");
-                        test_report.push(test_result);
-                        current_test_count += 1;
-                        if current_test_count == total_test_count {
-                            break;
-                        }
-                    }
+                    self.display_test_result(
+                        &test_result,
+                        current_test_count + 1,
+                        total_test_count,
+                    )
+                    .expect("Could not display test status");
+                    test_report.push(test_result);
+                    current_test_count += 1;
                 }
 
                 self.formatter
@@ -356,10 +358,11 @@ impl<'a> TestRunner<'a> {
                         self.args.compile_options.silence_warnings,
                     )
                     .expect("Could not display test report");
+                test_results.extend(test_report);
             }
         });
 
-        all_passed
+        test_results
     }
 
     /// Compiles all packages in parallel and returns their tests
@@ -431,7 +434,8 @@ impl<'a> TestRunner<'a> {
 
         let tests: Vec<Test> = test_functions
             .into_iter()
-            .map(|test_name| {
+            .enumerate()
+            .map(|(index, test_name)| {
                 let test_name_copy = test_name.clone();
                 let root_path = root_path.clone();
                 let package_name_clone = package_name.clone();
@@ -445,7 +449,7 @@ impl<'a> TestRunner<'a> {
                         package_name_clone.clone(),
                     )
                 });
-                Test { name: test_name_copy, package_name: package_name_clone2, runner }
+                Test { index, name: test_name_copy, package_name: package_name_clone2, runner }
             })
             .collect();
 
@@ -528,3 +532,102 @@ impl<'a> TestRunner<'a> {
         )
     }
 }
+
+/// Lists the `count` slowest of the given tests along with how long they took, slowest first.
+fn format_slowest_tests(test_results: &[TestResult], count: usize) -> String {
+    let mut test_results: Vec<_> = test_results.iter().collect();
+    test_results.sort_by_key(|test_result| Reverse(test_result.time_to_run));
+
+    let mut output = String::from("Slowest tests:\n");
+    for test_result in test_results.into_iter().take(count) {
+        output.push_str(&format!(
+            "  [{}] {} {}\n",
+            test_result.package_name,
+            test_result.name,
+            format_duration(test_result.time_to_run)
+        ));
+    }
+    output
+}
+
+/// Holds the results of tests which finished before the tests collected ahead of them.
+struct TestResultBuffer<T> {
+    /// Results by package name and by the index of the test within its package.
+    results: HashMap<String, BTreeMap<usize, T>>,
+}
+
+impl<T> Default for TestResultBuffer<T> {
+    fn default() -> Self {
+        Self { results: HashMap::new() }
+    }
+}
+
+impl<T> TestResultBuffer<T> {
+    fn insert(&mut self, package_name: String, index: usize, result: T) {
+        self.results.entry(package_name).or_default().insert(index, result);
+    }
+
+    /// Removes the result of the test at `index` in the given package, if it has been received.
+    fn take(&mut self, package_name: &str, index: usize) -> Option<T> {
+        self.results.get_mut(package_name)?.remove(&index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use nargo::ops::TestStatus;
+
+    use super::{format_slowest_tests, TestResult, TestResultBuffer};
+
+    #[test]
+    fn reports_slowest_tests_with_their_duration() {
+        let test_result = |name: &str, millis: u64, status: TestStatus| TestResult {
+            index: 0,
+            name: name.to_string(),
+            package_name: "pkg".to_string(),
+            status,
+            output: String::new(),
+            time_to_run: Duration::from_millis(millis),
+        };
+        let failure = TestStatus::Fail { message: String::new(), error_diagnostic: None };
+        let test_results = [
+            test_result("fast", 5, TestStatus::Pass),
+            test_result("slow", 2500, failure),
+            test_result("medium", 120, TestStatus::Pass),
+        ];
+
+        let expected = "\
+Slowest tests:
+  [pkg] slow <2.500s>
+  [pkg] medium <0.120s>
+";
+        assert_eq!(format_slowest_tests(&test_results, 2), expected);
+    }
+
+    #[test]
+    fn buffered_results_are_taken_in_collection_order() {
+        let tests = [("a", 0), ("a", 1), ("a", 2), ("b", 0), ("b", 1)];
+
+        // Simulate the order in which results arrive when the tests are spread over a number of
+        // threads, with the tests picked up by later threads finishing first.
+        for num_threads in 1..=tests.len() {
+            let mut arrival_order = Vec::new();
+            for thread in (0..num_threads).rev() {
+                arrival_order.extend(tests.iter().skip(thread).step_by(num_threads));
+            }
+
+            let mut buffer = TestResultBuffer::default();
+            for (package_name, index) in arrival_order {
+                buffer.insert(package_name.to_string(), index, (package_name, index));
+            }
+
+            let taken: Vec<_> = tests
+                .iter()
+                .map(|(package_name, index)| buffer.take(package_name, *index).unwrap())
+                .collect();
+            assert_eq!(taken, tests, "with {num_threads} threads");
+        }
+    }
+}
@@ -64,6 +64,11 @@ pub(super) trait Formatter: Send + Sync + RefUnwindSafe {
     ) -> std::io::Result<()>;
 }
 
+/// Formats how long a test took to run, as shown next to its result.
+pub(super) fn format_duration(duration: Duration) -> String {
+    format!("<{:.3}s>", duration.as_secs_f64())
+}
+
 pub(super) struct PrettyFormatter;
 
 impl Formatter for PrettyFormatter {
@@ -103,13 +108,8 @@ impl Formatter for PrettyFormatter {
         let writer = StandardStream::stderr(ColorChoice::Always);
         let mut writer = writer.lock();
 
-        let is_slow = test_result.time_to_run >= Duration::from_secs(30);
         let show_time = |writer: &mut StandardStreamLock<'_>| {
-            if is_slow {
-                write!(writer, " <{:.3}s>", test_result.time_to_run.as_secs_f64())
-            } else {
-                Ok(())
-            }
+            write!(writer, " {}", format_duration(test_result.time_to_run))
         };
 
         write!(writer, "[{}] Testing {}... ", &test_result.package_name, &test_result.name)?;
@@ -125,10 +125,13 @@ impl Formatter for PrettyFormatter {
             }
             TestStatus::Fail { message, error_diagnostic } => {
                 writer.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
-                write!(writer, "FAIL\n{message}\n")?;
+                write!(writer, "FAIL")?;
                 writer.reset()?;
                 show_time(&mut writer)?;
                 writeln!(writer)?;
+                writer.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                writeln!(writer, "{message}")?;
+                writer.reset()?;
                 if let Some(diag) = error_diagnostic {
                     noirc_errors::reporter::report_all(
                         file_manager.as_file_map(),
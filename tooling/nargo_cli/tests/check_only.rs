@@ -0,0 +1,44 @@
+//! Tests that `nargo compile --check-only` reports errors without producing artifacts.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathAssert, PathChild};
+
+#[test]
+fn check_only_reports_errors_without_producing_artifacts() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+
+    let project_name = "check_only";
+    let project_dir = test_dir.child(project_name);
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("new").arg(project_name);
+    cmd.assert().success();
+
+    let artifact = project_dir.child("target").child(format!("{project_name}.json"));
+
+    // A valid program checks successfully, but isn't compiled
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&project_dir).arg("compile").arg("--check-only");
+    cmd.assert().success();
+    artifact.assert(predicate::path::missing());
+
+    // Errors are still reported
+    project_dir
+        .child("src")
+        .child("main.nr")
+        .write_str("fn main(x: Field) -> pub u8 { x }")
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&project_dir).arg("compile").arg("--check-only");
+    cmd.assert().failure().stderr(predicate::str::contains("expected type u8, found type Field"));
+    artifact.assert(predicate::path::missing());
+
+    // Other commands need the compiled program, so they don't accept the flag
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&project_dir).arg("execute").arg("--check-only");
+    cmd.assert().failure().stderr(predicate::str::contains("unexpected argument '--check-only'"));
+}
@@ -0,0 +1,44 @@
+//! Tests that `nargo compile --show-largest-constant-arrays` points at where each array is created.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+
+#[test]
+fn shows_the_location_of_the_largest_constant_arrays() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+
+    let project_name = "largest_constant_arrays";
+    let project_dir = test_dir.child(project_name);
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&test_dir).arg("new").arg(project_name);
+    cmd.assert().success();
+
+    project_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            "fn main(x: u32) -> pub Field {
+    let small = [1, 2, 3];
+    let table = [7; 100];
+    table[x] + small[x] + lookup(x)
+}
+
+fn lookup(x: u32) -> Field {
+    let table = [5; 50];
+    table[x]
+}",
+        )
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&project_dir).arg("compile").arg("--show-largest-constant-arrays").arg("2");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("main.nr:3: 100 elements"))
+        .stdout(predicate::str::contains("main.nr:8: 50 elements"))
+        .stdout(predicate::str::contains("3 elements").not());
+}
@@ -65,6 +65,7 @@ fn run_stdlib_tests(force_brillig: bool, inliner_aggressiveness: i64) {
         name: "stdlib".parse().unwrap(),
         dependencies: BTreeMap::new(),
         expression_width: None,
+        include_dirs: Vec::new(),
     };
 
     let (mut context, dummy_crate_id) =
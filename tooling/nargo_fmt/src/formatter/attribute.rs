@@ -73,12 +73,14 @@ impl<'a> Formatter<'a> {
             SecondaryAttribute::ContractLibraryMethod
             | SecondaryAttribute::Export
             | SecondaryAttribute::Varargs
-            | SecondaryAttribute::UseCallersScope => {
+            | SecondaryAttribute::UseCallersScope
+            | SecondaryAttribute::UncheckedArithmetic => {
                 self.format_no_args_attribute();
             }
             SecondaryAttribute::Field(_)
             | SecondaryAttribute::Abi(_)
-            | SecondaryAttribute::Allow(_) => {
+            | SecondaryAttribute::Allow(_)
+            | SecondaryAttribute::Deny(_) => {
                 self.format_one_arg_attribute();
             }
             SecondaryAttribute::Tag(custom_attribute) => {
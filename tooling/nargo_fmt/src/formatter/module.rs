@@ -12,6 +12,12 @@ impl<'a> Formatter<'a> {
         self.write_keyword(Keyword::Mod);
         self.write_space();
         self.write_identifier(module_declaration.ident);
+        if let Some(alias) = module_declaration.alias {
+            self.write_space();
+            self.write_keyword(Keyword::As);
+            self.write_space();
+            self.write_identifier(alias);
+        }
         self.write_semicolon();
     }
 
@@ -56,6 +62,13 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_module_declaration_with_alias() {
+        let src = "  mod  foo  as  bar ; ";
+        let expected = "mod foo as bar;\n";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_module_declaration_with_doc_comments() {
         let src = " /// hello
@@ -34,6 +34,11 @@ pub enum ManifestError {
     #[error("Cannot find file {entry} which was specified as the `entry` field in {toml}")]
     MissingEntryFile { toml: PathBuf, entry: PathBuf },
 
+    #[error(
+        "Cannot find directory {dir} which was specified in the `include_dirs` field in {toml}"
+    )]
+    MissingIncludeDir { toml: PathBuf, dir: PathBuf },
+
     #[error(
         r#"Cannot find file {entry} which is defaulted due to specifying `type = "{package_type}"` in {toml}"#
     )]
@@ -235,6 +235,18 @@ impl PackageConfig {
             })?;
         }
 
+        let mut include_dirs = Vec::new();
+        for dir in self.package.include_dirs.iter().flatten() {
+            let dir = root_dir.join(dir);
+            if !dir.is_dir() {
+                return Err(ManifestError::MissingIncludeDir {
+                    toml: root_dir.join("Nargo.toml"),
+                    dir,
+                });
+            }
+            include_dirs.push(dir);
+        }
+
         let expression_width = self
             .package
             .expression_width
@@ -254,6 +266,7 @@ impl PackageConfig {
             name,
             dependencies,
             expression_width,
+            include_dirs,
         })
     }
 }
@@ -323,6 +336,9 @@ struct PackageMetadata {
     compiler_version: Option<String>,
     license: Option<String>,
     expression_width: Option<String>,
+    // Directories, relative to the package root, searched for module files
+    // which aren't found next to the file declaring them
+    include_dirs: Option<Vec<PathBuf>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -606,6 +622,22 @@ mod tests {
         assert!(Config::try_from(src).is_ok());
     }
 
+    #[test]
+    fn parse_package_include_dirs_toml() {
+        let src = r#"
+    [package]
+    name = "test"
+    type = "lib"
+    include_dirs = ["vendor", "generated"]
+    "#;
+
+        let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+            panic!("Expected a package config");
+        };
+        let include_dirs = package_config.package.include_dirs.unwrap();
+        assert_eq!(include_dirs, vec![PathBuf::from("vendor"), PathBuf::from("generated")]);
+    }
+
     /// Test that `find_root` handles all kinds of prefixes.
     /// (It dispatches based on `workspace` to methods which handle paths differently).
     #[test_matrix(
@@ -107,6 +107,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
         if let Err(err) = semver_check_package(&package, &compiler_version) {
             panic!("semver check should have passed. compiler version is 0.1.0 and required version from the package is 0.1.0\n error: {err:?}")
@@ -139,6 +140,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
 
         let valid_dependency = Package {
@@ -150,6 +152,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
         let invalid_dependency = Package {
             compiler_required_version: Some("0.2.0".to_string()),
@@ -160,6 +163,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
 
         package.dependencies.insert(
@@ -201,6 +205,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -221,6 +226,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -241,6 +247,7 @@ mod tests {
             dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            include_dirs: Vec::new(),
         };
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
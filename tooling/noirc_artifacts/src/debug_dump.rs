@@ -0,0 +1,181 @@
+//! A human readable dump of the debug information of a compiled program.
+//!
+//! The debug information is otherwise only stored compressed in the program artifact, which makes
+//! issues with source mapping hard to track down.
+use std::fmt::Write;
+
+use codespan_reporting::files::Files;
+use noirc_abi::{AbiErrorType, AbiType, Sign};
+use noirc_errors::Location;
+
+use crate::{debug::DebugArtifact, program::ProgramArtifact};
+
+impl ProgramArtifact {
+    /// Renders the debug information of the program: the source locations of the opcodes of
+    /// each function, the `main` parameter or return value held by each witness, and the type
+    /// of each error selector.
+    pub fn dump_debug_info(&self) -> String {
+        let debug_artifact = DebugArtifact {
+            debug_symbols: self.debug_symbols.debug_infos.clone(),
+            file_map: self.file_map.clone(),
+        };
+        let mut output = String::new();
+
+        for (index, debug_info) in debug_artifact.debug_symbols.iter().enumerate() {
+            let name = self.names.get(index).map_or("", String::as_str);
+            writeln!(output, "function {index} ({name}):").unwrap();
+            writeln!(output, "  opcodes:").unwrap();
+            for (opcode_location, call_stack) in &debug_info.locations {
+                let call_stack = format_call_stack(&debug_artifact, call_stack);
+                writeln!(output, "    {opcode_location}: {call_stack}").unwrap();
+            }
+
+            for (brillig_function_id, locations) in &debug_info.brillig_locations {
+                let index = brillig_function_id.as_usize();
+                let name = self.brillig_names.get(index).map_or("", String::as_str);
+                writeln!(output, "  unconstrained function {index} ({name}) opcodes:").unwrap();
+                for (opcode_location, call_stack) in locations {
+                    let call_stack = format_call_stack(&debug_artifact, call_stack);
+                    writeln!(output, "    {opcode_location}: {call_stack}").unwrap();
+                }
+            }
+        }
+
+        writeln!(output, "witnesses:").unwrap();
+        for (witness, name) in self.main_witness_names() {
+            writeln!(output, "  _{witness}: {name}").unwrap();
+        }
+
+        writeln!(output, "error selectors:").unwrap();
+        for (selector, error_type) in &self.abi.error_types {
+            let error_type = format_error_type(error_type);
+            writeln!(output, "  {}: {error_type}", selector.as_u64()).unwrap();
+        }
+
+        output
+    }
+
+    /// Returns the name of the `main` parameter or return value held by each of its witnesses.
+    ///
+    /// Parameters are assigned consecutive witnesses starting at zero, in order.
+    fn main_witness_names(&self) -> Vec<(u32, String)> {
+        let mut witness_names = Vec::new();
+        let mut witness = 0;
+        for parameter in &self.abi.parameters {
+            for _ in 0..parameter.typ.field_count() {
+                witness_names.push((witness, parameter.name.clone()));
+                witness += 1;
+            }
+        }
+
+        if let Some(main) = self.bytecode.functions.first() {
+            for return_witness in main.return_values.indices() {
+                witness_names.push((return_witness, "return value".to_string()));
+            }
+        }
+        witness_names
+    }
+}
+
+/// Formats a call stack as `file:line:column` locations, from the outermost call to the innermost.
+fn format_call_stack(debug_artifact: &DebugArtifact, call_stack: &[Location]) -> String {
+    // Call stacks are recorded from the innermost call to the outermost one
+    let locations = call_stack.iter().rev().map(|location| {
+        let path = debug_artifact.name(location.file).map(|path| path.to_string());
+        let line = debug_artifact.location_line_number(*location);
+        let column = debug_artifact.location_column_number(*location);
+        match (path, line, column) {
+            (Ok(path), Ok(line), Ok(column)) => format!("{path}:{line}:{column}"),
+            _ => format!("<unknown file {:?}>", location.file),
+        }
+    });
+    locations.collect::<Vec<_>>().join(" -> ")
+}
+
+fn format_error_type(error_type: &AbiErrorType) -> String {
+    match error_type {
+        AbiErrorType::String { string } => format!("{string:?}"),
+        AbiErrorType::FmtString { length, item_types } => {
+            let item_types: Vec<_> = item_types.iter().map(format_abi_type).collect();
+            format!("fmtstr<{length}, ({})>", item_types.join(", "))
+        }
+        AbiErrorType::Custom(typ) => format_abi_type(typ),
+    }
+}
+
+fn format_abi_type(typ: &AbiType) -> String {
+    match typ {
+        AbiType::Field => "Field".to_string(),
+        AbiType::Array { length, typ } => format!("[{}; {length}]", format_abi_type(typ)),
+        AbiType::Integer { sign: Sign::Unsigned, width } => format!("u{width}"),
+        AbiType::Integer { sign: Sign::Signed, width } => format!("i{width}"),
+        AbiType::Boolean => "bool".to_string(),
+        AbiType::Struct { path, .. } => path.clone(),
+        AbiType::Tuple { fields } => {
+            let fields: Vec<_> = fields.iter().map(format_abi_type).collect();
+            format!("({})", fields.join(", "))
+        }
+        AbiType::String { length } => format!("str<{length}>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions};
+    use noirc_frontend::hir::{def_map::parse_file, Context};
+
+    use crate::program::ProgramArtifact;
+
+    /// Compiles `source` as `main.nr` and returns the dump of its debug information.
+    fn dump_debug_info(source: &str) -> String {
+        let file_name = Path::new("main.nr");
+        let mut file_manager = file_manager_with_stdlib(Path::new(""));
+        file_manager.add_file_with_source(file_name, source.to_owned()).unwrap();
+        let parsed_files = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+            .collect();
+        let mut context = Context::new(file_manager, parsed_files);
+        let crate_id = prepare_crate(&mut context, file_name);
+
+        let (program, _) =
+            noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+                .expect("Expected the program to compile");
+        ProgramArtifact::from(program).dump_debug_info()
+    }
+
+    #[test]
+    fn dumps_opcode_locations_witnesses_and_error_selectors() {
+        let source = "fn main(x: u8, y: [Field; 2]) -> pub Field {
+    assert(x != 0, \"x must not be zero\");
+    y[0] * y[1]
+}
+";
+        let dump = dump_debug_info(source);
+
+        assert!(dump.contains("function 0 (main):"), "{dump}");
+        // The assertion is constrained by the first opcodes
+        assert!(dump.contains(": main.nr:2:12"), "{dump}");
+        assert!(dump.contains("  _0: x\n  _1: y\n  _2: y\n  _3: return value\n"), "{dump}");
+        assert!(dump.contains(": \"x must not be zero\"\n"), "{dump}");
+    }
+
+    #[test]
+    fn dumps_call_stacks_from_the_outermost_call() {
+        let source = "fn main(x: u8) {
+    check(x);
+}
+
+fn check(x: u8) {
+    assert(x != 0);
+}
+";
+        let dump = dump_debug_info(source);
+
+        // The call to `check` comes before the assertion inside it
+        assert!(dump.contains(": main.nr:2:5 -> main.nr:6:12\n"), "{dump}");
+    }
+}
@@ -11,5 +11,6 @@
 
 pub mod contract;
 pub mod debug;
+mod debug_dump;
 mod debug_vars;
 pub mod program;